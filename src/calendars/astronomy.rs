@@ -0,0 +1,258 @@
+use chrono::{Datelike, Duration, NaiveDate, NaiveDateTime, Timelike};
+
+/// Julian day of 0001-01-01 00:00 (day 1 of the common era)
+const JULIAN_DAY_OF_COMMON_ERA: f64 = 1_721_425.5;
+
+/// Astronomical algorithms from Jean Meeus, "Astronomical Algorithms" and the NOAA solar calculator
+///
+/// The results are precise to a few minutes, which is good enough to derive the day of a lunar calendar.
+pub struct Astronomy {}
+
+impl Astronomy {
+	/// Gets the time of the new moon with the given lunation number in UTC. Lunation 0 is the new moon of the
+	/// 6th january 2000.
+	///
+	/// # Example
+	///
+	/// ```
+	/// use horus::calendars::astronomy::Astronomy;
+	/// use chrono::NaiveDate;
+	///
+	/// let result = Astronomy::new_moon(299);
+	///
+	/// assert_eq!(result.date(), NaiveDate::from_ymd_opt(2024, 3, 10).unwrap());
+	/// ```
+	pub fn new_moon(lunation: i64) -> NaiveDateTime {
+		let k = lunation as f64;
+		let t = k / 1236.85;
+
+		let jde = 2_451_550.097_66 + 29.530_588_861 * k + 0.000_154_37 * t.powi(2) - 0.000_000_150 * t.powi(3)
+			+ 0.000_000_000_73 * t.powi(4);
+		let e = 1.0 - 0.002_516 * t - 0.000_007_4 * t.powi(2);
+		let m = (2.5534 + 29.105_356_70 * k - 0.000_001_4 * t.powi(2) - 0.000_000_11 * t.powi(3)).to_radians();
+		let mp = (201.5643 + 385.816_935_28 * k + 0.010_758_2 * t.powi(2) + 0.000_012_38 * t.powi(3)
+			- 0.000_000_058 * t.powi(4))
+		.to_radians();
+		let f = (160.7108 + 390.670_502_84 * k - 0.001_611_8 * t.powi(2) - 0.000_002_27 * t.powi(3)
+			+ 0.000_000_011 * t.powi(4))
+		.to_radians();
+		let omega = (124.7746 - 1.563_755_88 * k + 0.002_067_2 * t.powi(2) + 0.000_002_15 * t.powi(3)).to_radians();
+
+		let correction = -0.40720 * mp.sin()
+			+ 0.17241 * e * m.sin()
+			+ 0.01608 * (2.0 * mp).sin()
+			+ 0.01039 * (2.0 * f).sin()
+			+ 0.00739 * e * (mp - m).sin()
+			- 0.00514 * e * (mp + m).sin()
+			+ 0.00208 * e * e * (2.0 * m).sin()
+			- 0.00111 * (mp - 2.0 * f).sin()
+			- 0.00057 * (mp + 2.0 * f).sin()
+			+ 0.00056 * e * (2.0 * mp + m).sin()
+			- 0.00042 * (3.0 * mp).sin()
+			+ 0.00042 * e * (m + 2.0 * f).sin()
+			+ 0.00038 * e * (m - 2.0 * f).sin()
+			- 0.00024 * e * (2.0 * mp - m).sin()
+			- 0.00017 * omega.sin()
+			- 0.00007 * (mp + 2.0 * m).sin()
+			+ 0.00004 * (2.0 * mp - 2.0 * f).sin()
+			+ 0.00004 * (3.0 * m).sin()
+			+ 0.00003 * (mp + m - 2.0 * f).sin()
+			+ 0.00003 * (2.0 * mp + 2.0 * f).sin()
+			- 0.00003 * (mp + m + 2.0 * f).sin()
+			+ 0.00003 * (mp - m + 2.0 * f).sin()
+			- 0.00002 * (mp - m - 2.0 * f).sin()
+			- 0.00002 * (3.0 * mp + m).sin()
+			+ 0.00002 * (4.0 * mp).sin();
+
+		Self::from_julian_day(jde + correction - Self::delta_t(2000.0 + k / 12.3685) / 86_400.0)
+	}
+
+	/// Gets the time in UTC at which the apparent longitude of the sun reaches the given degrees in the given year.
+	/// The solar terms of the east asian calendars are the multiples of 15 degrees, e.g. 0 is the march equinox and
+	/// 270 the december solstice.
+	///
+	/// # Example
+	///
+	/// ```
+	/// use horus::calendars::astronomy::Astronomy;
+	/// use chrono::NaiveDate;
+	///
+	/// let result = Astronomy::solar_term(2024, 270.0);
+	///
+	/// assert_eq!(result.date(), NaiveDate::from_ymd_opt(2024, 12, 21).unwrap());
+	/// ```
+	pub fn solar_term(year: i32, longitude: f64) -> NaiveDateTime {
+		let days_per_year = 365.242_2;
+		let mut jde = 2_451_545.0
+			+ (year - 2000) as f64 * days_per_year
+			+ (longitude - 280.0).rem_euclid(360.0) / 360.0 * days_per_year;
+
+		for _ in 0..50 {
+			let difference = (longitude - Self::solar_longitude(jde) + 180.0).rem_euclid(360.0) - 180.0;
+			jde += difference * days_per_year / 360.0;
+			if difference.abs() < 1e-7 {
+				break;
+			}
+		}
+
+		Self::from_julian_day(jde - Self::delta_t(year as f64) / 86_400.0)
+	}
+
+	/// Gets the number of the last lunation which took place before the given time
+	pub fn lunation_before(time: NaiveDateTime) -> i64 {
+		let mean = (Self::to_julian_day(time) - 2_451_550.097_66) / 29.530_588_861;
+		let mut lunation = mean.floor() as i64 + 1;
+		while Self::new_moon(lunation) > time {
+			lunation -= 1;
+		}
+
+		lunation
+	}
+
+	/// Gets the time of the sunset on the given date at the given position in UTC. Latitude and longitude are
+	/// given in degrees, east and north are positive.
+	pub fn sunset(date: NaiveDate, latitude: f64, longitude: f64) -> NaiveDateTime {
+		let gamma = 2.0 * std::f64::consts::PI / 365.0 * (date.ordinal() - 1) as f64;
+		let equation_of_time = 229.18
+			* (0.000_075 + 0.001_868 * gamma.cos()
+				- 0.032_077 * gamma.sin()
+				- 0.014_615 * (2.0 * gamma).cos()
+				- 0.040_849 * (2.0 * gamma).sin());
+		let declination = 0.006_918 - 0.399_912 * gamma.cos() + 0.070_257 * gamma.sin()
+			- 0.006_758 * (2.0 * gamma).cos()
+			+ 0.000_907 * (2.0 * gamma).sin()
+			- 0.002_697 * (3.0 * gamma).cos()
+			+ 0.001_48 * (3.0 * gamma).sin();
+		let latitude = latitude.to_radians();
+		let hour_angle = (90.833_f64.to_radians().cos() / (latitude.cos() * declination.cos())
+			- latitude.tan() * declination.tan())
+		.clamp(-1.0, 1.0)
+		.acos()
+		.to_degrees();
+		let minutes = 720.0 - 4.0 * (longitude - hour_angle) - equation_of_time;
+
+		date.and_hms_opt(0, 0, 0).unwrap() + Duration::seconds((minutes * 60.0).round() as i64)
+	}
+
+	/// Gets the apparent longitude of the sun in degrees at the given julian ephemeris day (low accuracy of about
+	/// 0.01 degrees)
+	fn solar_longitude(jde: f64) -> f64 {
+		let t = (jde - 2_451_545.0) / 36_525.0;
+		let mean_longitude = 280.466_46 + 36_000.769_83 * t + 0.000_303_2 * t.powi(2);
+		let anomaly = (357.529_11 + 35_999.050_29 * t - 0.000_153_7 * t.powi(2)).to_radians();
+		let center = (1.914_602 - 0.004_817 * t - 0.000_014 * t.powi(2)) * anomaly.sin()
+			+ (0.019_993 - 0.000_101 * t) * (2.0 * anomaly).sin()
+			+ 0.000_289 * (3.0 * anomaly).sin();
+		let omega = (125.04 - 1_934.136 * t).to_radians();
+
+		(mean_longitude + center - 0.005_69 - 0.004_78 * omega.sin()).rem_euclid(360.0)
+	}
+
+	/// Gets the difference between the terrestrial time and the universal time in seconds by the polynomials of
+	/// Espenak and Meeus, which are used from 1900 until 2150
+	pub(crate) fn delta_t(year: f64) -> f64 {
+		if year < 1920.0 {
+			let t = year - 1900.0;
+			-2.79 + 1.494_119 * t - 0.059_893_9 * t.powi(2) + 0.006_196_6 * t.powi(3) - 0.000_197 * t.powi(4)
+		} else if year < 1941.0 {
+			let t = year - 1920.0;
+			21.20 + 0.844_93 * t - 0.076_100 * t.powi(2) + 0.002_093_6 * t.powi(3)
+		} else if year < 1961.0 {
+			let t = year - 1950.0;
+			29.07 + 0.407 * t - t.powi(2) / 233.0 + t.powi(3) / 2547.0
+		} else if year < 1986.0 {
+			let t = year - 1975.0;
+			45.45 + 1.067 * t - t.powi(2) / 260.0 - t.powi(3) / 718.0
+		} else if year < 2005.0 {
+			let t = year - 2000.0;
+			63.86 + 0.3345 * t - 0.060_374 * t.powi(2)
+				+ 0.001_727_5 * t.powi(3)
+				+ 0.000_651_814 * t.powi(4)
+				+ 0.000_023_735_99 * t.powi(5)
+		} else if year < 2050.0 {
+			let t = year - 2000.0;
+			62.92 + 0.322_17 * t + 0.005_589 * t.powi(2)
+		} else {
+			-20.0 + 32.0 * ((year - 1820.0) / 100.0).powi(2) - 0.5628 * (2150.0 - year)
+		}
+	}
+
+	/// Converts the given julian day into a date and time
+	pub fn from_julian_day(julian_day: f64) -> NaiveDateTime {
+		let days = julian_day - JULIAN_DAY_OF_COMMON_ERA;
+		let whole_days = days.floor();
+		let seconds = ((days - whole_days) * 86_400.0).round() as i64;
+
+		NaiveDate::from_num_days_from_ce_opt(whole_days as i32 + 1)
+			.unwrap()
+			.and_hms_opt(0, 0, 0)
+			.unwrap() + Duration::seconds(seconds)
+	}
+
+	/// Converts the given date and time into a julian day
+	pub fn to_julian_day(time: NaiveDateTime) -> f64 {
+		let seconds = time.time().num_seconds_from_midnight() as f64;
+
+		(time.date().num_days_from_ce() - 1) as f64 + seconds / 86_400.0 + JULIAN_DAY_OF_COMMON_ERA
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use chrono::{NaiveDate, Timelike};
+
+	use super::Astronomy;
+
+	#[test]
+	fn test_new_moon() {
+		// 2024-04-08 18:21 UTC, the total solar eclipse
+		let result = Astronomy::new_moon(300);
+		assert_eq!(result.date(), NaiveDate::from_ymd_opt(2024, 4, 8).unwrap());
+		assert_eq!(result.hour(), 18);
+		assert!((20..=22).contains(&result.minute()));
+	}
+
+	#[test]
+	fn test_lunation_before() {
+		let time = NaiveDate::from_ymd_opt(2024, 4, 9)
+			.unwrap()
+			.and_hms_opt(0, 0, 0)
+			.unwrap();
+		assert_eq!(Astronomy::lunation_before(time), 300);
+		let time = NaiveDate::from_ymd_opt(2024, 4, 8)
+			.unwrap()
+			.and_hms_opt(0, 0, 0)
+			.unwrap();
+		assert_eq!(Astronomy::lunation_before(time), 299);
+	}
+
+	#[test]
+	fn test_sunset() {
+		// sunset in mecca at 18:39 local time (UTC+3)
+		let result = Astronomy::sunset(NaiveDate::from_ymd_opt(2024, 4, 8).unwrap(), 21.4225, 39.8262);
+		assert_eq!(result.hour(), 15);
+		assert!((37..=41).contains(&result.minute()));
+	}
+
+	#[test]
+	fn test_solar_term() {
+		// march equinox at 2024-03-20 03:06 UTC
+		let result = Astronomy::solar_term(2024, 0.0);
+		assert_eq!(result.date(), NaiveDate::from_ymd_opt(2024, 3, 20).unwrap());
+		assert_eq!(result.hour(), 3);
+		// qingming at 2024-04-04 07:02 UTC
+		let result = Astronomy::solar_term(2024, 15.0);
+		assert_eq!(result.date(), NaiveDate::from_ymd_opt(2024, 4, 4).unwrap());
+		assert!((6..=7).contains(&result.hour()));
+	}
+
+	#[test]
+	fn test_julian_day_round_trip() {
+		let time = NaiveDate::from_ymd_opt(2000, 1, 1)
+			.unwrap()
+			.and_hms_opt(12, 0, 0)
+			.unwrap();
+		assert_eq!(Astronomy::to_julian_day(time), 2_451_545.0);
+		assert_eq!(Astronomy::from_julian_day(2_451_545.0), time);
+	}
+}
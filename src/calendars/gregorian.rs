@@ -0,0 +1,84 @@
+use chrono::{Datelike, NaiveDate};
+
+pub struct GregorianCalendar {}
+
+/// Julian day numbers of the (proleptic) gregorian calendar
+impl GregorianCalendar {
+	/// Gets the julian day number of the given gregorian date
+	///
+	/// # Example
+	///
+	/// ```
+	/// use horus::calendars::gregorian::GregorianCalendar;
+	///
+	/// assert_eq!(GregorianCalendar::to_julian_day(2000, 1, 1), 2_451_545);
+	/// ```
+	pub fn to_julian_day(year: i32, month: u32, day: u32) -> i64 {
+		let a = (14 - month as i64) / 12;
+		let y = year as i64 + 4800 - a;
+		let m = month as i64 + 12 * a - 3;
+
+		day as i64 + (153 * m + 2) / 5 + 365 * y + y / 4 - y / 100 + y / 400 - 32045
+	}
+
+	/// Gets the gregorian date (year, month, day) of the given julian day number
+	pub fn from_julian_day(julian_day: i64) -> (i32, u32, u32) {
+		let a = julian_day + 32044;
+		let b = (4 * a + 3) / 146_097;
+		let c = a - (146_097 * b) / 4;
+		let d = (4 * c + 3) / 1461;
+		let e = c - (1461 * d) / 4;
+		let m = (5 * e + 2) / 153;
+
+		let day = e - (153 * m + 2) / 5 + 1;
+		let month = m + 3 - 12 * (m / 10);
+		let year = 100 * b + d - 4800 + m / 10;
+
+		(year as i32, month as u32, day as u32)
+	}
+
+	/// Gets the julian day number of the given date
+	pub fn date_to_julian_day(date: NaiveDate) -> i64 {
+		Self::to_julian_day(date.year(), date.month(), date.day())
+	}
+
+	/// Gets the date of the given julian day number
+	pub fn date_from_julian_day(julian_day: i64) -> Option<NaiveDate> {
+		let (year, month, day) = Self::from_julian_day(julian_day);
+		NaiveDate::from_ymd_opt(year, month, day)
+	}
+
+	/// Checks if the given year is a leap year in the gregorian calendar
+	pub fn is_leap_year(year: i32) -> bool {
+		(year.rem_euclid(4) == 0 && year.rem_euclid(100) != 0) || year.rem_euclid(400) == 0
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use chrono::NaiveDate;
+
+	use super::GregorianCalendar;
+
+	#[test]
+	fn test_to_julian_day() {
+		assert_eq!(GregorianCalendar::to_julian_day(1582, 10, 15), 2_299_161);
+		assert_eq!(GregorianCalendar::to_julian_day(1858, 11, 17), 2_400_001);
+	}
+
+	#[test]
+	fn test_round_trip() {
+		for julian_day in 2_000_000..2_100_000 {
+			let date = GregorianCalendar::date_from_julian_day(julian_day).unwrap();
+			assert_eq!(GregorianCalendar::date_to_julian_day(date), julian_day);
+		}
+	}
+
+	#[test]
+	fn test_date_from_julian_day() {
+		assert_eq!(
+			GregorianCalendar::date_from_julian_day(2_460_311),
+			NaiveDate::from_ymd_opt(2024, 1, 1)
+		);
+	}
+}
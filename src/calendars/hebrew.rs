@@ -0,0 +1,271 @@
+use chrono::{Datelike, Duration, NaiveDate, NaiveDateTime};
+
+/// Days from the common era (0001-01-01 is day 1) of the 1st Tishrei of the year 1
+const EPOCH: i64 = -1_373_427;
+/// Parts (1/1080 of an hour) of a day
+const PARTS_PER_DAY: i64 = 25_920;
+/// Length of a mean lunar month, 29 days 12 hours and 793 parts
+const PARTS_PER_MONTH: i64 = 29 * PARTS_PER_DAY + 13_753;
+
+pub struct HebrewCalendar {}
+
+/// Arithmetic hebrew calendar
+///
+/// Months are numbered from Nisan (1) to Adar (12) and Adar II (13) in leap years, the year starts with Tishrei (7).
+/// The new year is calculated from the molad of Tishrei and postponed according to the dechiyot
+/// (molad zaken, lo ADU rosh, GaTaRaD and BeTUTaKPaT).
+impl HebrewCalendar {
+	pub const NISAN: u32 = 1;
+	pub const IYAR: u32 = 2;
+	pub const SIVAN: u32 = 3;
+	pub const TAMMUZ: u32 = 4;
+	pub const AV: u32 = 5;
+	pub const ELUL: u32 = 6;
+	pub const TISHREI: u32 = 7;
+	pub const MARHESHVAN: u32 = 8;
+	pub const KISLEV: u32 = 9;
+	pub const TEVET: u32 = 10;
+	pub const SHEVAT: u32 = 11;
+	pub const ADAR: u32 = 12;
+	pub const ADAR_II: u32 = 13;
+
+	/// Checks if the given year has 13 months
+	pub fn is_leap_year(year: i32) -> bool {
+		(7 * year as i64 + 1).rem_euclid(19) < 7
+	}
+
+	/// Gets the last month of the given year, which is Adar II in leap years
+	pub fn last_month_of_year(year: i32) -> u32 {
+		if Self::is_leap_year(year) {
+			Self::ADAR_II
+		} else {
+			Self::ADAR
+		}
+	}
+
+	/// Gets the molad (mean conjunction) of the given month in Jerusalem mean time
+	///
+	/// # Example
+	///
+	/// ```
+	/// use horus::calendars::hebrew::HebrewCalendar;
+	/// use chrono::NaiveDate;
+	///
+	/// let result = HebrewCalendar::molad(5785, HebrewCalendar::TISHREI);
+	///
+	/// assert_eq!(result, NaiveDate::from_ymd_opt(2024, 10, 3).unwrap().and_hms_opt(3, 21, 43).unwrap());
+	/// ```
+	pub fn molad(year: i32, month: u32) -> NaiveDateTime {
+		let year = if month < Self::TISHREI { year + 1 } else { year } as i64;
+		let months_elapsed = month as i64 - Self::TISHREI as i64 + (235 * year - 234).div_euclid(19);
+		// the molad of the creation (BaHaRaD) took place 876 parts before the midnight of the epoch
+		let parts = EPOCH * PARTS_PER_DAY - 876 + months_elapsed * PARTS_PER_MONTH;
+		let days = parts.div_euclid(PARTS_PER_DAY);
+		let seconds = parts.rem_euclid(PARTS_PER_DAY) * 10 / 3;
+
+		Self::from_days(days).and_hms_opt(0, 0, 0).unwrap() + Duration::seconds(seconds)
+	}
+
+	/// Gets the gregorian date of the 1st Tishrei of the given year
+	///
+	/// # Example
+	///
+	/// ```
+	/// use horus::calendars::hebrew::HebrewCalendar;
+	/// use chrono::NaiveDate;
+	///
+	/// assert_eq!(HebrewCalendar::new_year(5785), NaiveDate::from_ymd_opt(2024, 10, 3).unwrap());
+	/// ```
+	pub fn new_year(year: i32) -> NaiveDate {
+		Self::from_days(Self::new_year_days(year))
+	}
+
+	/// Gets the number of days of the given year
+	pub fn days_of_year(year: i32) -> i64 {
+		Self::new_year_days(year + 1) - Self::new_year_days(year)
+	}
+
+	/// Gets the number of days of the given month
+	pub fn days_of_month(year: i32, month: u32) -> u32 {
+		let days_of_year = Self::days_of_year(year);
+		match month {
+			Self::IYAR | Self::TAMMUZ | Self::ELUL | Self::TEVET | Self::ADAR_II => 29,
+			Self::ADAR if !Self::is_leap_year(year) => 29,
+			// Marheshvan has 30 days in complete years
+			Self::MARHESHVAN if days_of_year % 10 != 5 => 29,
+			// Kislev has 29 days in deficient years
+			Self::KISLEV if days_of_year % 10 == 3 => 29,
+			_ => 30,
+		}
+	}
+
+	/// Converts the given hebrew date into a gregorian date. If the date doesn't exist, None will be returned.
+	///
+	/// # Example
+	///
+	/// ```
+	/// use horus::calendars::hebrew::HebrewCalendar;
+	/// use chrono::NaiveDate;
+	///
+	/// let result = HebrewCalendar::to_gregorian(5784, HebrewCalendar::NISAN, 15);
+	///
+	/// assert_eq!(result, NaiveDate::from_ymd_opt(2024, 4, 23));
+	/// ```
+	pub fn to_gregorian(year: i32, month: u32, day: u32) -> Option<NaiveDate> {
+		if month == 0 || month > Self::last_month_of_year(year) || day == 0 || day > Self::days_of_month(year, month) {
+			return None;
+		}
+
+		let mut days = Self::new_year_days(year) + day as i64 - 1;
+		if month < Self::TISHREI {
+			for m in Self::TISHREI..=Self::last_month_of_year(year) {
+				days += Self::days_of_month(year, m) as i64;
+			}
+			for m in Self::NISAN..month {
+				days += Self::days_of_month(year, m) as i64;
+			}
+		} else {
+			for m in Self::TISHREI..month {
+				days += Self::days_of_month(year, m) as i64;
+			}
+		}
+
+		Some(Self::from_days(days))
+	}
+
+	/// Converts the given gregorian date into a hebrew date (year, month, day)
+	pub fn from_gregorian(date: NaiveDate) -> (i32, u32, u32) {
+		let days = date.num_days_from_ce() as i64;
+		// mean length of a year is 35975351/98496 days
+		let mut year = ((days - EPOCH) * 98_496).div_euclid(35_975_351) as i32;
+		while Self::new_year_days(year + 1) <= days {
+			year += 1;
+		}
+
+		let mut month = if date < Self::to_gregorian(year, Self::NISAN, 1).unwrap() {
+			Self::TISHREI
+		} else {
+			Self::NISAN
+		};
+		while date > Self::to_gregorian(year, month, Self::days_of_month(year, month)).unwrap() {
+			month += 1;
+		}
+
+		let day = (date - Self::to_gregorian(year, month, 1).unwrap()).num_days() + 1;
+
+		(year, month, day as u32)
+	}
+
+	/// Gets the gregorian date of a hebrew month and day which takes place in the given gregorian year
+	pub fn fixed_date_in_gregorian_year(gregorian_year: i32, month: u32, day: u32) -> Option<NaiveDate> {
+		let year = if month >= Self::TISHREI {
+			gregorian_year + 3761
+		} else {
+			gregorian_year + 3760
+		};
+
+		Self::to_gregorian(year, month, day).filter(|date| date.year() == gregorian_year)
+	}
+
+	fn elapsed_days(year: i32) -> i64 {
+		let months_elapsed = (235 * year as i64 - 234).div_euclid(19);
+		let parts_elapsed = 12_084 + 13_753 * months_elapsed;
+		let days = 29 * months_elapsed + parts_elapsed.div_euclid(PARTS_PER_DAY);
+
+		// lo ADU rosh, the new year mustn't fall on a sunday, wednesday or friday
+		if (3 * (days + 1)).rem_euclid(7) < 3 {
+			days + 1
+		} else {
+			days
+		}
+	}
+
+	fn year_length_correction(year: i32) -> i64 {
+		let previous = Self::elapsed_days(year - 1);
+		let current = Self::elapsed_days(year);
+		let next = Self::elapsed_days(year + 1);
+
+		if next - current == 356 {
+			// GaTaRaD, otherwise the year would be too long
+			2
+		} else if current - previous == 382 {
+			// BeTUTaKPaT, otherwise the previous leap year would be too short
+			1
+		} else {
+			0
+		}
+	}
+
+	fn new_year_days(year: i32) -> i64 {
+		EPOCH + Self::elapsed_days(year) + Self::year_length_correction(year)
+	}
+
+	fn from_days(days: i64) -> NaiveDate {
+		NaiveDate::from_num_days_from_ce_opt(days as i32).unwrap()
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use chrono::NaiveDate;
+
+	use super::HebrewCalendar;
+
+	#[test]
+	fn test_is_leap_year() {
+		assert!(HebrewCalendar::is_leap_year(5784));
+		assert!(!HebrewCalendar::is_leap_year(5785));
+	}
+
+	#[test]
+	fn test_new_year() {
+		assert_eq!(
+			HebrewCalendar::new_year(5783),
+			NaiveDate::from_ymd_opt(2022, 9, 26).unwrap()
+		);
+		assert_eq!(
+			HebrewCalendar::new_year(5784),
+			NaiveDate::from_ymd_opt(2023, 9, 16).unwrap()
+		);
+		assert_eq!(
+			HebrewCalendar::new_year(5786),
+			NaiveDate::from_ymd_opt(2025, 9, 23).unwrap()
+		);
+	}
+
+	#[test]
+	fn test_days_of_year() {
+		for year in 5000..6000 {
+			let days = HebrewCalendar::days_of_year(year);
+			if HebrewCalendar::is_leap_year(year) {
+				assert!([383, 384, 385].contains(&days));
+			} else {
+				assert!([353, 354, 355].contains(&days));
+			}
+		}
+	}
+
+	#[test]
+	fn test_round_trip() {
+		let mut date = NaiveDate::from_ymd_opt(1900, 1, 1).unwrap();
+		while date < NaiveDate::from_ymd_opt(2100, 1, 1).unwrap() {
+			let (year, month, day) = HebrewCalendar::from_gregorian(date);
+			assert_eq!(HebrewCalendar::to_gregorian(year, month, day), Some(date));
+			date = date.succ_opt().unwrap();
+		}
+	}
+
+	#[test]
+	fn test_to_gregorian_invalid_date() {
+		assert_eq!(HebrewCalendar::to_gregorian(5785, HebrewCalendar::ADAR_II, 1), None);
+		assert_eq!(HebrewCalendar::to_gregorian(5785, HebrewCalendar::IYAR, 30), None);
+	}
+
+	#[test]
+	fn test_fixed_date_in_gregorian_year() {
+		let result = HebrewCalendar::fixed_date_in_gregorian_year(2024, HebrewCalendar::TISHREI, 10);
+		assert_eq!(result, NaiveDate::from_ymd_opt(2024, 10, 12));
+		let result = HebrewCalendar::fixed_date_in_gregorian_year(2024, HebrewCalendar::SIVAN, 6);
+		assert_eq!(result, NaiveDate::from_ymd_opt(2024, 6, 12));
+	}
+}
@@ -0,0 +1,233 @@
+use chrono::{Datelike, Duration, NaiveDate};
+
+use super::astronomy::Astronomy;
+
+/// Days from the common era (0001-01-01 is day 1) of the 1st Muharram of the year 1 in the civil arithmetic calendar
+const EPOCH: i64 = 227_015;
+/// Latitude of the kaaba in mecca
+const MECCA_LATITUDE: f64 = 21.4225;
+/// Longitude of the kaaba in mecca
+const MECCA_LONGITUDE: f64 = 39.8262;
+/// Offset of the arabia standard time from UTC in hours
+const MECCA_UTC_OFFSET: i64 = 3;
+/// Number of months from the 1st Muharram of the year 1 until the month which started with lunation 0
+/// (Shawwal 1420, which started at the 8th january 2000)
+const LUNATION_OFFSET: i64 = 17_037;
+
+/// Variants of the islamic (hijri) calendar
+///
+/// Months are numbered from Muharram (1) to Dhu al-Hijjah (12). The actual beginning of a month depends on the
+/// sighting of the crescent moon, so both variants only estimate the dates which are announced by the authorities.
+///
+/// * `Arithmetic` is the tabular civil calendar with 11 leap years in a cycle of 30 years
+/// * `UmmAlQura` is the calendar of saudi arabia. A month starts at the day after the conjunction, if the
+///   conjunction takes place before the sunset in mecca and otherwise one day later.
+///
+/// # Example
+///
+/// ```
+/// use horus::calendars::hijri::HijriCalendar;
+/// use chrono::NaiveDate;
+///
+/// let result = HijriCalendar::UmmAlQura.to_gregorian(1445, HijriCalendar::SHAWWAL, 1);
+///
+/// assert_eq!(result, NaiveDate::from_ymd_opt(2024, 4, 10));
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum HijriCalendar {
+	Arithmetic,
+	UmmAlQura,
+}
+
+impl HijriCalendar {
+	pub const MUHARRAM: u32 = 1;
+	pub const SAFAR: u32 = 2;
+	pub const RABI_AL_AWWAL: u32 = 3;
+	pub const RABI_AL_THANI: u32 = 4;
+	pub const JUMADA_AL_AWWAL: u32 = 5;
+	pub const JUMADA_AL_THANI: u32 = 6;
+	pub const RAJAB: u32 = 7;
+	pub const SHABAN: u32 = 8;
+	pub const RAMADAN: u32 = 9;
+	pub const SHAWWAL: u32 = 10;
+	pub const DHU_AL_QADAH: u32 = 11;
+	pub const DHU_AL_HIJJAH: u32 = 12;
+
+	/// Checks if the given year has 355 days in the arithmetic calendar
+	pub fn is_leap_year(year: i32) -> bool {
+		(14 + 11 * year as i64).rem_euclid(30) < 11
+	}
+
+	/// Gets the number of days of the given month in the calendar
+	pub fn days_of_month(&self, year: i32, month: u32) -> Option<u32> {
+		if !(Self::MUHARRAM..=Self::DHU_AL_HIJJAH).contains(&month) {
+			return None;
+		}
+
+		let index = Self::month_index(year, month);
+		Some((self.month_start(index + 1) - self.month_start(index)) as u32)
+	}
+
+	/// Converts the given hijri date into a gregorian date. If the date doesn't exist, None will be returned.
+	pub fn to_gregorian(&self, year: i32, month: u32, day: u32) -> Option<NaiveDate> {
+		if day == 0 || day > self.days_of_month(year, month)? {
+			return None;
+		}
+
+		Some(Self::from_days(
+			self.month_start(Self::month_index(year, month)) + day as i64 - 1,
+		))
+	}
+
+	/// Converts the given gregorian date into a hijri date (year, month, day)
+	///
+	/// # Example
+	///
+	/// ```
+	/// use horus::calendars::hijri::HijriCalendar;
+	/// use chrono::NaiveDate;
+	///
+	/// let result = HijriCalendar::Arithmetic.from_gregorian(NaiveDate::from_ymd_opt(622, 7, 19).unwrap());
+	///
+	/// assert_eq!(result, (1, HijriCalendar::MUHARRAM, 1));
+	/// ```
+	pub fn from_gregorian(&self, date: NaiveDate) -> (i32, u32, u32) {
+		let days = date.num_days_from_ce() as i64;
+		let year = (30 * (days - EPOCH) + 10_646).div_euclid(10_631);
+		let month =
+			((11 * (days - Self::arithmetic_month_start(Self::month_index(year as i32, 1))) + 330) / 325).clamp(1, 12);
+		let mut index = Self::month_index(year as i32, month as u32);
+
+		while self.month_start(index) > days {
+			index -= 1;
+		}
+		while self.month_start(index + 1) <= days {
+			index += 1;
+		}
+
+		(
+			index.div_euclid(12) as i32 + 1,
+			index.rem_euclid(12) as u32 + 1,
+			(days - self.month_start(index)) as u32 + 1,
+		)
+	}
+
+	/// Gets the gregorian dates of the given hijri month and day in the given gregorian year. As the hijri year is
+	/// about 11 days shorter than the gregorian year, a date can take place twice or not at all.
+	pub fn fixed_dates_in_gregorian_year(&self, gregorian_year: i32, month: u32, day: u32) -> Vec<NaiveDate> {
+		let (first_year, _, _) = self.from_gregorian(NaiveDate::from_ymd_opt(gregorian_year, 1, 1).unwrap());
+		let (last_year, _, _) = self.from_gregorian(NaiveDate::from_ymd_opt(gregorian_year, 12, 31).unwrap());
+
+		(first_year..=last_year)
+			.filter_map(|year| self.to_gregorian(year, month, day))
+			.filter(|date| date.year() == gregorian_year)
+			.collect()
+	}
+
+	/// Number of months since the 1st Muharram of the year 1
+	fn month_index(year: i32, month: u32) -> i64 {
+		(year as i64 - 1) * 12 + month as i64 - 1
+	}
+
+	/// Days from the common era of the first day of the month with the given index
+	fn month_start(&self, index: i64) -> i64 {
+		match self {
+			HijriCalendar::Arithmetic => Self::arithmetic_month_start(index),
+			HijriCalendar::UmmAlQura => Self::umm_al_qura_month_start(index),
+		}
+	}
+
+	fn arithmetic_month_start(index: i64) -> i64 {
+		let year = index.div_euclid(12) + 1;
+		let month = index.rem_euclid(12) + 1;
+
+		EPOCH - 1 + (year - 1) * 354 + (3 + 11 * year).div_euclid(30) + 29 * (month - 1) + month / 2 + 1
+	}
+
+	fn umm_al_qura_month_start(index: i64) -> i64 {
+		let conjunction = Astronomy::new_moon(index - LUNATION_OFFSET) + Duration::hours(MECCA_UTC_OFFSET);
+		let sunset =
+			Astronomy::sunset(conjunction.date(), MECCA_LATITUDE, MECCA_LONGITUDE) + Duration::hours(MECCA_UTC_OFFSET);
+		let days = conjunction.date().num_days_from_ce() as i64;
+
+		if conjunction < sunset {
+			days + 1
+		} else {
+			days + 2
+		}
+	}
+
+	fn from_days(days: i64) -> NaiveDate {
+		NaiveDate::from_num_days_from_ce_opt(days as i32).unwrap()
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use chrono::NaiveDate;
+
+	use super::HijriCalendar;
+
+	#[test]
+	fn test_is_leap_year() {
+		assert!(HijriCalendar::is_leap_year(1445));
+		assert!(!HijriCalendar::is_leap_year(1444));
+		assert_eq!((1..=30).filter(|year| HijriCalendar::is_leap_year(*year)).count(), 11);
+	}
+
+	#[test]
+	fn test_arithmetic_days_of_month() {
+		let calendar = HijriCalendar::Arithmetic;
+		assert_eq!(calendar.days_of_month(1444, HijriCalendar::MUHARRAM), Some(30));
+		assert_eq!(calendar.days_of_month(1444, HijriCalendar::SAFAR), Some(29));
+		assert_eq!(calendar.days_of_month(1444, HijriCalendar::DHU_AL_HIJJAH), Some(29));
+		assert_eq!(calendar.days_of_month(1445, HijriCalendar::DHU_AL_HIJJAH), Some(30));
+		assert_eq!(calendar.days_of_month(1445, 13), None);
+	}
+
+	#[test]
+	fn test_umm_al_qura_to_gregorian() {
+		let calendar = HijriCalendar::UmmAlQura;
+		let result = calendar.to_gregorian(1444, HijriCalendar::SHAWWAL, 1);
+		assert_eq!(result, NaiveDate::from_ymd_opt(2023, 4, 21));
+		let result = calendar.to_gregorian(1445, HijriCalendar::RAMADAN, 1);
+		assert_eq!(result, NaiveDate::from_ymd_opt(2024, 3, 11));
+		let result = calendar.to_gregorian(1445, HijriCalendar::DHU_AL_HIJJAH, 10);
+		assert_eq!(result, NaiveDate::from_ymd_opt(2024, 6, 16));
+		let result = calendar.to_gregorian(1446, HijriCalendar::MUHARRAM, 1);
+		assert_eq!(result, NaiveDate::from_ymd_opt(2024, 7, 7));
+	}
+
+	#[test]
+	fn test_umm_al_qura_days_of_month() {
+		let calendar = HijriCalendar::UmmAlQura;
+		for year in 1420..1500 {
+			for month in 1..=12 {
+				let days = calendar.days_of_month(year, month).unwrap();
+				assert!(days == 29 || days == 30);
+			}
+		}
+	}
+
+	#[test]
+	fn test_round_trip() {
+		for calendar in [HijriCalendar::Arithmetic, HijriCalendar::UmmAlQura] {
+			let mut date = NaiveDate::from_ymd_opt(2000, 1, 1).unwrap();
+			while date < NaiveDate::from_ymd_opt(2050, 1, 1).unwrap() {
+				let (year, month, day) = calendar.from_gregorian(date);
+				assert_eq!(calendar.to_gregorian(year, month, day), Some(date));
+				date = date.succ_opt().unwrap();
+			}
+		}
+	}
+
+	#[test]
+	fn test_fixed_dates_in_gregorian_year() {
+		let calendar = HijriCalendar::UmmAlQura;
+		let result = calendar.fixed_dates_in_gregorian_year(2024, HijriCalendar::SHAWWAL, 1);
+		assert_eq!(result, vec![NaiveDate::from_ymd_opt(2024, 4, 10).unwrap()]);
+		// eid al-fitr took place twice in 2000
+		let result = calendar.fixed_dates_in_gregorian_year(2000, HijriCalendar::SHAWWAL, 1);
+		assert_eq!(result.len(), 2);
+	}
+}
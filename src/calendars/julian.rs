@@ -0,0 +1,154 @@
+use chrono::NaiveDate;
+
+use super::CalendarSystem;
+
+pub struct JulianCalendar {}
+
+/// Conversion between the julian calendar and the (proleptic) gregorian calendar
+///
+/// The conversion uses the julian day number and is therefore valid for any year, not only for the years in
+/// which the difference between both calendars is 13 days.
+impl JulianCalendar {
+	/// Gets the julian day number of the given julian date
+	///
+	/// # Example
+	///
+	/// ```
+	/// use horus::calendars::julian::JulianCalendar;
+	///
+	/// assert_eq!(JulianCalendar::to_julian_day(2000, 1, 1), 2_451_558);
+	/// ```
+	pub fn to_julian_day(year: i32, month: u32, day: u32) -> i64 {
+		let a = (14 - month as i64) / 12;
+		let y = year as i64 + 4800 - a;
+		let m = month as i64 + 12 * a - 3;
+
+		day as i64 + (153 * m + 2) / 5 + 365 * y + y / 4 - 32083
+	}
+
+	/// Gets the julian date (year, month, day) of the given julian day number
+	pub fn from_julian_day(julian_day: i64) -> (i32, u32, u32) {
+		let c = julian_day + 32082;
+		let d = (4 * c + 3) / 1461;
+		let e = c - (1461 * d) / 4;
+		let m = (5 * e + 2) / 153;
+
+		let day = e - (153 * m + 2) / 5 + 1;
+		let month = m + 3 - 12 * (m / 10);
+		let year = d - 4800 + m / 10;
+
+		(year as i32, month as u32, day as u32)
+	}
+
+	/// Converts the given julian date into a gregorian date
+	///
+	/// # Example
+	///
+	/// ```
+	/// use horus::calendars::julian::JulianCalendar;
+	/// use chrono::NaiveDate;
+	///
+	/// assert_eq!(JulianCalendar::to_gregorian(2023, 12, 25), NaiveDate::from_ymd_opt(2024, 1, 7));
+	/// assert_eq!(JulianCalendar::to_gregorian(2100, 12, 25), NaiveDate::from_ymd_opt(2101, 1, 8));
+	/// ```
+	pub fn to_gregorian(year: i32, month: u32, day: u32) -> Option<NaiveDate> {
+		CalendarSystem::Julian.to_gregorian(year, month, day)
+	}
+
+	/// Converts the given gregorian date into a julian date (year, month, day)
+	pub fn from_gregorian(date: NaiveDate) -> (i32, u32, u32) {
+		CalendarSystem::Julian.from_gregorian(date)
+	}
+
+	/// Gets the gregorian date of a fixed julian date (month and day) which takes place in the given gregorian year
+	///
+	/// # Example
+	///
+	/// ```
+	/// use horus::calendars::julian::JulianCalendar;
+	/// use chrono::NaiveDate;
+	///
+	/// assert_eq!(JulianCalendar::fixed_date_in_gregorian_year(2024, 12, 25), NaiveDate::from_ymd_opt(2024, 1, 7));
+	/// ```
+	pub fn fixed_date_in_gregorian_year(gregorian_year: i32, month: u32, day: u32) -> Option<NaiveDate> {
+		CalendarSystem::Julian.fixed_date_in_gregorian_year(gregorian_year, month, day)
+	}
+
+	/// Gets the julian date (year, month, day) of easter sunday for the given julian year
+	pub fn easter_sunday(year: i32) -> (i32, u32, u32) {
+		// Meeus, Astronomical Algorithms - Julian Easter
+		let a = year.rem_euclid(4);
+		let b = year.rem_euclid(7);
+		let c = year.rem_euclid(19);
+		let d = (19 * c + 15) % 30;
+		let e = (2 * a + 4 * b - d + 34) % 7;
+
+		let month = (d + e + 114) / 31;
+		let day = (d + e + 114) % 31 + 1;
+
+		(year, month as u32, day as u32)
+	}
+
+	/// Checks if the given year is a leap year in the julian calendar
+	pub fn is_leap_year(year: i32) -> bool {
+		year.rem_euclid(4) == 0
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use chrono::NaiveDate;
+
+	use super::JulianCalendar;
+
+	#[test]
+	fn test_to_gregorian() {
+		// introduction of the gregorian calendar
+		assert_eq!(
+			JulianCalendar::to_gregorian(1582, 10, 5),
+			NaiveDate::from_ymd_opt(1582, 10, 15)
+		);
+		// russian calendar reform
+		assert_eq!(
+			JulianCalendar::to_gregorian(1918, 2, 1),
+			NaiveDate::from_ymd_opt(1918, 2, 14)
+		);
+		assert_eq!(
+			JulianCalendar::to_gregorian(1900, 2, 29),
+			NaiveDate::from_ymd_opt(1900, 3, 13)
+		);
+		assert_eq!(JulianCalendar::to_gregorian(2023, 2, 29), None);
+	}
+
+	#[test]
+	fn test_from_gregorian() {
+		let date = NaiveDate::from_ymd_opt(2024, 1, 7).unwrap();
+		assert_eq!(JulianCalendar::from_gregorian(date), (2023, 12, 25));
+	}
+
+	#[test]
+	fn test_round_trip() {
+		for julian_day in 2_000_000..2_100_000 {
+			let (year, month, day) = JulianCalendar::from_julian_day(julian_day);
+			assert_eq!(JulianCalendar::to_julian_day(year, month, day), julian_day);
+		}
+	}
+
+	#[test]
+	fn test_fixed_date_in_gregorian_year() {
+		assert_eq!(
+			JulianCalendar::fixed_date_in_gregorian_year(1900, 12, 25),
+			NaiveDate::from_ymd_opt(1900, 1, 6)
+		);
+		assert_eq!(
+			JulianCalendar::fixed_date_in_gregorian_year(2101, 12, 25),
+			NaiveDate::from_ymd_opt(2101, 1, 8)
+		);
+	}
+
+	#[test]
+	fn test_easter_sunday() {
+		assert_eq!(JulianCalendar::easter_sunday(2022), (2022, 4, 11));
+		assert_eq!(JulianCalendar::easter_sunday(2024), (2024, 4, 22));
+	}
+}
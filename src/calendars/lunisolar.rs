@@ -0,0 +1,319 @@
+use chrono::{Datelike, Duration, NaiveDate};
+
+use super::astronomy::Astronomy;
+
+/// Tables of the lunisolar years from 1900 until 2100, calculated from the new moons and solar terms at the
+/// meridian of the time zone. Each entry contains the day of the year of the new year (bits 17 and above), the leap
+/// month (bits 13 to 16, 0 if there is no leap month) and one bit for each month which has 30 instead of 29 days
+/// (bit 0 is the first month, the leap month follows the month it repeats).
+/// China, UTC+8
+const YEARS_UTC8: [u32; 201] = [
+	0x03f16d2, 0x0640752, 0x04e0ea5, 0x03ab64a, 0x05e064b, 0x0460a9b, 0x0329556, 0x058056a, 0x0420b59, 0x02c5752,
+	0x0520752, 0x03cdb25, 0x0620b25, 0x04a0a4b, 0x034b2ab, 0x05a0aad, 0x046056a, 0x02e4b69, 0x0540da9, 0x040fd92,
+	0x0660d92, 0x04e0d25, 0x038ba4d, 0x05e0a56, 0x04802b6, 0x03095b5, 0x05806d4, 0x0420ea9, 0x02e5e92, 0x0520e92,
+	0x03ccd26, 0x060052b, 0x04a0a57, 0x034b2b6, 0x05a0b5a, 0x04606d4, 0x0306ec9, 0x0540749, 0x03ef693, 0x0640a93,
+	0x04e052b, 0x036ca5b, 0x05c0aad, 0x048056a, 0x0329b55, 0x0580ba4, 0x0420b49, 0x02c5a93, 0x0520a95, 0x03af52d,
+	0x0600536, 0x04a0aad, 0x036b5aa, 0x05a05b2, 0x0440da5, 0x0307d4a, 0x0560d4a, 0x03f0a95, 0x0620a97, 0x04e0556,
+	0x038cab5, 0x05c0ad5, 0x04806d2, 0x0328ea5, 0x0580ea5, 0x042064a, 0x02a6c97, 0x0500a9b, 0x03cf55a, 0x060056a,
+	0x04a0b69, 0x036b752, 0x05c0b52, 0x0440b25, 0x02e964b, 0x0540a4b, 0x03f14ab, 0x06202ad, 0x04c056d, 0x038cb69,
+	0x05e0da9, 0x0480d92, 0x0329d25, 0x0580d25, 0x0435a4d, 0x0660a56, 0x05002b6, 0x03ac5b5, 0x06006d5, 0x04a0ea9,
+	0x036be92, 0x05c0e92, 0x0460d26, 0x02e6a56, 0x0520a57, 0x03f14d6, 0x064035a, 0x04c06d5, 0x038b6c9, 0x05e0749,
+	0x0480693, 0x030952b, 0x056052b, 0x0400a5b, 0x02c555a, 0x050056a, 0x03afb55, 0x0620ba4, 0x04c0b49, 0x034ba93,
+	0x05a0a95, 0x044052d, 0x02e8aad, 0x0520ab5, 0x03f35aa, 0x06405d2, 0x04e0da5, 0x038dd4a, 0x05e0d4a, 0x0480c95,
+	0x032952e, 0x0560556, 0x0400ab5, 0x02c55b2, 0x05206d2, 0x03acea5, 0x0600725, 0x04a064b, 0x034ac97, 0x0580cab,
+	0x044055a, 0x02e6ad6, 0x0540b69, 0x03f7752, 0x0640b52, 0x04e0b25, 0x038da4b, 0x05c0a4b, 0x04604ab, 0x030a55b,
+	0x05605ad, 0x0400b6a, 0x02c5b52, 0x0520d92, 0x03cfd25, 0x0600d25, 0x04a0a55, 0x034b4ad, 0x05a04b6, 0x04205b5,
+	0x02e6daa, 0x0540ec9, 0x0411e92, 0x0640e92, 0x04e0d26, 0x038ca56, 0x05c0a57, 0x0460556, 0x03086d5, 0x0560755,
+	0x0420749, 0x02a6e93, 0x0500693, 0x03af52b, 0x060052b, 0x0480a5b, 0x034b55a, 0x05a056a, 0x0440b65, 0x02e974a,
+	0x0540b4a, 0x03f1a95, 0x0640a95, 0x04c052d, 0x036caad, 0x05c0ab5, 0x04805aa, 0x0308ba5, 0x0560da5, 0x0420d4a,
+	0x02c7c95, 0x0500c96, 0x03af94e, 0x0600556, 0x04a0ab5, 0x034b5b2, 0x05a06d2, 0x0440ea5, 0x0308e4a, 0x052068b,
+	0x03d0c97, 0x06204ab, 0x04c055b, 0x036cad6, 0x05c0b6a, 0x0480752, 0x0329725, 0x0560b45, 0x0400a8b, 0x02a549b,
+	0x05004ab,
+];
+/// Korea, UTC+9
+const YEARS_UTC9: [u32; 201] = [
+	0x03f16d2, 0x0640752, 0x04e0ea5, 0x03aad4a, 0x05e054b, 0x0460a97, 0x0329556, 0x058055a, 0x0420b55, 0x02c56d2,
+	0x0520752, 0x03cd725, 0x0620b25, 0x04a0a4b, 0x034b29b, 0x05a0aad, 0x046056a, 0x02e4b69, 0x0540ba9, 0x040fb52,
+	0x0660d92, 0x04e0d25, 0x038ba4d, 0x05e0956, 0x04802b5, 0x03095ad, 0x05806d4, 0x0420da9, 0x02e5d92, 0x0520e92,
+	0x03ccd26, 0x0600527, 0x04a0a57, 0x034b2b6, 0x05a0ada, 0x04606d4, 0x0306ea9, 0x0540749, 0x03ef693, 0x0640a93,
+	0x04e052b, 0x036ca5b, 0x05c096d, 0x0480b6a, 0x0349b54, 0x0580ba4, 0x0420b49, 0x02c5a93, 0x0520a95, 0x03af52b,
+	0x060052d, 0x04a0aad, 0x036b56a, 0x05a0db2, 0x0460da4, 0x0307d49, 0x0560d4a, 0x03f1a95, 0x0640a96, 0x04e0556,
+	0x038cab5, 0x05c0ad5, 0x04806d2, 0x0328ea5, 0x0580ea5, 0x0420e4a, 0x02c6c96, 0x0500a9b, 0x03cf556, 0x060056a,
+	0x04a0b59, 0x036b752, 0x05c0752, 0x0440725, 0x02e964b, 0x0540a4b, 0x03f12ab, 0x06202ad, 0x04c056b, 0x038cb69,
+	0x05e0da9, 0x0480d92, 0x0329b25, 0x0580d25, 0x0435a4d, 0x0660a56, 0x05002b6, 0x03ad5ad, 0x06206d4, 0x04a0da9,
+	0x036bd92, 0x05c0e92, 0x0460d26, 0x02e6a56, 0x0520a57, 0x03f12b6, 0x0640b5a, 0x04e06d4, 0x038aec9, 0x05e0749,
+	0x0480693, 0x0309527, 0x056052b, 0x0400a5b, 0x02c555a, 0x050036a, 0x03afb55, 0x0620ba4, 0x04c0b49, 0x034ba93,
+	0x05a0a95, 0x044052d, 0x02e6a5d, 0x0520aad, 0x03f35aa, 0x06405d2, 0x04e0da5, 0x038bd4a, 0x05e0d4a, 0x0480a95,
+	0x032952d, 0x0560556, 0x0400ab5, 0x02c55aa, 0x05206d2, 0x03acea5, 0x0600ea5, 0x04c0e4a, 0x036ac96, 0x0580c9b,
+	0x044055a, 0x02e6ad5, 0x0540b69, 0x03f7752, 0x0640752, 0x04e0b25, 0x038d64b, 0x05c0a4b, 0x04604ab, 0x030a55b,
+	0x056056d, 0x0400b69, 0x02c5b52, 0x0520d92, 0x03cfd25, 0x0600d25, 0x04a0a4d, 0x034b4ad, 0x05a02b6, 0x04205b5,
+	0x02e6da9, 0x0540ea9, 0x0411d92, 0x0640e92, 0x04e0d26, 0x038ca56, 0x05c0a57, 0x04604d6, 0x03086b5, 0x05606d5,
+	0x0420ec9, 0x02c6e92, 0x0500693, 0x03af52b, 0x060052b, 0x0480a5b, 0x034b55a, 0x05a056a, 0x0440b55, 0x02e9749,
+	0x0540b49, 0x03f1a93, 0x0640a95, 0x04c052d, 0x036caad, 0x05c0ab5, 0x04805aa, 0x0308ba5, 0x0560da5, 0x0420d4a,
+	0x02c7a95, 0x0500c95, 0x03af52e, 0x0600556, 0x04a0ab5, 0x034b5b2, 0x05a06d2, 0x0440ea5, 0x0309e4a, 0x054064a,
+	0x03d0c97, 0x0620cab, 0x04e055a, 0x036cad5, 0x05c0b69, 0x0480752, 0x03296a5, 0x0560b25, 0x040064b, 0x02a7497,
+	0x05004ab,
+];
+/// Vietnam, UTC+7
+const YEARS_UTC7: [u32; 201] = [
+	0x03f16d2, 0x0640752, 0x04e06a5, 0x038b64b, 0x05e064b, 0x0460c9b, 0x032955a, 0x058056a, 0x0420b69, 0x02c5752,
+	0x0520b52, 0x03cdb25, 0x0620b25, 0x04a0a4b, 0x034b4ab, 0x05a02ad, 0x044056d, 0x02e6b69, 0x0540da9, 0x040fd92,
+	0x0660e92, 0x04e0d25, 0x038da4d, 0x05e0a56, 0x04802b6, 0x03095b5, 0x05806d4, 0x0420ea9, 0x02e5e92, 0x0520e92,
+	0x03ccd26, 0x060052b, 0x04a0a57, 0x034b4d6, 0x05a035a, 0x04406d5, 0x03076c9, 0x0540749, 0x03f1693, 0x0640a95,
+	0x04e052b, 0x036ca5b, 0x05c0aad, 0x048056a, 0x0329b55, 0x0580ba4, 0x0420b49, 0x02c5a95, 0x0520a95, 0x03af52d,
+	0x0600556, 0x04a0ab5, 0x036b5aa, 0x05a05d2, 0x0440da5, 0x0307d4a, 0x0560e4a, 0x03f0c96, 0x0620a97, 0x04e0556,
+	0x038cab5, 0x05c0ad9, 0x04806d2, 0x0328ea5, 0x0580725, 0x040064b, 0x02a6c97, 0x050049b, 0x03ae55b, 0x05e056b,
+	0x04a0b69, 0x036b752, 0x05c0b52, 0x0440b25, 0x02e9a4b, 0x0540a4d, 0x03f14ab, 0x06202ad, 0x04c05ad, 0x038cb6a,
+	0x05e0da9, 0x0480d92, 0x0329d25, 0x0580d25, 0x0420a55, 0x02a54ad, 0x05004b6, 0x03ae5b5, 0x06006d5, 0x04a0ec9,
+	0x036be92, 0x05c0e92, 0x0460d26, 0x02e6a56, 0x0520a57, 0x03f1556, 0x064056a, 0x04c0b55, 0x038b6c9, 0x05e0749,
+	0x0480693, 0x030952b, 0x056052b, 0x0400a5b, 0x02c555a, 0x050056a, 0x03aeb65, 0x0600ba5, 0x04c0d49, 0x034ba95,
+	0x05a0a95, 0x044052d, 0x02e8aad, 0x0520ab5, 0x03f35aa, 0x06405d2, 0x04e0da5, 0x038dd4a, 0x05e0e4a, 0x0480c96,
+	0x032992e, 0x0560556, 0x0400ab5, 0x02c55b2, 0x05206d2, 0x03acea5, 0x0600725, 0x04a064b, 0x034ac97, 0x05804ab,
+	0x042055b, 0x02e6ada, 0x0540b6a, 0x03f7752, 0x0640b92, 0x04e0b25, 0x038da4b, 0x05c0a4d, 0x04604ad, 0x030a95b,
+	0x05605ad, 0x0400baa, 0x02c5b52, 0x0520d92, 0x03cfd25, 0x0600d25, 0x04a0a55, 0x034b4ad, 0x05a04b6, 0x04206b5,
+	0x02e6daa, 0x0540eca, 0x0410e92, 0x0620e93, 0x04e0d26, 0x038ca56, 0x05c0a5b, 0x046055a, 0x0308ad5, 0x0560b55,
+	0x042074a, 0x02a6e93, 0x0500a93, 0x03af52b, 0x060052b, 0x0480a9b, 0x034b55a, 0x05a056a, 0x0440b65, 0x02e974a,
+	0x0540d4a, 0x03f1a95, 0x0640c95, 0x04c092d, 0x036caad, 0x05c0ab5, 0x04805aa, 0x0308da5, 0x0560ea5, 0x0420d4a,
+	0x02c7d15, 0x0500c96, 0x03af956, 0x0600556, 0x04a0ab5, 0x034b6b4, 0x05a06d4, 0x0440ea5, 0x0308e8a, 0x052068b,
+	0x03d1497, 0x06204ab, 0x04c095b, 0x036cada, 0x05c0b6a, 0x0480754, 0x0329725, 0x0560b45, 0x0400a8b, 0x02a552b,
+	0x05004ad,
+];
+
+/// Date of a lunisolar calendar, leap months repeat the number of the month before them
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct LunisolarDate {
+	pub year: i32,
+	pub month: u32,
+	pub leap_month: bool,
+	pub day: u32,
+}
+
+/// Chinese lunisolar calendar and its variants
+///
+/// A month starts at the day of the new moon and the month which contains the december solstice is the 11th month.
+/// If a year has 13 months, the first month without a principal solar term is a leap month. The variants use the
+/// same rules at the meridian of their current standard time, so the dates of a month can differ by one day.
+///
+/// The conversion is table-driven and supports the years from 1900 until 2100.
+///
+/// # Example
+///
+/// ```
+/// use horus::calendars::lunisolar::LunisolarCalendar;
+/// use chrono::NaiveDate;
+///
+/// assert_eq!(LunisolarCalendar::China.new_year(1985), NaiveDate::from_ymd_opt(1985, 2, 20));
+/// assert_eq!(LunisolarCalendar::Vietnam.new_year(1985), NaiveDate::from_ymd_opt(1985, 1, 21));
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum LunisolarCalendar {
+	China,
+	Korea,
+	Vietnam,
+}
+
+impl LunisolarCalendar {
+	pub const FIRST_YEAR: i32 = 1900;
+	pub const LAST_YEAR: i32 = 2100;
+
+	/// Gets the offset of the time zone of the calendar from UTC in hours
+	pub fn utc_offset(&self) -> i64 {
+		match self {
+			LunisolarCalendar::China => 8,
+			LunisolarCalendar::Korea => 9,
+			LunisolarCalendar::Vietnam => 7,
+		}
+	}
+
+	/// Gets the gregorian date of the first day of the given year. If the year isn't supported, None will be returned.
+	pub fn new_year(&self, year: i32) -> Option<NaiveDate> {
+		NaiveDate::from_yo_opt(year, self.entry(year)? >> 17)
+	}
+
+	/// Gets the leap month of the given year. If the year has no leap month or isn't supported, None will be returned.
+	pub fn leap_month(&self, year: i32) -> Option<u32> {
+		match (self.entry(year)? >> 13) & 0xf {
+			0 => None,
+			month => Some(month),
+		}
+	}
+
+	/// Gets the number of days of the given month. If the month doesn't exist, None will be returned.
+	pub fn days_of_month(&self, year: i32, month: u32, leap_month: bool) -> Option<u32> {
+		let position = self.position(year, month, leap_month)?;
+
+		if self.entry(year)? & (1 << position) != 0 {
+			Some(30)
+		} else {
+			Some(29)
+		}
+	}
+
+	/// Converts the given lunisolar date into a gregorian date. If the date doesn't exist or isn't supported, None will
+	/// be returned.
+	///
+	/// # Example
+	///
+	/// ```
+	/// use horus::calendars::lunisolar::LunisolarCalendar;
+	/// use chrono::NaiveDate;
+	///
+	/// let result = LunisolarCalendar::China.to_gregorian(2024, 8, false, 15);
+	///
+	/// assert_eq!(result, NaiveDate::from_ymd_opt(2024, 9, 17));
+	/// ```
+	pub fn to_gregorian(&self, year: i32, month: u32, leap_month: bool, day: u32) -> Option<NaiveDate> {
+		if day == 0 || day > self.days_of_month(year, month, leap_month)? {
+			return None;
+		}
+
+		let position = self.position(year, month, leap_month)?;
+		let days: u32 = (0..position).map(|p| self.days_of_position(year, p)).sum();
+
+		Some(self.new_year(year)? + Duration::days((days + day - 1) as i64))
+	}
+
+	/// Converts the given gregorian date into a lunisolar date. If the date isn't supported, None will be returned.
+	pub fn from_gregorian(&self, date: NaiveDate) -> Option<LunisolarDate> {
+		let mut year = date.year();
+		if date < self.new_year(year)? {
+			year -= 1;
+		}
+
+		let leap = self.leap_month(year);
+		let mut days = (date - self.new_year(year)?).num_days() as u32;
+		for position in 0..self.months_of_year(year) {
+			let length = self.days_of_position(year, position);
+			if days < length {
+				let (month, leap_month) = match leap {
+					Some(leap) if position == leap => (leap, true),
+					Some(leap) if position > leap => (position, false),
+					_ => (position + 1, false),
+				};
+				return Some(LunisolarDate {
+					year,
+					month,
+					leap_month,
+					day: days + 1,
+				});
+			}
+			days -= length;
+		}
+
+		None
+	}
+
+	/// Gets the date of the given solar term (longitude of the sun in degrees) of the given gregorian year in the
+	/// time zone of the calendar, e.g. 15 for Qingming
+	pub fn solar_term(&self, year: i32, longitude: f64) -> NaiveDate {
+		(Astronomy::solar_term(year, longitude) + Duration::hours(self.utc_offset())).date()
+	}
+
+	fn entry(&self, year: i32) -> Option<u32> {
+		if !(Self::FIRST_YEAR..=Self::LAST_YEAR).contains(&year) {
+			return None;
+		}
+
+		let table = match self {
+			LunisolarCalendar::China => &YEARS_UTC8,
+			LunisolarCalendar::Korea => &YEARS_UTC9,
+			LunisolarCalendar::Vietnam => &YEARS_UTC7,
+		};
+
+		Some(table[(year - Self::FIRST_YEAR) as usize])
+	}
+
+	fn months_of_year(&self, year: i32) -> u32 {
+		if self.leap_month(year).is_some() {
+			13
+		} else {
+			12
+		}
+	}
+
+	/// Position of the month in the year, counting the leap month
+	fn position(&self, year: i32, month: u32, leap_month: bool) -> Option<u32> {
+		if !(1..=12).contains(&month) {
+			return None;
+		}
+
+		match self.leap_month(year) {
+			Some(leap) if leap_month && leap == month => Some(month),
+			_ if leap_month => None,
+			Some(leap) if month > leap => Some(month),
+			_ => Some(month - 1),
+		}
+	}
+
+	fn days_of_position(&self, year: i32, position: u32) -> u32 {
+		match self.entry(year) {
+			Some(entry) if entry & (1 << position) != 0 => 30,
+			_ => 29,
+		}
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use chrono::NaiveDate;
+
+	use super::{LunisolarCalendar, LunisolarDate};
+
+	#[test]
+	fn test_new_year() {
+		let calendar = LunisolarCalendar::China;
+		assert_eq!(calendar.new_year(1900), NaiveDate::from_ymd_opt(1900, 1, 31));
+		assert_eq!(calendar.new_year(2024), NaiveDate::from_ymd_opt(2024, 2, 10));
+		assert_eq!(calendar.new_year(2025), NaiveDate::from_ymd_opt(2025, 1, 29));
+		assert_eq!(calendar.new_year(2101), None);
+	}
+
+	#[test]
+	fn test_new_year_time_zones() {
+		assert_eq!(
+			LunisolarCalendar::China.new_year(1997),
+			NaiveDate::from_ymd_opt(1997, 2, 7)
+		);
+		assert_eq!(
+			LunisolarCalendar::Korea.new_year(1997),
+			NaiveDate::from_ymd_opt(1997, 2, 8)
+		);
+	}
+
+	#[test]
+	fn test_leap_month() {
+		let calendar = LunisolarCalendar::China;
+		assert_eq!(calendar.leap_month(2023), Some(2));
+		assert_eq!(calendar.leap_month(2024), None);
+		assert_eq!(calendar.leap_month(2025), Some(6));
+		assert_eq!(calendar.leap_month(2033), Some(11));
+	}
+
+	#[test]
+	fn test_to_gregorian_leap_month() {
+		let calendar = LunisolarCalendar::China;
+		assert_eq!(
+			calendar.to_gregorian(2023, 2, true, 1),
+			NaiveDate::from_ymd_opt(2023, 3, 22)
+		);
+		assert_eq!(calendar.to_gregorian(2024, 2, true, 1), None);
+	}
+
+	#[test]
+	fn test_round_trip() {
+		for calendar in [LunisolarCalendar::China, LunisolarCalendar::Korea, LunisolarCalendar::Vietnam] {
+			let mut date = NaiveDate::from_ymd_opt(1900, 1, 31).unwrap();
+			while date < NaiveDate::from_ymd_opt(2100, 12, 31).unwrap() {
+				let LunisolarDate { year, month, leap_month, day } = calendar.from_gregorian(date).unwrap();
+				assert_eq!(calendar.to_gregorian(year, month, leap_month, day), Some(date));
+				date = date.succ_opt().unwrap();
+			}
+		}
+	}
+
+	#[test]
+	fn test_solar_term() {
+		let result = LunisolarCalendar::China.solar_term(2024, 15.0);
+		assert_eq!(result, NaiveDate::from_ymd_opt(2024, 4, 4).unwrap());
+	}
+}
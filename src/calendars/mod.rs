@@ -0,0 +1,166 @@
+pub mod astronomy;
+pub mod gregorian;
+pub mod hebrew;
+pub mod hijri;
+pub mod julian;
+pub mod lunisolar;
+pub mod reform;
+pub mod revisedjulian;
+
+use chrono::{Datelike, NaiveDate};
+
+use self::{gregorian::GregorianCalendar, julian::JulianCalendar, revisedjulian::RevisedJulianCalendar};
+
+/// Solar calendars which can be converted into each other by their julian day numbers
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum CalendarSystem {
+	Gregorian,
+	Julian,
+	RevisedJulian,
+}
+
+impl CalendarSystem {
+	/// Checks if the given year is a leap year in the calendar
+	pub fn is_leap_year(&self, year: i32) -> bool {
+		match self {
+			CalendarSystem::Gregorian => GregorianCalendar::is_leap_year(year),
+			CalendarSystem::Julian => JulianCalendar::is_leap_year(year),
+			CalendarSystem::RevisedJulian => RevisedJulianCalendar::is_leap_year(year),
+		}
+	}
+
+	/// Gets the number of days of the given month in the calendar
+	pub fn days_of_month(&self, year: i32, month: u32) -> Option<u32> {
+		match month {
+			1 | 3 | 5 | 7 | 8 | 10 | 12 => Some(31),
+			4 | 6 | 9 | 11 => Some(30),
+			2 if self.is_leap_year(year) => Some(29),
+			2 => Some(28),
+			_ => None,
+		}
+	}
+
+	/// Gets the julian day number of the given date in the calendar. If the date doesn't exist, None will be returned.
+	///
+	/// # Example
+	///
+	/// ```
+	/// use horus::calendars::CalendarSystem;
+	///
+	/// assert_eq!(CalendarSystem::Julian.to_julian_day(1900, 2, 29), Some(2_415_092));
+	/// assert_eq!(CalendarSystem::Gregorian.to_julian_day(1900, 2, 29), None);
+	/// ```
+	pub fn to_julian_day(&self, year: i32, month: u32, day: u32) -> Option<i64> {
+		if day == 0 || day > self.days_of_month(year, month)? {
+			return None;
+		}
+
+		Some(match self {
+			CalendarSystem::Gregorian => GregorianCalendar::to_julian_day(year, month, day),
+			CalendarSystem::Julian => JulianCalendar::to_julian_day(year, month, day),
+			CalendarSystem::RevisedJulian => RevisedJulianCalendar::to_julian_day(year, month, day),
+		})
+	}
+
+	/// Gets the date (year, month, day) of the given julian day number in the calendar
+	pub fn from_julian_day(&self, julian_day: i64) -> (i32, u32, u32) {
+		match self {
+			CalendarSystem::Gregorian => GregorianCalendar::from_julian_day(julian_day),
+			CalendarSystem::Julian => JulianCalendar::from_julian_day(julian_day),
+			CalendarSystem::RevisedJulian => RevisedJulianCalendar::from_julian_day(julian_day),
+		}
+	}
+
+	/// Converts the given date of the calendar into the date (year, month, day) of the target calendar
+	///
+	/// # Example
+	///
+	/// ```
+	/// use horus::calendars::CalendarSystem;
+	///
+	/// let result = CalendarSystem::Julian.convert(2023, 12, 25, CalendarSystem::RevisedJulian);
+	///
+	/// assert_eq!(result, Some((2024, 1, 7)));
+	/// ```
+	pub fn convert(&self, year: i32, month: u32, day: u32, target: CalendarSystem) -> Option<(i32, u32, u32)> {
+		Some(target.from_julian_day(self.to_julian_day(year, month, day)?))
+	}
+
+	/// Converts the given date of the calendar into a gregorian date
+	pub fn to_gregorian(&self, year: i32, month: u32, day: u32) -> Option<NaiveDate> {
+		GregorianCalendar::date_from_julian_day(self.to_julian_day(year, month, day)?)
+	}
+
+	/// Converts the given gregorian date into a date (year, month, day) of the calendar
+	pub fn from_gregorian(&self, date: NaiveDate) -> (i32, u32, u32) {
+		self.from_julian_day(GregorianCalendar::date_to_julian_day(date))
+	}
+
+	/// Gets the gregorian date of a fixed date (month and day) of the calendar which takes place in the given
+	/// gregorian year
+	///
+	/// # Example
+	///
+	/// ```
+	/// use horus::calendars::CalendarSystem;
+	/// use chrono::NaiveDate;
+	///
+	/// let result = CalendarSystem::Julian.fixed_date_in_gregorian_year(2024, 12, 25);
+	///
+	/// assert_eq!(result, NaiveDate::from_ymd_opt(2024, 1, 7));
+	/// ```
+	pub fn fixed_date_in_gregorian_year(&self, gregorian_year: i32, month: u32, day: u32) -> Option<NaiveDate> {
+		[gregorian_year - 1, gregorian_year, gregorian_year + 1]
+			.into_iter()
+			.filter_map(|year| self.to_gregorian(year, month, day))
+			.find(|date| date.year() == gregorian_year)
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use chrono::NaiveDate;
+
+	use super::CalendarSystem;
+
+	#[test]
+	fn test_round_trip() {
+		let calendars = [CalendarSystem::Gregorian, CalendarSystem::Julian, CalendarSystem::RevisedJulian];
+		let date = NaiveDate::from_ymd_opt(2024, 2, 29).unwrap();
+		for calendar in calendars {
+			let (year, month, day) = calendar.from_gregorian(date);
+			assert_eq!(calendar.to_gregorian(year, month, day), Some(date));
+			for target in calendars {
+				let (target_year, target_month, target_day) = calendar.convert(year, month, day, target).unwrap();
+				assert_eq!(
+					target.convert(target_year, target_month, target_day, calendar),
+					Some((year, month, day))
+				);
+			}
+		}
+	}
+
+	#[test]
+	fn test_convert_invalid_date() {
+		assert_eq!(
+			CalendarSystem::Gregorian.convert(2023, 2, 29, CalendarSystem::Julian),
+			None
+		);
+		assert_eq!(
+			CalendarSystem::Julian.convert(2023, 13, 1, CalendarSystem::Gregorian),
+			None
+		);
+	}
+
+	#[test]
+	fn test_fixed_date_in_gregorian_year() {
+		assert_eq!(
+			CalendarSystem::RevisedJulian.fixed_date_in_gregorian_year(2024, 12, 25),
+			NaiveDate::from_ymd_opt(2024, 12, 25)
+		);
+		assert_eq!(
+			CalendarSystem::Julian.fixed_date_in_gregorian_year(2101, 12, 25),
+			NaiveDate::from_ymd_opt(2101, 1, 8)
+		);
+	}
+}
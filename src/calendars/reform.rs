@@ -0,0 +1,201 @@
+use chrono::{Datelike, NaiveDate};
+
+use super::{julian::JulianCalendar, CalendarSystem};
+use crate::types::{computus::Computus, countrycode::CountryCode};
+
+pub struct CalendarReform {}
+
+/// Adoption of the gregorian calendar by the countries which used the julian calendar before
+///
+/// Fixed dates and the easter computus of years before the reform are calculated in the julian calendar and
+/// converted into the (proleptic) gregorian calendar, so the resulting dates are the days on which the holidays
+/// actually took place.
+impl CalendarReform {
+	/// Gets the first day on which the gregorian calendar was in force in the country. If the country never used the
+	/// julian calendar or the date is unknown, None will be returned.
+	///
+	/// # Example
+	///
+	/// ```
+	/// use horus::{calendars::reform::CalendarReform, types::countrycode::CountryCode};
+	/// use chrono::NaiveDate;
+	///
+	/// assert_eq!(CalendarReform::adoption_date(CountryCode::RU), NaiveDate::from_ymd_opt(1918, 2, 14));
+	/// ```
+	pub fn adoption_date(country_code: CountryCode) -> Option<NaiveDate> {
+		let (year, month, day) = match country_code {
+			CountryCode::ES | CountryCode::IT | CountryCode::PL | CountryCode::PT => (1582, 10, 15),
+			CountryCode::FR => (1582, 12, 20),
+			// protestant states, most of the catholic states already changed in 1583
+			CountryCode::DE => (1700, 3, 1),
+			CountryCode::DK | CountryCode::NO => (1700, 3, 1),
+			CountryCode::GB | CountryCode::IE | CountryCode::US => (1752, 9, 14),
+			CountryCode::SE => (1753, 3, 1),
+			CountryCode::BG => (1916, 4, 14),
+			CountryCode::RU => (1918, 2, 14),
+			CountryCode::UA => (1918, 3, 1),
+			CountryCode::RO => (1919, 4, 14),
+			CountryCode::GR => (1923, 3, 1),
+			_ => return None,
+		};
+
+		NaiveDate::from_ymd_opt(year, month, day)
+	}
+
+	/// Gets the calendar which was in force in the country on the given gregorian date
+	pub fn calendar_in_force(country_code: CountryCode, date: NaiveDate) -> CalendarSystem {
+		match Self::adoption_date(country_code) {
+			Some(adoption_date) if date < adoption_date => CalendarSystem::Julian,
+			_ => CalendarSystem::Gregorian,
+		}
+	}
+
+	/// Gets the gregorian date on which a fixed date (month and day) of the calendar in force in the country took
+	/// place in the given gregorian year. Before the reform, the julian date of the previous julian year can fall into
+	/// the gregorian year, like julian christmas 1699 on january 4, 1700. If the date took place twice in the year of
+	/// the reform, the date of the gregorian calendar is returned. If the date was skipped by the reform, None will be
+	/// returned.
+	///
+	/// # Example
+	///
+	/// ```
+	/// use horus::{calendars::reform::CalendarReform, types::countrycode::CountryCode};
+	/// use chrono::NaiveDate;
+	///
+	/// assert_eq!(CalendarReform::fixed_date(CountryCode::RU, 1900, 5, 1), NaiveDate::from_ymd_opt(1900, 5, 14));
+	/// assert_eq!(CalendarReform::fixed_date(CountryCode::RU, 2000, 5, 1), NaiveDate::from_ymd_opt(2000, 5, 1));
+	/// assert_eq!(CalendarReform::fixed_date(CountryCode::GB, 1752, 9, 5), None);
+	/// ```
+	pub fn fixed_date(country_code: CountryCode, year: i32, month: u32, day: u32) -> Option<NaiveDate> {
+		Self::fixed_dates(country_code, year, month, day).pop()
+	}
+
+	/// Gets all gregorian dates on which a fixed date (month and day) of the calendar in force in the country took
+	/// place in the given gregorian year, in ascending order. In the year of the reform, a date can take place twice,
+	/// once in the julian and once in the gregorian calendar, and the dates skipped by the reform don't take place.
+	///
+	/// # Example
+	///
+	/// ```
+	/// use horus::{calendars::reform::CalendarReform, types::countrycode::CountryCode};
+	/// use chrono::NaiveDate;
+	///
+	/// let result = CalendarReform::fixed_dates(CountryCode::GB, 1752, 12, 25);
+	///
+	/// assert_eq!(result[0], NaiveDate::from_ymd_opt(1752, 1, 5).unwrap());
+	/// assert_eq!(result[1], NaiveDate::from_ymd_opt(1752, 12, 25).unwrap());
+	/// ```
+	pub fn fixed_dates(country_code: CountryCode, year: i32, month: u32, day: u32) -> Vec<NaiveDate> {
+		let mut dates: Vec<NaiveDate> = [year - 1, year]
+			.into_iter()
+			.filter_map(|julian_year| JulianCalendar::to_gregorian(julian_year, month, day))
+			.filter(|date| {
+				date.year() == year && Self::calendar_in_force(country_code, *date) == CalendarSystem::Julian
+			})
+			.collect();
+
+		if let Some(date) = NaiveDate::from_ymd_opt(year, month, day) {
+			if Self::calendar_in_force(country_code, date) == CalendarSystem::Gregorian {
+				dates.push(date);
+			}
+		}
+
+		dates
+	}
+
+	/// Gets the computus which was used in the country for the easter of the given year
+	pub fn computus_in_force(country_code: CountryCode, computus: Computus, year: i32) -> Computus {
+		let julian_easter = Computus::Julian.easter_sunday(year);
+		if Self::calendar_in_force(country_code, julian_easter) == CalendarSystem::Julian {
+			Computus::Julian
+		} else {
+			computus
+		}
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use chrono::NaiveDate;
+
+	use crate::{
+		calendars::CalendarSystem,
+		types::{computus::Computus, countrycode::CountryCode},
+	};
+
+	use super::CalendarReform;
+
+	#[test]
+	fn test_calendar_in_force() {
+		let before = NaiveDate::from_ymd_opt(1752, 9, 2).unwrap();
+		let after = NaiveDate::from_ymd_opt(1752, 9, 14).unwrap();
+		assert_eq!(
+			CalendarReform::calendar_in_force(CountryCode::GB, before),
+			CalendarSystem::Julian
+		);
+		assert_eq!(
+			CalendarReform::calendar_in_force(CountryCode::GB, after),
+			CalendarSystem::Gregorian
+		);
+		assert_eq!(
+			CalendarReform::calendar_in_force(CountryCode::JP, before),
+			CalendarSystem::Gregorian
+		);
+	}
+
+	#[test]
+	fn test_fixed_date() {
+		// julian christmas 1700 took place in the next gregorian year, so 1700 has the christmas of 1699
+		assert_eq!(
+			CalendarReform::fixed_date(CountryCode::GB, 1700, 12, 25),
+			NaiveDate::from_ymd_opt(1700, 1, 4)
+		);
+		assert_eq!(
+			CalendarReform::fixed_date(CountryCode::GB, 1701, 12, 25),
+			NaiveDate::from_ymd_opt(1701, 1, 5)
+		);
+		// september 3 to 13 were skipped in 1752
+		assert_eq!(CalendarReform::fixed_date(CountryCode::GB, 1752, 9, 3), None);
+		assert_eq!(
+			CalendarReform::fixed_date(CountryCode::GB, 1752, 9, 2),
+			NaiveDate::from_ymd_opt(1752, 9, 13)
+		);
+		assert_eq!(
+			CalendarReform::fixed_date(CountryCode::GB, 1752, 9, 14),
+			NaiveDate::from_ymd_opt(1752, 9, 14)
+		);
+		// the reform took place in february, so new year was still in the julian calendar
+		assert_eq!(
+			CalendarReform::fixed_date(CountryCode::GR, 1923, 1, 1),
+			NaiveDate::from_ymd_opt(1923, 1, 14)
+		);
+		assert_eq!(
+			CalendarReform::fixed_date(CountryCode::GR, 1923, 3, 25),
+			NaiveDate::from_ymd_opt(1923, 3, 25)
+		);
+		assert_eq!(CalendarReform::fixed_date(CountryCode::GR, 1923, 2, 30), None);
+	}
+
+	#[test]
+	fn test_fixed_dates() {
+		assert_eq!(
+			CalendarReform::fixed_dates(CountryCode::GB, 1752, 12, 25),
+			vec![
+				NaiveDate::from_ymd_opt(1752, 1, 5).unwrap(),
+				NaiveDate::from_ymd_opt(1752, 12, 25).unwrap()
+			]
+		);
+		assert!(CalendarReform::fixed_dates(CountryCode::RU, 1918, 2, 5).is_empty());
+		assert_eq!(CalendarReform::fixed_dates(CountryCode::JP, 1752, 12, 25).len(), 1);
+	}
+
+	#[test]
+	fn test_computus_in_force() {
+		let result = CalendarReform::computus_in_force(CountryCode::GB, Computus::Gregorian, 1752);
+		assert_eq!(result, Computus::Julian);
+		let result = CalendarReform::computus_in_force(CountryCode::GB, Computus::Gregorian, 1753);
+		assert_eq!(result, Computus::Gregorian);
+		let result = CalendarReform::computus_in_force(CountryCode::JP, Computus::Gregorian, 1500);
+		assert_eq!(result, Computus::Gregorian);
+	}
+}
@@ -0,0 +1,105 @@
+use super::gregorian::GregorianCalendar;
+
+pub struct RevisedJulianCalendar {}
+
+/// Julian day numbers of the revised julian calendar (Milanković calendar)
+///
+/// The revised julian calendar is used by several orthodox churches for the fixed feasts. It matches the
+/// gregorian calendar from 1600-03-01 until 2800-02-28, but skips more leap days in the centuries.
+impl RevisedJulianCalendar {
+	/// Gets the julian day number of the given revised julian date
+	///
+	/// # Example
+	///
+	/// ```
+	/// use horus::calendars::revisedjulian::RevisedJulianCalendar;
+	///
+	/// assert_eq!(RevisedJulianCalendar::to_julian_day(2000, 1, 1), 2_451_545);
+	/// ```
+	pub fn to_julian_day(year: i32, month: u32, day: u32) -> i64 {
+		let a = (14 - month as i64) / 12;
+		let y = year as i64 + 4800 - a;
+		let m = month as i64 + 12 * a - 3;
+		// centuries are only leap years if they leave a remainder of 2 or 6 when divided by 900,
+		// which is 0 or 5 for the centuries counted from the shifted year
+		let c = y / 100;
+
+		day as i64 + (153 * m + 2) / 5 + 365 * y + y / 4 - c + c / 9 + (c + 4) / 9 - 32043
+	}
+
+	/// Gets the revised julian date (year, month, day) of the given julian day number
+	pub fn from_julian_day(julian_day: i64) -> (i32, u32, u32) {
+		// both calendars differ by a few days over thousands of years, so the gregorian year is a close guess
+		let (mut year, _, _) = GregorianCalendar::from_julian_day(julian_day);
+		while Self::to_julian_day(year, 1, 1) > julian_day {
+			year -= 1;
+		}
+		while Self::to_julian_day(year + 1, 1, 1) <= julian_day {
+			year += 1;
+		}
+
+		let mut month = 12;
+		while Self::to_julian_day(year, month, 1) > julian_day {
+			month -= 1;
+		}
+
+		let day = julian_day - Self::to_julian_day(year, month, 1) + 1;
+
+		(year, month, day as u32)
+	}
+
+	/// Checks if the given year is a leap year in the revised julian calendar
+	pub fn is_leap_year(year: i32) -> bool {
+		if year.rem_euclid(100) == 0 {
+			let remainder = year.rem_euclid(900);
+			return remainder == 200 || remainder == 600;
+		}
+
+		year.rem_euclid(4) == 0
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use crate::calendars::gregorian::GregorianCalendar;
+
+	use super::RevisedJulianCalendar;
+
+	#[test]
+	fn test_matches_gregorian_calendar() {
+		let start = GregorianCalendar::to_julian_day(1600, 3, 1);
+		let end = GregorianCalendar::to_julian_day(2800, 2, 28);
+		for julian_day in start..=end {
+			assert_eq!(
+				RevisedJulianCalendar::from_julian_day(julian_day),
+				GregorianCalendar::from_julian_day(julian_day)
+			);
+		}
+	}
+
+	#[test]
+	fn test_differs_from_gregorian_calendar() {
+		// 2800 is a leap year in the gregorian calendar, but not in the revised julian calendar
+		let julian_day = GregorianCalendar::to_julian_day(2800, 2, 29);
+		assert_eq!(RevisedJulianCalendar::from_julian_day(julian_day), (2800, 3, 1));
+		let julian_day = GregorianCalendar::to_julian_day(1600, 2, 29);
+		assert_eq!(RevisedJulianCalendar::from_julian_day(julian_day), (1600, 2, 28));
+	}
+
+	#[test]
+	fn test_round_trip() {
+		for julian_day in 1_000_000..1_100_000 {
+			let (year, month, day) = RevisedJulianCalendar::from_julian_day(julian_day);
+			assert_eq!(RevisedJulianCalendar::to_julian_day(year, month, day), julian_day);
+		}
+	}
+
+	#[test]
+	fn test_is_leap_year() {
+		assert!(RevisedJulianCalendar::is_leap_year(2000));
+		assert!(RevisedJulianCalendar::is_leap_year(2400));
+		assert!(!RevisedJulianCalendar::is_leap_year(2800));
+		assert!(RevisedJulianCalendar::is_leap_year(2900));
+		assert!(!RevisedJulianCalendar::is_leap_year(2023));
+	}
+}
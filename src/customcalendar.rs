@@ -0,0 +1,521 @@
+use std::collections::HashMap;
+
+use chrono::{Datelike, NaiveTime};
+use chrono_tz::Tz;
+
+use crate::{
+	calendars::reform::CalendarReform,
+	holidaycalendar::HolidayCalendar,
+	holidayprovider::HolidayProvider,
+	types::{
+		countrycode::CountryCode, holidayid::HolidayId, holidayperiod::HolidayPeriod, publicholiday::PublicHoliday,
+		publicholidaytype::PublicHolidayType, suspensionperiod::SuspensionPeriod,
+		workingdayoverride::WorkingDayOverride,
+	},
+};
+
+#[derive(Debug, Clone)]
+enum CustomCalendarRule {
+	AddFixed {
+		month: u32,
+		day: u32,
+		local_name: String,
+		name: String,
+		holiday_type: PublicHolidayType,
+		start_time: Option<NaiveTime>,
+	},
+	Add(PublicHoliday),
+	RemoveByName(String),
+	RemoveByDate {
+		month: u32,
+		day: u32,
+	},
+	ChangeType {
+		name: String,
+		holiday_type: PublicHolidayType,
+	},
+}
+
+/// Calendar which wraps the holidays of a country (and optionally a county) and applies custom changes on top of them
+///
+/// The changes are applied in the order they were added, so a holiday which is added after a removal is kept.
+///
+/// # Example
+///
+/// ```
+/// use horus::{customcalendar::CustomCalendar, holidayprovider::HolidayProvider};
+/// use horus::types::{countrycode::CountryCode, publicholidaytype::PublicHolidayType};
+///
+/// let mut calendar = CustomCalendar::new(CountryCode::DE);
+/// calendar.set_county("DE-BY");
+/// calendar.add_fixed_holiday(12, 24, "Heiligabend", "Christmas Eve", PublicHolidayType::Optional);
+/// calendar.add_fixed_holiday(12, 31, "Silvester", "New Year's Eve", PublicHolidayType::Optional);
+/// calendar.remove_holiday_by_name("Heilige Drei Könige");
+///
+/// let holidays = HolidayProvider::get_holidays(2022, &calendar);
+///
+/// assert_eq!(holidays.len(), 13);
+/// assert_eq!(holidays.last().unwrap().name, "New Year's Eve");
+/// ```
+#[derive(Debug, Clone)]
+pub struct CustomCalendar {
+	country_code: CountryCode,
+	county: Option<String>,
+	locality: Option<String>,
+	rules: Vec<CustomCalendarRule>,
+	suspension_periods: Vec<SuspensionPeriod>,
+}
+
+impl CustomCalendar {
+	pub fn new(country_code: CountryCode) -> Self {
+		CustomCalendar {
+			country_code,
+			county: None,
+			locality: None,
+			rules: Vec::new(),
+			suspension_periods: Vec::new(),
+		}
+	}
+
+	pub fn country_code(&self) -> CountryCode {
+		self.country_code
+	}
+
+	pub fn county(&self) -> Option<&str> {
+		self.county.as_deref()
+	}
+
+	/// Restricts the calendar to the holidays of the given county
+	pub fn set_county(&mut self, county: &str) -> &mut Self {
+		self.county = Some(county.to_string());
+		self
+	}
+
+	pub fn locality(&self) -> Option<&str> {
+		self.locality.as_deref()
+	}
+
+	/// Restricts the calendar to the holidays of the given locality within the county, which is either an official
+	/// municipality key (AGS) or the name of a municipality. Holidays of a county which are restricted to some of its
+	/// municipalities are only kept for these municipalities.
+	pub fn set_locality(&mut self, locality: &str) -> &mut Self {
+		self.locality = Some(locality.to_string());
+		self
+	}
+
+	/// Adds a holiday which takes place every year on the given month and day
+	pub fn add_fixed_holiday(
+		&mut self,
+		month: u32,
+		day: u32,
+		local_name: &str,
+		name: &str,
+		holiday_type: PublicHolidayType,
+	) -> &mut Self {
+		self.rules.push(CustomCalendarRule::AddFixed {
+			month,
+			day,
+			local_name: local_name.to_string(),
+			name: name.to_string(),
+			holiday_type,
+			start_time: None,
+		});
+		self
+	}
+
+	/// Adds a holiday which takes place every year on the given month and day from noon
+	pub fn add_fixed_half_day(
+		&mut self,
+		month: u32,
+		day: u32,
+		local_name: &str,
+		name: &str,
+		holiday_type: PublicHolidayType,
+	) -> &mut Self {
+		self.rules.push(CustomCalendarRule::AddFixed {
+			month,
+			day,
+			local_name: local_name.to_string(),
+			name: name.to_string(),
+			holiday_type,
+			start_time: NaiveTime::from_hms_opt(12, 0, 0),
+		});
+		self
+	}
+
+	/// Adds a holiday which only takes place in the year of its date
+	pub fn add_holiday(&mut self, holiday: PublicHoliday) -> &mut Self {
+		self.rules.push(CustomCalendarRule::Add(holiday));
+		self
+	}
+
+	/// Removes all holidays whose name or local name matches the given name
+	pub fn remove_holiday_by_name(&mut self, name: &str) -> &mut Self {
+		self.rules.push(CustomCalendarRule::RemoveByName(name.to_string()));
+		self
+	}
+
+	/// Removes all holidays which take place on the given month and day
+	pub fn remove_holiday_by_date(&mut self, month: u32, day: u32) -> &mut Self {
+		self.rules.push(CustomCalendarRule::RemoveByDate { month, day });
+		self
+	}
+
+	/// Changes the type of all holidays whose name or local name matches the given name
+	pub fn change_holiday_type(&mut self, name: &str, holiday_type: PublicHolidayType) -> &mut Self {
+		self.rules
+			.push(CustomCalendarRule::ChangeType { name: name.to_string(), holiday_type });
+		self
+	}
+
+	/// Adds a period in which the holidays of the calendar don't give a day off, in addition to the suspension periods
+	/// of the country
+	pub fn add_suspension_period(&mut self, suspension_period: SuspensionPeriod) -> &mut Self {
+		self.suspension_periods.push(suspension_period);
+		self
+	}
+
+	fn matches_name(holiday: &PublicHoliday, name: &str) -> bool {
+		holiday.name == name || holiday.local_name == name
+	}
+}
+
+impl HolidayCalendar for CustomCalendar {
+	fn get_holidays(&self, year: i32) -> Vec<PublicHoliday> {
+		let mut holidays = HolidayProvider::get_holidays(year, self.country_code);
+		if let Some(county) = &self.county {
+			holidays.retain(|holiday| holiday.is_in_location(county, self.locality.as_deref()));
+		}
+
+		for rule in &self.rules {
+			match rule {
+				CustomCalendarRule::AddFixed {
+					month,
+					day,
+					local_name,
+					name,
+					holiday_type,
+					start_time,
+				} => {
+					if CalendarReform::fixed_date(self.country_code, year, *month, *day).is_some() {
+						let mut holiday = PublicHoliday::new_fixed(
+							HolidayId::from_name(name),
+							year,
+							*month,
+							*day,
+							local_name,
+							name,
+							self.country_code,
+							None,
+							None,
+							*holiday_type,
+						);
+						if let Some(start_time) = start_time {
+							holiday.set_start_time(*start_time);
+						}
+						holidays.push(holiday);
+					}
+				}
+				CustomCalendarRule::Add(holiday) => {
+					if holiday.date.year() == year {
+						holidays.push(holiday.clone());
+					}
+				}
+				CustomCalendarRule::RemoveByName(name) => {
+					holidays.retain(|holiday| !Self::matches_name(holiday, name));
+				}
+				CustomCalendarRule::RemoveByDate { month, day } => {
+					holidays.retain(|holiday| holiday.date.month() != *month || holiday.date.day() != *day);
+				}
+				CustomCalendarRule::ChangeType { name, holiday_type } => {
+					for holiday in holidays.iter_mut().filter(|holiday| Self::matches_name(holiday, name)) {
+						holiday.set_holiday_type(*holiday_type);
+					}
+				}
+			}
+		}
+
+		for holiday in holidays.iter_mut().filter(|holiday| {
+			self.suspension_periods
+				.iter()
+				.any(|period| period.contains(holiday.date))
+		}) {
+			holiday.set_non_working(false);
+		}
+
+		holidays.sort_by_key(|a| a.date);
+
+		holidays
+	}
+
+	fn get_counties(&self) -> Option<HashMap<String, String>> {
+		let counties = HolidayProvider::get_counties(self.country_code)?;
+		match &self.county {
+			Some(county) => Some(counties.into_iter().filter(|(code, _)| code == county).collect()),
+			None => Some(counties),
+		}
+	}
+
+	fn get_working_day_overrides(&self, year: i32) -> Vec<WorkingDayOverride> {
+		HolidayProvider::get_working_day_overrides(year, self.country_code)
+	}
+
+	fn get_holiday_periods(&self, year: i32) -> Vec<HolidayPeriod> {
+		let mut periods = HolidayProvider::get_holiday_periods(year, self.country_code);
+		if let Some(county) = &self.county {
+			periods.retain(|period| period.is_in_county(county));
+		}
+
+		periods
+	}
+
+	fn get_suspension_periods(&self) -> Vec<SuspensionPeriod> {
+		let mut periods = HolidayProvider::get_suspension_periods(self.country_code);
+		periods.extend(self.suspension_periods.iter().cloned());
+
+		periods
+	}
+
+	fn get_time_zone(&self, county: Option<&str>) -> Option<Tz> {
+		self.country_code.get_time_zone(county.or(self.county.as_deref()))
+	}
+
+	fn county(&self) -> Option<&str> {
+		self.county.as_deref()
+	}
+
+	fn locality(&self) -> Option<&str> {
+		self.locality.as_deref()
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use chrono::{NaiveDate, TimeZone, Utc};
+	use chrono_tz::Tz;
+
+	use crate::{
+		holidaycalendar::HolidayCalendar,
+		holidayprovider::HolidayProvider,
+		types::{
+			countrycode::CountryCode, holidayid::HolidayId, publicholiday::PublicHoliday,
+			publicholidaytype::PublicHolidayType, suspensionperiod::SuspensionPeriod,
+		},
+	};
+
+	use super::CustomCalendar;
+
+	#[test]
+	fn test_get_holidays_without_rules() {
+		let result = CustomCalendar::new(CountryCode::PL).get_holidays(2022);
+		assert_eq!(result.len(), 13);
+	}
+
+	#[test]
+	fn test_set_county() {
+		let mut calendar = CustomCalendar::new(CountryCode::DE);
+		calendar.set_county("DE-SN");
+		let result = calendar.get_holidays(2022);
+		assert!(result.iter().any(|holiday| holiday.local_name == "Buß- und Bettag"));
+		assert!(!result.iter().any(|holiday| holiday.local_name == "Allerheiligen"));
+		assert_eq!(calendar.get_counties().unwrap().len(), 1);
+	}
+
+	#[test]
+	fn test_queries_use_county_of_calendar() {
+		let mut calendar = CustomCalendar::new(CountryCode::DE);
+		calendar.set_county("DE-BY");
+		let epiphany = NaiveDate::from_ymd_opt(2025, 1, 6).unwrap();
+		assert!(!HolidayProvider::is_business_day(epiphany, &calendar, None));
+		assert!(HolidayProvider::is_business_day(epiphany, &calendar, Some("DE-BE")));
+		let instant = Utc.with_ymd_and_hms(2025, 1, 6, 10, 0, 0).unwrap();
+		assert!(HolidayProvider::is_holiday_at(instant, &calendar, None));
+	}
+
+	#[test]
+	fn test_queries_use_locality_of_calendar() {
+		let mut calendar = CustomCalendar::new(CountryCode::DE);
+		calendar.set_county("DE-BY").set_locality("Augsburg");
+		let peace_festival = NaiveDate::from_ymd_opt(2024, 8, 8).unwrap();
+		assert!(calendar
+			.get_holidays(2024)
+			.iter()
+			.any(|holiday| holiday.date == peace_festival));
+		assert!(!HolidayProvider::is_business_day(
+			peace_festival,
+			&calendar,
+			Some("DE-BY")
+		));
+		assert!(HolidayProvider::is_business_day_in_locality(
+			peace_festival,
+			&calendar,
+			Some("DE-BY"),
+			Some("München")
+		));
+		let instant = Utc.with_ymd_and_hms(2024, 8, 8, 10, 0, 0).unwrap();
+		assert!(HolidayProvider::is_holiday_at(instant, &calendar, None));
+	}
+
+	#[test]
+	fn test_set_locality() {
+		let mut calendar = CustomCalendar::new(CountryCode::DE);
+		calendar.set_county("DE-SN");
+		assert!(!calendar
+			.get_holidays(2024)
+			.iter()
+			.any(|holiday| holiday.local_name == "Fronleichnam"));
+		calendar.set_locality("Panschwitz-Kuckau");
+		let result = calendar.get_holidays(2024);
+		let corpus_christi = result
+			.iter()
+			.find(|holiday| holiday.local_name == "Fronleichnam")
+			.unwrap();
+		assert_eq!(corpus_christi.to_string(), "2024-05-30 Corpus Christi");
+		assert_eq!(calendar.locality(), Some("Panschwitz-Kuckau"));
+	}
+
+	#[test]
+	fn test_get_holiday_periods() {
+		let mut calendar = CustomCalendar::new(CountryCode::DE);
+		calendar.set_county("DE-NW");
+		let result = calendar.get_holiday_periods(2024);
+		assert_eq!(result.len(), 5);
+		assert_eq!(result[0].counties.as_ref().unwrap()[0], "DE-NW");
+	}
+
+	#[test]
+	fn test_add_fixed_holiday() {
+		let mut calendar = CustomCalendar::new(CountryCode::PL);
+		calendar.add_fixed_holiday(3, 14, "Dzień Firmy", "Founding Day", PublicHolidayType::Optional);
+		let result = calendar.get_holidays(2023);
+		assert_eq!(result.len(), 14);
+		assert_eq!(result[2].to_string(), "2023-03-14 Founding Day");
+		assert_eq!(result[2].holiday_type, PublicHolidayType::Optional);
+	}
+
+	#[test]
+	fn test_add_fixed_half_day() {
+		let mut calendar = CustomCalendar::new(CountryCode::PL);
+		calendar.add_fixed_half_day(12, 31, "Sylwester", "New Year's Eve", PublicHolidayType::Bank);
+		let result = calendar.get_holidays(2023);
+		let new_years_eve = result.last().unwrap();
+		assert_eq!(new_years_eve.name, "New Year's Eve");
+		assert!(new_years_eve.is_partial_day());
+	}
+
+	#[test]
+	fn test_add_fixed_holiday_leap_day() {
+		let mut calendar = CustomCalendar::new(CountryCode::PL);
+		calendar.add_fixed_holiday(2, 29, "test", "test name", PublicHolidayType::Public);
+		assert_eq!(calendar.get_holidays(2023).len(), 13);
+		assert_eq!(calendar.get_holidays(2024).len(), 14);
+	}
+
+	#[test]
+	fn test_add_holiday() {
+		let mut calendar = CustomCalendar::new(CountryCode::PL);
+		calendar.add_holiday(PublicHoliday::new_non_fixed(
+			HolidayId::new("TEST_NAME"),
+			NaiveDate::from_ymd_opt(2023, 6, 2).unwrap(),
+			"test",
+			"test name",
+			CountryCode::PL,
+			None,
+			None,
+			PublicHolidayType::Public,
+		));
+		assert_eq!(calendar.get_holidays(2022).len(), 13);
+		assert_eq!(calendar.get_holidays(2023).len(), 14);
+	}
+
+	#[test]
+	fn test_remove_holiday() {
+		let mut calendar = CustomCalendar::new(CountryCode::PL);
+		calendar
+			.remove_holiday_by_name("Epiphany")
+			.remove_holiday_by_date(12, 26);
+		let result = calendar.get_holidays(2022);
+		assert_eq!(result.len(), 11);
+		assert!(!result.iter().any(|holiday| holiday.name == "Epiphany"));
+		assert!(!result
+			.iter()
+			.any(|holiday| holiday.local_name == "Drugi Dzień Bożego Narodzenia"));
+	}
+
+	#[test]
+	fn test_change_holiday_type() {
+		let mut calendar = CustomCalendar::new(CountryCode::PL);
+		calendar.change_holiday_type("Zielone Świątki", PublicHolidayType::Observance);
+		let result = calendar.get_holidays(2022);
+		let pentecost = result.iter().find(|holiday| holiday.name == "Pentecost").unwrap();
+		assert_eq!(pentecost.holiday_type, PublicHolidayType::Observance);
+	}
+
+	#[test]
+	fn test_rules_are_applied_in_order() {
+		let mut calendar = CustomCalendar::new(CountryCode::PL);
+		calendar
+			.remove_holiday_by_date(12, 24)
+			.add_fixed_holiday(12, 24, "Wigilia", "Christmas Eve", PublicHolidayType::Public)
+			.remove_holiday_by_name("Christmas Eve");
+		assert_eq!(calendar.get_holidays(2022).len(), 13);
+	}
+
+	#[test]
+	fn test_add_suspension_period() {
+		let start = NaiveDate::from_ymd_opt(2024, 12, 24).unwrap();
+		let end = NaiveDate::from_ymd_opt(2024, 12, 26).unwrap();
+		let mut calendar = CustomCalendar::new(CountryCode::DE);
+		calendar.add_suspension_period(SuspensionPeriod::new(
+			start,
+			Some(end),
+			"Sperre",
+			"Freeze",
+			CountryCode::DE,
+		));
+		assert_eq!(calendar.get_suspension_periods().len(), 1);
+		let christmas = NaiveDate::from_ymd_opt(2024, 12, 25).unwrap();
+		let holidays = calendar.get_holidays(2024);
+		assert!(
+			!holidays
+				.iter()
+				.find(|holiday| holiday.date == christmas)
+				.unwrap()
+				.non_working
+		);
+		assert!(HolidayProvider::is_business_day(christmas, &calendar, None));
+		assert!(!HolidayProvider::is_holiday_at(
+			Utc.with_ymd_and_hms(2024, 12, 25, 12, 0, 0).unwrap(),
+			&calendar,
+			None
+		));
+		assert!(!HolidayProvider::is_business_day(
+			NaiveDate::from_ymd_opt(2024, 1, 1).unwrap(),
+			&calendar,
+			None
+		));
+	}
+
+	#[test]
+	fn test_get_time_zone() {
+		let mut calendar = CustomCalendar::new(CountryCode::US);
+		assert_eq!(calendar.get_time_zone(None), Some(Tz::America__New_York));
+		calendar.set_county("US-CA");
+		assert_eq!(calendar.get_time_zone(None), Some(Tz::America__Los_Angeles));
+		assert_eq!(calendar.get_time_zone(Some("US-TX")), Some(Tz::America__Chicago));
+	}
+
+	#[test]
+	fn test_is_holiday_at_partial_day() {
+		let mut calendar = CustomCalendar::new(CountryCode::DE);
+		calendar.add_fixed_half_day(12, 24, "Heiligabend", "Christmas Eve", PublicHolidayType::Bank);
+		// 10:00 and 14:00 in berlin
+		let morning = Utc.with_ymd_and_hms(2024, 12, 24, 9, 0, 0).unwrap();
+		let afternoon = Utc.with_ymd_and_hms(2024, 12, 24, 13, 0, 0).unwrap();
+		assert!(!HolidayProvider::is_holiday_at(morning, &calendar, None));
+		assert!(HolidayProvider::is_holiday_at(afternoon, &calendar, None));
+		// 2024-12-25 00:30 in berlin
+		let christmas = Utc.with_ymd_and_hms(2024, 12, 24, 23, 30, 0).unwrap();
+		assert!(HolidayProvider::is_holiday_at(christmas, &calendar, None));
+	}
+}
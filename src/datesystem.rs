@@ -1,6 +1,37 @@
-use chrono::{DateTime, Datelike, Duration, NaiveDate, Utc, Weekday};
+use chrono::{DateTime, Datelike, Duration, NaiveDate, TimeZone, Utc, Weekday};
 
-use super::types::occurrence::Occurrence;
+use super::{
+	calendars::astronomy::Astronomy,
+	types::{occurrence::Occurrence, season::Season},
+};
+
+/// Periodic terms (amplitude, phase and speed) for the equinoxes and solstices from Meeus, table 27.C
+const SEASON_TERMS: [(f64, f64, f64); 24] = [
+	(485.0, 324.96, 1934.136),
+	(203.0, 337.23, 32964.467),
+	(199.0, 342.08, 20.186),
+	(182.0, 27.85, 445267.112),
+	(156.0, 73.14, 45036.886),
+	(136.0, 171.52, 22518.443),
+	(77.0, 222.54, 65928.934),
+	(74.0, 296.72, 3034.906),
+	(70.0, 243.58, 9037.513),
+	(58.0, 119.81, 33718.147),
+	(52.0, 297.17, 150.678),
+	(50.0, 21.02, 2281.226),
+	(45.0, 247.54, 29929.562),
+	(44.0, 325.15, 31555.956),
+	(29.0, 60.93, 4443.417),
+	(18.0, 155.12, 67555.328),
+	(17.0, 288.79, 4562.452),
+	(16.0, 198.04, 62894.029),
+	(14.0, 199.76, 31436.921),
+	(12.0, 95.39, 14577.848),
+	(12.0, 287.11, 31931.756),
+	(12.0, 320.81, 34777.259),
+	(9.0, 227.73, 1222.114),
+	(8.0, 15.45, 16859.074),
+];
 
 pub struct DateSystem {}
 
@@ -14,14 +45,14 @@ impl DateSystem {
 	/// ```
 	///
 	/// use horus::{datesystem::DateSystem, types::occurrence::Occurrence};
-	/// use chrono::{TimeZone, Utc, Weekday};
+	/// use chrono::{NaiveDate, Weekday};
 	///
 	/// let result = DateSystem::find_day(2023, 1, chrono::Weekday::Mon, Occurrence::First);
 	///
 	/// assert_eq!(result.is_some(), true);
-	/// assert_eq!(result.unwrap(), Utc.with_ymd_and_hms(2023, 1, 2, 0, 0, 0).unwrap());
+	/// assert_eq!(result.unwrap(), NaiveDate::from_ymd_opt(2023, 1, 2).unwrap());
 	/// ```
-	pub fn find_day(year: i32, month: u32, day: Weekday, occurence: Occurrence) -> Option<DateTime<Utc>> {
+	pub fn find_day(year: i32, month: u32, day: Weekday, occurence: Occurrence) -> Option<NaiveDate> {
 		let oc = occurence as i32;
 		if oc == 0 || oc > 5 {
 			return None;
@@ -37,13 +68,7 @@ impl DateSystem {
 			return None;
 		}
 
-		Some(DateTime::<Utc>::from_utc(
-			NaiveDate::from_ymd_opt(year, month, resulted_day as u32)
-				.unwrap()
-				.and_hms_opt(0, 0, 0)
-				.unwrap(),
-			Utc,
-		))
+		NaiveDate::from_ymd_opt(year, month, resulted_day as u32)
 	}
 
 	/// Gets a the last occurence of a weekday in a specific month for a specific year
@@ -53,14 +78,14 @@ impl DateSystem {
 	/// ```
 	///
 	/// use horus::{datesystem::DateSystem, types::occurrence::Occurrence};
-	/// use chrono::{TimeZone, Utc, Weekday};
+	/// use chrono::{NaiveDate, Weekday};
 	///
 	/// let result = DateSystem::find_last_day(2023, 1, chrono::Weekday::Mon);
 	///
 	/// assert_eq!(result.is_some(), true);
-	/// assert_eq!(result.unwrap(), Utc.with_ymd_and_hms(2023, 1, 30, 0, 0, 0).unwrap());
+	/// assert_eq!(result.unwrap(), NaiveDate::from_ymd_opt(2023, 1, 30).unwrap());
 	/// ```
-	pub fn find_last_day(year: i32, month: u32, day: Weekday) -> Option<DateTime<Utc>> {
+	pub fn find_last_day(year: i32, month: u32, day: Weekday) -> Option<NaiveDate> {
 		let resulted_day = Self::find_day(year, month, day, Occurrence::Fifth);
 		if resulted_day.is_some() {
 			return resulted_day;
@@ -69,6 +94,32 @@ impl DateSystem {
 		Self::find_day(year, month, day, Occurrence::Fourth)
 	}
 
+	/// Gets the first given weekday after a specific occurence of another weekday in a specific month for a specific
+	/// year, like the tuesday after the first monday in november
+	///
+	/// # Example
+	///
+	/// ```
+	///
+	/// use horus::{datesystem::DateSystem, types::occurrence::Occurrence};
+	/// use chrono::{NaiveDate, Weekday};
+	///
+	/// let result = DateSystem::find_day_after(2026, 11, Weekday::Tue, Weekday::Mon, Occurrence::First);
+	///
+	/// assert_eq!(result.unwrap(), NaiveDate::from_ymd_opt(2026, 11, 3).unwrap());
+	/// ```
+	pub fn find_day_after(
+		year: i32,
+		month: u32,
+		day: Weekday,
+		after: Weekday,
+		occurence: Occurrence,
+	) -> Option<NaiveDate> {
+		let reference = Self::find_day(year, month, after, occurence)?;
+
+		reference.iter_days().skip(1).find(|date| date.weekday() == day)
+	}
+
 	/// Shifts the date depending on the weekday and the given days
 	///
 	/// # Example
@@ -76,19 +127,14 @@ impl DateSystem {
 	/// ```
 	///
 	/// use horus::{datesystem::DateSystem, types::occurrence::Occurrence};
-	/// use chrono::{TimeZone, Utc, Weekday};
+	/// use chrono::{NaiveDate, Weekday};
 	///
-	/// let date = Utc.with_ymd_and_hms(2023, 1, 29, 0, 0, 0).unwrap();
+	/// let date = NaiveDate::from_ymd_opt(2023, 1, 29).unwrap();
 	/// let result = DateSystem::shift(date, 1, 1, None);
 	///
-	/// assert_eq!(result, Utc.with_ymd_and_hms(2023, 1, 30, 0, 0, 0).unwrap());
+	/// assert_eq!(result, NaiveDate::from_ymd_opt(2023, 1, 30).unwrap());
 	/// ```
-	pub fn shift(
-		value: DateTime<Utc>,
-		days_saturday: i64,
-		days_sunday: i64,
-		days_monday: Option<i64>,
-	) -> DateTime<Utc> {
+	pub fn shift(value: NaiveDate, days_saturday: i64, days_sunday: i64, days_monday: Option<i64>) -> NaiveDate {
 		match value.weekday() {
 			Weekday::Sat => value + Duration::days(days_saturday),
 			Weekday::Sun => value + Duration::days(days_sunday),
@@ -103,6 +149,72 @@ impl DateSystem {
 		}
 	}
 
+	/// Gets the time of an equinox or solstice for a specific year with the low-precision algorithm of Meeus, which is
+	/// accurate to about a minute for the years 1000 until 3000
+	///
+	/// # Example
+	///
+	/// ```
+	///
+	/// use horus::{datesystem::DateSystem, types::season::Season};
+	/// let result = DateSystem::get_season_time(2024, Season::MarchEquinox);
+	///
+	/// assert_eq!(result.format("%Y-%m-%d %H").to_string(), "2024-03-20 03");
+	/// ```
+	pub fn get_season_time(year: i32, season: Season) -> DateTime<Utc> {
+		let y = (year - 2000) as f64 / 1000.0;
+		let mean = match season {
+			Season::MarchEquinox => {
+				2_451_623.809_84 + 365_242.374_04 * y + 0.051_69 * y.powi(2)
+					- 0.004_11 * y.powi(3)
+					- 0.000_57 * y.powi(4)
+			}
+			Season::JuneSolstice => {
+				2_451_716.567_67 + 365_241.626_03 * y + 0.003_25 * y.powi(2) + 0.008_88 * y.powi(3)
+					- 0.000_30 * y.powi(4)
+			}
+			Season::SeptemberEquinox => {
+				2_451_810.217_15 + 365_242.017_67 * y - 0.115_75 * y.powi(2)
+					+ 0.003_37 * y.powi(3)
+					+ 0.000_78 * y.powi(4)
+			}
+			Season::DecemberSolstice => {
+				2_451_900.059_52 + 365_242.740_49 * y - 0.062_23 * y.powi(2) - 0.008_23 * y.powi(3)
+					+ 0.000_32 * y.powi(4)
+			}
+		};
+
+		let t = (mean - 2_451_545.0) / 36_525.0;
+		let w = (35_999.373 * t - 2.47).to_radians();
+		let lambda = 1.0 + 0.0334 * w.cos() + 0.0007 * (2.0 * w).cos();
+		let sum: f64 = SEASON_TERMS
+			.iter()
+			.map(|(a, b, c)| a * (b + c * t).to_radians().cos())
+			.sum();
+		let julian_day = mean + 0.00001 * sum / lambda - Astronomy::delta_t(year as f64) / 86_400.0;
+
+		Utc.from_utc_datetime(&Astronomy::from_julian_day(julian_day))
+	}
+
+	/// Gets the day of an equinox or solstice for a specific year in the given time zone
+	///
+	/// # Example
+	///
+	/// ```
+	///
+	/// use horus::{datesystem::DateSystem, types::season::Season};
+	/// use chrono::{FixedOffset, NaiveDate};
+	///
+	/// let result = DateSystem::find_season(2024, Season::SeptemberEquinox, FixedOffset::east_opt(9 * 3600).unwrap());
+	///
+	/// assert_eq!(result, NaiveDate::from_ymd_opt(2024, 9, 22).unwrap());
+	/// ```
+	pub fn find_season<Tz: TimeZone>(year: i32, season: Season, time_zone: Tz) -> NaiveDate {
+		Self::get_season_time(year, season)
+			.with_timezone(&time_zone)
+			.date_naive()
+	}
+
 	fn get_days_of_month(year: i32, month: u32) -> i32 {
 		if month == 12 {
 			NaiveDate::from_ymd_opt(year + 1, 1, 1).unwrap()
@@ -113,3 +225,45 @@ impl DateSystem {
 		.num_days() as i32
 	}
 }
+
+#[cfg(test)]
+mod tests {
+	use chrono::{FixedOffset, NaiveDate, Timelike, Weekday};
+
+	use crate::types::{occurrence::Occurrence, season::Season};
+
+	use super::DateSystem;
+
+	#[test]
+	fn test_find_day_after() {
+		// the first monday of november 2022 is after the first tuesday
+		let result = DateSystem::find_day_after(2022, 11, Weekday::Tue, Weekday::Mon, Occurrence::First);
+		assert_eq!(result, NaiveDate::from_ymd_opt(2022, 11, 8));
+		let result = DateSystem::find_day_after(2024, 11, Weekday::Tue, Weekday::Mon, Occurrence::First);
+		assert_eq!(result, NaiveDate::from_ymd_opt(2024, 11, 5));
+		let result = DateSystem::find_day_after(2023, 2, Weekday::Thu, Weekday::Mon, Occurrence::Fifth);
+		assert_eq!(result, None);
+	}
+
+	#[test]
+	fn test_get_season_time() {
+		// june solstice at 2024-06-20 20:51 UTC
+		let result = DateSystem::get_season_time(2024, Season::JuneSolstice);
+		assert_eq!(result.date_naive(), NaiveDate::from_ymd_opt(2024, 6, 20).unwrap());
+		assert_eq!(result.hour(), 20);
+		assert!((49..=53).contains(&result.minute()));
+		// december solstice at 2024-12-21 09:20 UTC
+		let result = DateSystem::get_season_time(2024, Season::DecemberSolstice);
+		assert_eq!(result.hour(), 9);
+		assert!((18..=22).contains(&result.minute()));
+	}
+
+	#[test]
+	fn test_find_season_time_zone() {
+		// the june solstice of 2024 is on the 21st in japan and on the 20th in the americas
+		let result = DateSystem::find_season(2024, Season::JuneSolstice, FixedOffset::east_opt(9 * 3600).unwrap());
+		assert_eq!(result, NaiveDate::from_ymd_opt(2024, 6, 21).unwrap());
+		let result = DateSystem::find_season(2024, Season::JuneSolstice, FixedOffset::west_opt(5 * 3600).unwrap());
+		assert_eq!(result, NaiveDate::from_ymd_opt(2024, 6, 20).unwrap());
+	}
+}
@@ -0,0 +1,128 @@
+use std::collections::HashMap;
+
+use chrono_tz::Tz;
+
+use crate::{
+	holidayprovider::HolidayProvider,
+	types::{
+		countrycode::CountryCode, holidayperiod::HolidayPeriod, market::Market, publicholiday::PublicHoliday,
+		suspensionperiod::SuspensionPeriod, workingdayoverride::WorkingDayOverride,
+	},
+};
+
+/// Calendar which can be passed to the query functions of the [`HolidayProvider`]
+///
+/// It is implemented for [`CountryCode`] to get the holidays of the national providers, for [`Market`] to get the
+/// closing days of an exchange and for [`CustomCalendar`](crate::customcalendar::CustomCalendar) to get the holidays
+/// with company specific changes.
+pub trait HolidayCalendar {
+	/// Gets all holidays of the calendar for the given year
+	fn get_holidays(&self, year: i32) -> Vec<PublicHoliday>;
+
+	/// Gets all counties of the calendar. If no counties are existing, None will be returned.
+	fn get_counties(&self) -> Option<HashMap<String, String>>;
+
+	/// Gets all weekend days of the calendar for the given year which are declared as working days
+	fn get_working_day_overrides(&self, _year: i32) -> Vec<WorkingDayOverride> {
+		Vec::new()
+	}
+
+	/// Gets all holiday periods of the calendar which start in the given year, like the school holidays
+	fn get_holiday_periods(&self, _year: i32) -> Vec<HolidayPeriod> {
+		Vec::new()
+	}
+
+	/// Gets all periods in which the holidays of the calendar don't give a day off
+	fn get_suspension_periods(&self) -> Vec<SuspensionPeriod> {
+		Vec::new()
+	}
+
+	/// Gets the time zone of the calendar for the given county. If no time zone is known, None will be returned.
+	fn get_time_zone(&self, _county: Option<&str>) -> Option<Tz> {
+		None
+	}
+
+	/// Gets the county the calendar is restricted to, which is used by the query functions if no county is given
+	fn county(&self) -> Option<&str> {
+		None
+	}
+
+	/// Gets the locality the calendar is restricted to, which is used by the query functions if no locality is given
+	fn locality(&self) -> Option<&str> {
+		None
+	}
+}
+
+impl HolidayCalendar for CountryCode {
+	fn get_holidays(&self, year: i32) -> Vec<PublicHoliday> {
+		HolidayProvider::get_country_holidays(year, *self)
+	}
+
+	fn get_counties(&self) -> Option<HashMap<String, String>> {
+		HolidayProvider::get_country_counties(*self)
+	}
+
+	fn get_working_day_overrides(&self, year: i32) -> Vec<WorkingDayOverride> {
+		HolidayProvider::get_country_working_day_overrides(year, *self)
+	}
+
+	fn get_holiday_periods(&self, year: i32) -> Vec<HolidayPeriod> {
+		HolidayProvider::get_country_holiday_periods(year, *self)
+	}
+
+	fn get_suspension_periods(&self) -> Vec<SuspensionPeriod> {
+		HolidayProvider::get_country_suspension_periods(*self)
+	}
+
+	fn get_time_zone(&self, county: Option<&str>) -> Option<Tz> {
+		HolidayProvider::get_country_time_zone(*self, county)
+	}
+}
+
+impl HolidayCalendar for Market {
+	fn get_holidays(&self, year: i32) -> Vec<PublicHoliday> {
+		HolidayProvider::get_market_holidays(year, *self)
+	}
+
+	fn get_counties(&self) -> Option<HashMap<String, String>> {
+		None
+	}
+
+	fn get_time_zone(&self, _county: Option<&str>) -> Option<Tz> {
+		Some(HolidayProvider::get_market_time_zone(*self))
+	}
+}
+
+impl<T: HolidayCalendar + ?Sized> HolidayCalendar for &T {
+	fn get_holidays(&self, year: i32) -> Vec<PublicHoliday> {
+		(**self).get_holidays(year)
+	}
+
+	fn get_counties(&self) -> Option<HashMap<String, String>> {
+		(**self).get_counties()
+	}
+
+	fn get_working_day_overrides(&self, year: i32) -> Vec<WorkingDayOverride> {
+		(**self).get_working_day_overrides(year)
+	}
+
+	fn get_holiday_periods(&self, year: i32) -> Vec<HolidayPeriod> {
+		(**self).get_holiday_periods(year)
+	}
+
+	fn get_suspension_periods(&self) -> Vec<SuspensionPeriod> {
+		(**self).get_suspension_periods()
+	}
+
+	fn get_time_zone(&self, county: Option<&str>) -> Option<Tz> {
+		(**self).get_time_zone(county)
+	}
+
+	fn county(&self) -> Option<&str> {
+		(**self).county()
+	}
+
+	fn locality(&self) -> Option<&str> {
+		(**self).locality()
+	}
+}
@@ -1,13 +1,37 @@
 use std::collections::HashMap;
 
+use chrono::{DateTime, Datelike, Duration, NaiveDate, NaiveDateTime, NaiveTime, Utc, Weekday};
+use chrono_tz::Tz;
+
 use crate::{
+	holidaycalendar::HolidayCalendar,
+	proclamations::ProclamationRegistry,
 	providers::locations::{
-		germanholidayprovider::GermanHolidayProvider, greeceholidayprovider::GreeceHolidayProvider,
+		chinaholidayprovider::ChinaHolidayProvider, germanholidayprovider::GermanHolidayProvider,
+		greeceholidayprovider::GreeceHolidayProvider, guernseyholidayprovider::GuernseyHolidayProvider,
+		isleofmanholidayprovider::IsleOfManHolidayProvider, israelholidayprovider::IsraelHolidayProvider,
+		japanholidayprovider::JapanHolidayProvider, jerseyholidayprovider::JerseyHolidayProvider,
 		polandholidayprovider::PolandHolidayProvider, russiaholidayprovider::RussiaHolidayProvider,
+		saudiarabiaholidayprovider::SaudiArabiaHolidayProvider, southkoreaholidayprovider::SouthKoreaHolidayProvider,
 		ukrainholidayprovider::UkrainHolidayProvider, unitedkingdomholidayprovider::UnitedKingdomHolidayProvider,
 		unitedstatesholidayprovider::UnitedStatesHolidayProvider,
 	},
-	types::{countrycode::CountryCode, publicholiday::PublicHoliday},
+	providers::markets::{
+		federalreserveholidayprovider::FederalReserveHolidayProvider, lseholidayprovider::LseHolidayProvider,
+		nyseholidayprovider::NyseHolidayProvider, target2holidayprovider::Target2HolidayProvider,
+		xetraholidayprovider::XetraHolidayProvider,
+	},
+	types::{
+		countrycode::CountryCode,
+		holidaycomparison::{HolidayComparison, LocationComparison},
+		holidayperiod::HolidayPeriod,
+		location::Location,
+		market::Market,
+		publicholiday::PublicHoliday,
+		publicholidaytype::PublicHolidayType,
+		suspensionperiod::SuspensionPeriod,
+		workingdayoverride::WorkingDayOverride,
+	},
 };
 
 pub struct HolidayProvider {}
@@ -21,12 +45,15 @@ pub struct HolidayProvider {}
 ///
 /// let holidays = HolidayProvider::get_holidays(2022, CountryCode::DE);
 ///
-/// assert_eq!(holidays.len(), 19);
+/// assert_eq!(holidays.len(), 20);
 /// assert_eq!(holidays.is_empty(), false);
 /// assert_eq!(holidays[5].local_name, "Ostermontag".to_string());
 /// ```
 impl HolidayProvider {
-	/// Gets all holidays for the given year and country code
+	/// Hour of the evening at which the holidays which start at the evening before their date begin
+	const EVENING_HOUR: u32 = 18;
+
+	/// Gets all holidays for the given year and calendar, which is either a country code or a custom calendar
 	///
 	/// # Example
 	///
@@ -35,21 +62,589 @@ impl HolidayProvider {
 	///
 	/// let holidays = HolidayProvider::get_holidays(2022, CountryCode::DE);
 	///
-	/// assert_eq!(holidays.len(), 19);
+	/// assert_eq!(holidays.len(), 20);
 	/// assert_eq!(holidays.is_empty(), false);
 	/// assert_eq!(holidays[5].local_name, "Ostermontag".to_string());
 	/// ```
-	pub fn get_holidays(year: i32, country_code: CountryCode) -> Vec<PublicHoliday> {
-		if country_code == CountryCode::DE {
+	pub fn get_holidays<C: HolidayCalendar>(year: i32, calendar: C) -> Vec<PublicHoliday> {
+		calendar.get_holidays(year)
+	}
+
+	/// Gets all counties for the calendar. If no counties are existing, None will be returned.
+	///
+	/// # Example
+	///
+	/// ```
+	/// use horus::{holidayprovider::HolidayProvider, types::countrycode::CountryCode};
+	///
+	/// let counties = HolidayProvider::get_counties(CountryCode::DE);
+	///
+	/// assert_eq!(counties.unwrap().len(), 16);
+	/// ```
+	pub fn get_counties<C: HolidayCalendar>(calendar: C) -> Option<HashMap<String, String>> {
+		calendar.get_counties()
+	}
+
+	/// Gets all weekend days of the calendar for the given year which are declared as working days. The national
+	/// providers only know the working days which are declared for China from 2020 to 2025 and for Russia from 2021 to
+	/// 2025.
+	///
+	/// # Example
+	///
+	/// ```
+	/// use horus::{holidayprovider::HolidayProvider, types::countrycode::CountryCode};
+	///
+	/// let working_days = HolidayProvider::get_working_day_overrides(2024, CountryCode::RU);
+	///
+	/// assert_eq!(working_days[0].to_string(), "2024-04-27 Working day");
+	/// ```
+	pub fn get_working_day_overrides<C: HolidayCalendar>(year: i32, calendar: C) -> Vec<WorkingDayOverride> {
+		calendar.get_working_day_overrides(year)
+	}
+
+	/// Checks if the given date is a business day of the calendar. Saturdays and sundays are no business days, unless
+	/// they are declared as working days, and public and bank holidays are no business days, as well as the days off
+	/// which were transferred from a working day. Regional holidays are only considered for the given county, which
+	/// defaults to the county of the calendar, and holidays which last a part of the day don't change the business day.
+	///
+	/// # Example
+	///
+	/// ```
+	/// use horus::{holidayprovider::HolidayProvider, types::countrycode::CountryCode};
+	/// use chrono::NaiveDate;
+	///
+	/// let saturday = NaiveDate::from_ymd_opt(2024, 4, 27).unwrap();
+	/// let monday = NaiveDate::from_ymd_opt(2024, 4, 29).unwrap();
+	///
+	/// assert!(HolidayProvider::is_business_day(saturday, CountryCode::RU, None));
+	/// assert!(!HolidayProvider::is_business_day(monday, CountryCode::RU, None));
+	/// ```
+	pub fn is_business_day<C: HolidayCalendar>(date: NaiveDate, calendar: C, county: Option<&str>) -> bool {
+		Self::is_business_day_in_locality(date, calendar, county, None)
+	}
+
+	/// Checks if the given date is a business day of the calendar in the given locality of the county, which is either
+	/// an official municipality key (AGS) or the name of a municipality. Holidays which are restricted to some
+	/// municipalities of a county are only considered if the locality is one of them. If no locality is given, the
+	/// locality of the calendar is used.
+	///
+	/// # Example
+	///
+	/// ```
+	/// use horus::{holidayprovider::HolidayProvider, types::countrycode::CountryCode};
+	/// use chrono::NaiveDate;
+	///
+	/// let date = NaiveDate::from_ymd_opt(2024, 8, 8).unwrap();
+	/// let bavaria = Some("DE-BY");
+	///
+	/// assert!(!HolidayProvider::is_business_day_in_locality(date, CountryCode::DE, bavaria, Some("09761000")));
+	/// assert!(HolidayProvider::is_business_day_in_locality(date, CountryCode::DE, bavaria, Some("München")));
+	/// assert!(HolidayProvider::is_business_day(date, CountryCode::DE, bavaria));
+	/// ```
+	pub fn is_business_day_in_locality<C: HolidayCalendar>(
+		date: NaiveDate,
+		calendar: C,
+		county: Option<&str>,
+		locality: Option<&str>,
+	) -> bool {
+		let county = county.or(calendar.county());
+		let locality = locality.or(calendar.locality());
+		// a day off can be transferred across the turn of the year, so the overrides of the adjacent years are needed
+		let overrides: Vec<WorkingDayOverride> = (date.year() - 1..=date.year() + 1)
+			.flat_map(|year| calendar.get_working_day_overrides(year))
+			.collect();
+
+		if overrides
+			.iter()
+			.any(|working_day| working_day.transferred_to == Some(date))
+		{
+			return false;
+		}
+
+		let suspension_periods = calendar.get_suspension_periods();
+		let holiday = calendar.get_holidays(date.year()).iter().any(|holiday| {
+			holiday.date == date
+				&& !holiday.is_partial_day()
+				&& Self::is_day_off(holiday, county, locality)
+				&& !Self::is_suspended(holiday, &suspension_periods)
+		});
+		if holiday {
+			return false;
+		}
+
+		match date.weekday() {
+			Weekday::Sat | Weekday::Sun => overrides.iter().any(|working_day| working_day.date == date),
+			_ => true,
+		}
+	}
+
+	/// Gets the working hours on the given date from the regular opening and closing time. The hours of the holidays
+	/// which last a part of the day are taken out, so a holiday in the middle of the day splits the working hours into
+	/// a morning and an afternoon. If the date is no business day or no working time is left, an empty list will be
+	/// returned.
+	///
+	/// # Example
+	///
+	/// ```
+	/// use horus::{customcalendar::CustomCalendar, holidayprovider::HolidayProvider};
+	/// use horus::types::{countrycode::CountryCode, publicholidaytype::PublicHolidayType};
+	/// use chrono::{NaiveDate, NaiveTime};
+	///
+	/// let mut calendar = CustomCalendar::new(CountryCode::DE);
+	/// calendar.add_fixed_half_day(12, 24, "Heiligabend", "Christmas Eve", PublicHolidayType::Bank);
+	///
+	/// let opening = NaiveTime::from_hms_opt(9, 0, 0).unwrap();
+	/// let closing = NaiveTime::from_hms_opt(17, 0, 0).unwrap();
+	/// let date = NaiveDate::from_ymd_opt(2024, 12, 24).unwrap();
+	/// let result = HolidayProvider::get_working_hours(date, &calendar, None, opening, closing);
+	///
+	/// assert_eq!(result, vec![(opening, NaiveTime::from_hms_opt(12, 0, 0).unwrap())]);
+	/// ```
+	pub fn get_working_hours<C: HolidayCalendar>(
+		date: NaiveDate,
+		calendar: C,
+		county: Option<&str>,
+		opening: NaiveTime,
+		closing: NaiveTime,
+	) -> Vec<(NaiveTime, NaiveTime)> {
+		Self::get_working_hours_in_locality(date, calendar, county, None, opening, closing)
+	}
+
+	/// Gets the working hours on the given date in the given locality of the county, see
+	/// [`HolidayProvider::get_working_hours`] and [`HolidayProvider::is_business_day_in_locality`]
+	///
+	/// # Example
+	///
+	/// ```
+	/// use horus::{holidayprovider::HolidayProvider, types::countrycode::CountryCode};
+	/// use chrono::{NaiveDate, NaiveTime};
+	///
+	/// let opening = NaiveTime::from_hms_opt(9, 0, 0).unwrap();
+	/// let closing = NaiveTime::from_hms_opt(17, 0, 0).unwrap();
+	/// let date = NaiveDate::from_ymd_opt(2024, 8, 8).unwrap();
+	/// let bavaria = Some("DE-BY");
+	/// let augsburg = Some("Augsburg");
+	///
+	/// assert_eq!(
+	///     HolidayProvider::get_working_hours_in_locality(date, CountryCode::DE, bavaria, augsburg, opening, closing),
+	///     vec![]
+	/// );
+	/// assert_eq!(
+	///     HolidayProvider::get_working_hours(date, CountryCode::DE, bavaria, opening, closing),
+	///     vec![(opening, closing)]
+	/// );
+	/// ```
+	pub fn get_working_hours_in_locality<C: HolidayCalendar>(
+		date: NaiveDate,
+		calendar: C,
+		county: Option<&str>,
+		locality: Option<&str>,
+		opening: NaiveTime,
+		closing: NaiveTime,
+	) -> Vec<(NaiveTime, NaiveTime)> {
+		let county = county.or(calendar.county());
+		let locality = locality.or(calendar.locality());
+		if !Self::is_business_day_in_locality(date, &calendar, county, locality) || opening >= closing {
+			return Vec::new();
+		}
+
+		let suspension_periods = calendar.get_suspension_periods();
+		let mut working_hours = vec![(opening, closing)];
+		for holiday in calendar.get_holidays(date.year()).iter().filter(|holiday| {
+			holiday.date == date
+				&& holiday.is_partial_day()
+				&& Self::is_day_off(holiday, county, locality)
+				&& !Self::is_suspended(holiday, &suspension_periods)
+		}) {
+			working_hours = working_hours
+				.into_iter()
+				.flat_map(|(opening, closing)| {
+					let mut remaining = Vec::new();
+					// the working time before the holiday starts
+					if let Some(start_time) = holiday.start_time.filter(|start_time| opening < *start_time) {
+						remaining.push((opening, closing.min(start_time)));
+					}
+					// the working time after the holiday ends
+					if let Some(end_time) = holiday.end_time.filter(|end_time| *end_time < closing) {
+						remaining.push((opening.max(end_time), closing));
+					}
+					remaining
+				})
+				.collect();
+		}
+
+		working_hours
+	}
+
+	/// Checks if the given local time is within the working hours of the calendar
+	///
+	/// # Example
+	///
+	/// ```
+	/// use horus::{customcalendar::CustomCalendar, holidayprovider::HolidayProvider};
+	/// use horus::types::{countrycode::CountryCode, publicholidaytype::PublicHolidayType};
+	/// use chrono::{NaiveDate, NaiveTime};
+	///
+	/// let mut calendar = CustomCalendar::new(CountryCode::DE);
+	/// calendar.add_fixed_half_day(12, 31, "Silvester", "New Year's Eve", PublicHolidayType::Bank);
+	///
+	/// let opening = NaiveTime::from_hms_opt(9, 0, 0).unwrap();
+	/// let closing = NaiveTime::from_hms_opt(17, 0, 0).unwrap();
+	/// let morning = NaiveDate::from_ymd_opt(2024, 12, 31).unwrap().and_hms_opt(10, 0, 0).unwrap();
+	/// let afternoon = NaiveDate::from_ymd_opt(2024, 12, 31).unwrap().and_hms_opt(14, 0, 0).unwrap();
+	///
+	/// assert!(HolidayProvider::is_working_time(morning, &calendar, None, opening, closing));
+	/// assert!(!HolidayProvider::is_working_time(afternoon, &calendar, None, opening, closing));
+	/// ```
+	pub fn is_working_time<C: HolidayCalendar>(
+		time: NaiveDateTime,
+		calendar: C,
+		county: Option<&str>,
+		opening: NaiveTime,
+		closing: NaiveTime,
+	) -> bool {
+		Self::get_working_hours(time.date(), calendar, county, opening, closing)
+			.iter()
+			.any(|(opening, closing)| *opening <= time.time() && time.time() < *closing)
+	}
+
+	/// Adds the given number of business days to the date, a negative number goes back in time
+	///
+	/// # Example
+	///
+	/// ```
+	/// use horus::{holidayprovider::HolidayProvider, types::countrycode::CountryCode};
+	/// use chrono::NaiveDate;
+	///
+	/// let friday = NaiveDate::from_ymd_opt(2024, 4, 26).unwrap();
+	/// let result = HolidayProvider::add_business_days(friday, 2, CountryCode::RU, None);
+	///
+	/// assert_eq!(result, NaiveDate::from_ymd_opt(2024, 5, 2).unwrap());
+	/// ```
+	pub fn add_business_days<C: HolidayCalendar>(
+		date: NaiveDate,
+		days: i64,
+		calendar: C,
+		county: Option<&str>,
+	) -> NaiveDate {
+		let step = Duration::days(days.signum());
+		let mut result = date;
+		let mut remaining = days.abs();
+
+		while remaining > 0 {
+			result += step;
+			if Self::is_business_day(result, &calendar, county) {
+				remaining -= 1;
+			}
+		}
+
+		result
+	}
+
+	/// Gets all holiday periods of the calendar which start in the given year. The national providers only contain the
+	/// german school holidays which start in 2024 and 2025.
+	///
+	/// # Example
+	///
+	/// ```
+	/// use horus::{holidayprovider::HolidayProvider, types::countrycode::CountryCode};
+	///
+	/// let periods = HolidayProvider::get_holiday_periods(2024, CountryCode::DE);
+	///
+	/// assert_eq!(periods[0].to_string(), "2024-03-23..2024-04-05 Easter holidays");
+	/// ```
+	pub fn get_holiday_periods<C: HolidayCalendar>(year: i32, calendar: C) -> Vec<HolidayPeriod> {
+		calendar.get_holiday_periods(year)
+	}
+
+	/// Gets all periods in which the holidays of the calendar don't give a day off. The holidays in these periods are
+	/// still returned, but they aren't considered by the business day calculations.
+	///
+	/// # Example
+	///
+	/// ```
+	/// use horus::{holidayprovider::HolidayProvider, types::countrycode::CountryCode};
+	/// use chrono::NaiveDate;
+	///
+	/// let periods = HolidayProvider::get_suspension_periods(CountryCode::UA);
+	/// let independence_day = NaiveDate::from_ymd_opt(2023, 8, 24).unwrap();
+	///
+	/// assert_eq!(periods[0].name, "Martial law");
+	/// assert!(HolidayProvider::is_business_day(independence_day, CountryCode::UA, None));
+	/// ```
+	pub fn get_suspension_periods<C: HolidayCalendar>(calendar: C) -> Vec<SuspensionPeriod> {
+		calendar.get_suspension_periods()
+	}
+
+	/// Checks if the given date is in a school holiday period of the calendar for the given county. Periods which
+	/// started in the previous year are considered as well. The national providers only contain the german school
+	/// holidays which start in 2024 and 2025, so any other date returns false unless the calendar provides its own
+	/// periods.
+	///
+	/// # Example
+	///
+	/// ```
+	/// use horus::{holidayprovider::HolidayProvider, types::countrycode::CountryCode};
+	/// use chrono::NaiveDate;
+	///
+	/// let date = NaiveDate::from_ymd_opt(2024, 7, 15).unwrap();
+	/// let christmas = NaiveDate::from_ymd_opt(2024, 12, 27).unwrap();
+	///
+	/// assert!(HolidayProvider::is_school_holiday(date, CountryCode::DE, "DE-NW"));
+	/// assert!(!HolidayProvider::is_school_holiday(date, CountryCode::DE, "DE-BY"));
+	/// assert!(HolidayProvider::is_school_holiday(christmas, CountryCode::DE, "DE-BY"));
+	/// ```
+	pub fn is_school_holiday<C: HolidayCalendar>(date: NaiveDate, calendar: C, county: &str) -> bool {
+		[date.year() - 1, date.year()].iter().any(|year| {
+			calendar.get_holiday_periods(*year).iter().any(|period| {
+				period.holiday_type == PublicHolidayType::School && period.is_in_county(county) && period.contains(date)
+			})
+		})
+	}
+
+	/// Checks if the given instant is on a day off of the calendar. The instant is converted into the local time of
+	/// the time zone of the county or, if neither a county is given nor the calendar has one, of the country. Only
+	/// public and bank holidays whose days off aren't suspended are considered, regional holidays only for the county,
+	/// and holidays which last a part of the day only match within their hours. Holidays which start at the evening
+	/// before their date, like the holidays of the hebrew calendar, last from 6 p.m. on the day before until 6 p.m. on
+	/// their date.
+	///
+	/// # Example
+	///
+	/// ```
+	/// use horus::{holidayprovider::HolidayProvider, types::countrycode::CountryCode};
+	/// use chrono::{TimeZone, Utc};
+	///
+	/// let instant = Utc.with_ymd_and_hms(2024, 7, 4, 5, 0, 0).unwrap();
+	/// let yom_kippur_eve = Utc.with_ymd_and_hms(2024, 10, 11, 17, 0, 0).unwrap();
+	///
+	/// assert!(HolidayProvider::is_holiday_at(instant, CountryCode::US, Some("US-NY")));
+	/// assert!(!HolidayProvider::is_holiday_at(instant, CountryCode::US, Some("US-CA")));
+	/// assert!(HolidayProvider::is_holiday_at(yom_kippur_eve, CountryCode::IL, None));
+	/// ```
+	pub fn is_holiday_at<C: HolidayCalendar>(instant: DateTime<Utc>, calendar: C, county: Option<&str>) -> bool {
+		Self::is_holiday_at_in_locality(instant, calendar, county, None)
+	}
+
+	/// Checks if the given instant is on a day off of the calendar in the given locality of the county, see
+	/// [`HolidayProvider::is_holiday_at`] and [`HolidayProvider::is_business_day_in_locality`]
+	///
+	/// # Example
+	///
+	/// ```
+	/// use horus::{holidayprovider::HolidayProvider, types::countrycode::CountryCode};
+	/// use chrono::{TimeZone, Utc};
+	///
+	/// let instant = Utc.with_ymd_and_hms(2024, 8, 8, 10, 0, 0).unwrap();
+	/// let bavaria = Some("DE-BY");
+	///
+	/// assert!(HolidayProvider::is_holiday_at_in_locality(instant, CountryCode::DE, bavaria, Some("09761000")));
+	/// assert!(!HolidayProvider::is_holiday_at(instant, CountryCode::DE, bavaria));
+	/// ```
+	pub fn is_holiday_at_in_locality<C: HolidayCalendar>(
+		instant: DateTime<Utc>,
+		calendar: C,
+		county: Option<&str>,
+		locality: Option<&str>,
+	) -> bool {
+		let county = county.or(calendar.county());
+		let locality = locality.or(calendar.locality());
+		let local = match calendar.get_time_zone(county) {
+			Some(time_zone) => instant.with_timezone(&time_zone).naive_local(),
+			None => instant.naive_utc(),
+		};
+		let evening = NaiveTime::from_hms_opt(Self::EVENING_HOUR, 0, 0).unwrap();
+		let suspension_periods = calendar.get_suspension_periods();
+
+		// a holiday on the next day, which can be in the next year, may start at the evening of the day
+		let mut years = vec![local.year()];
+		let next_day = local.date() + Duration::days(1);
+		if next_day.year() != local.year() {
+			years.push(next_day.year());
+		}
+
+		years
+			.into_iter()
+			.flat_map(|year| calendar.get_holidays(year))
+			.filter(|holiday| {
+				Self::is_day_off(holiday, county, locality) && !Self::is_suspended(holiday, &suspension_periods)
+			})
+			.any(|holiday| {
+				if holiday.starts_previous_evening {
+					let start = (holiday.date - Duration::days(1)).and_time(evening);
+					start <= local && local < holiday.date.and_time(evening)
+				} else {
+					holiday.date == local.date()
+						&& holiday.start_time.map_or(true, |start_time| start_time <= local.time())
+						&& holiday.end_time.map_or(true, |end_time| local.time() < end_time)
+				}
+			})
+	}
+
+	/// Compares the days off of the given locations in a year, whose calendars can be national providers, custom
+	/// calendars or markets. Only public and bank holidays which last the whole day, aren't suspended and apply to the
+	/// county and the locality of a location are considered, which fall back to the ones of its calendar.
+	///
+	/// # Example
+	///
+	/// ```
+	/// use horus::holidayprovider::HolidayProvider;
+	/// use horus::types::{countrycode::CountryCode, location::Location, market::Market};
+	///
+	/// let germany = Location::new(CountryCode::DE, Some("DE-BY"));
+	/// let poland = Location::new(CountryCode::PL, None);
+	/// let comparison = HolidayProvider::compare_holidays(2024, &[germany, poland]);
+	///
+	/// assert_eq!(comparison.shared[0].name, "New Year's Day");
+	///
+	/// let frankfurt = Location::new(CountryCode::DE, Some("DE-HE"));
+	/// let exchange = Location::from_calendar("New York Stock Exchange", Market::NYSE, None);
+	/// let comparison = HolidayProvider::compare_holidays(2024, &[frankfurt, exchange.clone()]);
+	///
+	/// assert_eq!(comparison.only_in(&exchange).unwrap()[0].name, "Martin Luther King, Jr. Day");
+	/// ```
+	pub fn compare_holidays(year: i32, locations: &[Location]) -> HolidayComparison {
+		let days_off: Vec<Vec<PublicHoliday>> = locations
+			.iter()
+			.map(|location| {
+				let county = location.get_county();
+				let locality = location.get_locality();
+				let suspension_periods = location.calendar.get_suspension_periods();
+				let mut holidays: Vec<PublicHoliday> = Self::get_holidays(year, &*location.calendar)
+					.into_iter()
+					.filter(|holiday| {
+						!holiday.is_partial_day()
+							&& Self::is_day_off(holiday, county, locality)
+							&& !Self::is_suspended(holiday, &suspension_periods)
+					})
+					.collect();
+				holidays.sort_by_key(|holiday| holiday.date);
+				holidays
+			})
+			.collect();
+		let is_shared = |date: NaiveDate| {
+			days_off
+				.iter()
+				.all(|holidays| holidays.iter().any(|holiday| holiday.date == date))
+		};
+		let is_day_off_elsewhere = |index: usize, date: NaiveDate| {
+			days_off
+				.iter()
+				.enumerate()
+				.any(|(other, holidays)| other != index && holidays.iter().any(|holiday| holiday.date == date))
+		};
+
+		let mut shared: Vec<PublicHoliday> = days_off
+			.first()
+			.map(|holidays| {
+				holidays
+					.iter()
+					.filter(|holiday| is_shared(holiday.date))
+					.cloned()
+					.collect()
+			})
+			.unwrap_or_default();
+		shared.dedup_by_key(|holiday| holiday.date);
+
+		let start = NaiveDate::from_ymd_opt(year, 1, 1).unwrap();
+		let locations = locations
+			.iter()
+			.zip(days_off.iter())
+			.enumerate()
+			.map(|(index, (location, holidays))| LocationComparison {
+				location: location.clone(),
+				not_shared: holidays
+					.iter()
+					.filter(|holiday| !is_shared(holiday.date))
+					.cloned()
+					.collect(),
+				only: holidays
+					.iter()
+					.filter(|holiday| !is_day_off_elsewhere(index, holiday.date))
+					.cloned()
+					.collect(),
+				working_days: start
+					.iter_days()
+					.take_while(|date| date.year() == year)
+					.filter(|date| {
+						Self::is_business_day_in_locality(
+							*date,
+							&*location.calendar,
+							location.county.as_deref(),
+							location.locality.as_deref(),
+						)
+					})
+					.count() as u32,
+			})
+			.collect();
+
+		HolidayComparison { year, shared, locations }
+	}
+
+	/// Checks if the holiday gives a day off in the given county and locality, which are public and bank holidays
+	/// whose days off aren't suspended
+	fn is_day_off(holiday: &PublicHoliday, county: Option<&str>, locality: Option<&str>) -> bool {
+		holiday.non_working
+			&& matches!(
+				holiday.holiday_type,
+				PublicHolidayType::Public | PublicHolidayType::Bank
+			) && match county {
+			Some(county) => holiday.is_in_location(county, locality),
+			None => holiday.global(),
+		}
+	}
+
+	/// Checks if the holiday is in one of the suspension periods of the calendar, which are respected even if the
+	/// calendar doesn't mark its holidays as working days
+	fn is_suspended(holiday: &PublicHoliday, suspension_periods: &[SuspensionPeriod]) -> bool {
+		suspension_periods.iter().any(|period| period.contains(holiday.date))
+	}
+
+	/// Gets all holidays of the national provider for the given country code, including the proclaimed holidays. The
+	/// holidays in a suspension period are marked as working days.
+	pub(crate) fn get_country_holidays(year: i32, country_code: CountryCode) -> Vec<PublicHoliday> {
+		let mut holidays = Self::get_provider_holidays(year, country_code);
+		ProclamationRegistry::apply(year, country_code, &mut holidays);
+
+		let suspension_periods = Self::get_country_suspension_periods(country_code);
+		for holiday in holidays
+			.iter_mut()
+			.filter(|holiday| suspension_periods.iter().any(|period| period.contains(holiday.date)))
+		{
+			holiday.set_non_working(false);
+		}
+
+		holidays
+	}
+
+	/// Gets the regular holidays of the national provider, without the proclaimed holidays
+	fn get_provider_holidays(year: i32, country_code: CountryCode) -> Vec<PublicHoliday> {
+		if country_code == CountryCode::CN {
+			return ChinaHolidayProvider::get_holidays(year);
+		} else if country_code == CountryCode::DE {
 			return GermanHolidayProvider::get_holidays(year);
 		} else if country_code == CountryCode::GB {
 			return UnitedKingdomHolidayProvider::get_holidays(year);
 		} else if country_code == CountryCode::GR {
 			return GreeceHolidayProvider::get_holidays(year);
+		} else if country_code == CountryCode::GG {
+			return GuernseyHolidayProvider::get_holidays(year);
+		} else if country_code == CountryCode::IL {
+			return IsraelHolidayProvider::get_holidays(year);
+		} else if country_code == CountryCode::IM {
+			return IsleOfManHolidayProvider::get_holidays(year);
+		} else if country_code == CountryCode::JE {
+			return JerseyHolidayProvider::get_holidays(year);
+		} else if country_code == CountryCode::JP {
+			return JapanHolidayProvider::get_holidays(year);
+		} else if country_code == CountryCode::KR {
+			return SouthKoreaHolidayProvider::get_holidays(year);
 		} else if country_code == CountryCode::PL {
 			return PolandHolidayProvider::get_holidays(year);
 		} else if country_code == CountryCode::RU {
 			return RussiaHolidayProvider::get_holidays(year);
+		} else if country_code == CountryCode::SA {
+			return SaudiArabiaHolidayProvider::get_holidays(year);
 		} else if country_code == CountryCode::UA {
 			return UkrainHolidayProvider::get_holidays(year);
 		} else if country_code == CountryCode::US {
@@ -59,18 +654,94 @@ impl HolidayProvider {
 		Vec::new()
 	}
 
-	/// Gets all counties for the country code. If no counties are existing, None will be returned.
-	///
-	/// # Example
-	///
-	/// ```
-	/// use horus::{holidayprovider::HolidayProvider, types::countrycode::CountryCode};
-	///
-	/// let counties = HolidayProvider::get_counties(CountryCode::DE);
-	///
-	/// assert_eq!(counties.unwrap().len(), 16);
-	/// ```
-	pub fn get_counties(country_code: CountryCode) -> Option<HashMap<String, String>> {
+	/// Gets all closing days and early closes of the given market
+	pub(crate) fn get_market_holidays(year: i32, market: Market) -> Vec<PublicHoliday> {
+		match market {
+			Market::FederalReserve => FederalReserveHolidayProvider::get_holidays(year),
+			Market::NYSE => NyseHolidayProvider::get_holidays(year),
+			Market::LSE => LseHolidayProvider::get_holidays(year),
+			Market::Xetra => XetraHolidayProvider::get_holidays(year),
+			Market::TARGET2 => Target2HolidayProvider::get_holidays(year),
+		}
+	}
+
+	/// Gets the time zone in which the given market operates
+	pub(crate) fn get_market_time_zone(market: Market) -> Tz {
+		match market {
+			Market::FederalReserve | Market::NYSE => Tz::America__New_York,
+			Market::LSE => Tz::Europe__London,
+			Market::Xetra | Market::TARGET2 => Tz::Europe__Berlin,
+		}
+	}
+
+	/// Gets all working day overrides of the national provider for the given country code
+	pub(crate) fn get_country_working_day_overrides(year: i32, country_code: CountryCode) -> Vec<WorkingDayOverride> {
+		if country_code == CountryCode::CN {
+			return ChinaHolidayProvider::get_working_day_overrides(year);
+		} else if country_code == CountryCode::RU {
+			return RussiaHolidayProvider::get_working_day_overrides(year);
+		}
+
+		Vec::new()
+	}
+
+	/// Gets all holiday periods of the national provider for the given country code
+	pub(crate) fn get_country_holiday_periods(year: i32, country_code: CountryCode) -> Vec<HolidayPeriod> {
+		if country_code == CountryCode::DE {
+			return GermanHolidayProvider::get_school_holidays(year);
+		}
+
+		Vec::new()
+	}
+
+	/// Gets all suspension periods of the national provider for the given country code
+	pub(crate) fn get_country_suspension_periods(country_code: CountryCode) -> Vec<SuspensionPeriod> {
+		if country_code == CountryCode::UA {
+			return UkrainHolidayProvider::get_suspension_periods();
+		}
+
+		Vec::new()
+	}
+
+	/// Gets the time zone of the national provider for the given country code and county
+	pub(crate) fn get_country_time_zone(country_code: CountryCode, county: Option<&str>) -> Option<Tz> {
+		if country_code == CountryCode::CN {
+			return Some(Tz::Asia__Shanghai);
+		} else if country_code == CountryCode::DE {
+			return Some(Tz::Europe__Berlin);
+		} else if country_code == CountryCode::GB {
+			return Some(Tz::Europe__London);
+		} else if country_code == CountryCode::GR {
+			return Some(Tz::Europe__Athens);
+		} else if country_code == CountryCode::GG {
+			return Some(Tz::Europe__Guernsey);
+		} else if country_code == CountryCode::IL {
+			return Some(Tz::Asia__Jerusalem);
+		} else if country_code == CountryCode::IM {
+			return Some(Tz::Europe__Isle_of_Man);
+		} else if country_code == CountryCode::JE {
+			return Some(Tz::Europe__Jersey);
+		} else if country_code == CountryCode::JP {
+			return Some(Tz::Asia__Tokyo);
+		} else if country_code == CountryCode::KR {
+			return Some(Tz::Asia__Seoul);
+		} else if country_code == CountryCode::PL {
+			return Some(Tz::Europe__Warsaw);
+		} else if country_code == CountryCode::RU {
+			return Some(Tz::Europe__Moscow);
+		} else if country_code == CountryCode::SA {
+			return Some(Tz::Asia__Riyadh);
+		} else if country_code == CountryCode::UA {
+			return Some(Tz::Europe__Kyiv);
+		} else if country_code == CountryCode::US {
+			return Some(UnitedStatesHolidayProvider::get_time_zone(county));
+		}
+
+		None
+	}
+
+	/// Gets all counties of the national provider for the given country code
+	pub(crate) fn get_country_counties(country_code: CountryCode) -> Option<HashMap<String, String>> {
 		if country_code == CountryCode::DE {
 			return Some(GermanHolidayProvider::get_counties());
 		} else if country_code == CountryCode::GB {
@@ -82,3 +753,127 @@ impl HolidayProvider {
 		None
 	}
 }
+
+#[cfg(test)]
+mod tests {
+	use chrono::{NaiveDate, NaiveTime, TimeZone, Utc};
+
+	use crate::{
+		customcalendar::CustomCalendar,
+		types::{
+			countrycode::CountryCode, holidayid::HolidayId, publicholiday::PublicHoliday,
+			publicholidaytype::PublicHolidayType,
+		},
+	};
+
+	use super::HolidayProvider;
+
+	fn partial_day(start_time: Option<u32>, end_time: Option<u32>) -> CustomCalendar {
+		let mut holiday = PublicHoliday::new_non_fixed(
+			HolidayId::new("TEST_NAME"),
+			NaiveDate::from_ymd_opt(2024, 6, 5).unwrap(),
+			"test",
+			"test name",
+			CountryCode::DE,
+			None,
+			None,
+			PublicHolidayType::Bank,
+		);
+		if let Some(hour) = start_time {
+			holiday.set_start_time(NaiveTime::from_hms_opt(hour, 0, 0).unwrap());
+		}
+		if let Some(hour) = end_time {
+			holiday.set_end_time(NaiveTime::from_hms_opt(hour, 0, 0).unwrap());
+		}
+
+		let mut calendar = CustomCalendar::new(CountryCode::DE);
+		calendar.add_holiday(holiday);
+		calendar
+	}
+
+	#[test]
+	fn test_get_working_hours() {
+		let date = NaiveDate::from_ymd_opt(2024, 6, 5).unwrap();
+		let hour = |hour| NaiveTime::from_hms_opt(hour, 0, 0).unwrap();
+		let working_hours =
+			|calendar: &CustomCalendar| HolidayProvider::get_working_hours(date, calendar, None, hour(9), hour(17));
+
+		assert_eq!(working_hours(&partial_day(Some(12), None)), vec![(hour(9), hour(12))]);
+		assert_eq!(working_hours(&partial_day(None, Some(12))), vec![(hour(12), hour(17))]);
+		assert_eq!(working_hours(&partial_day(Some(8), None)), vec![]);
+		assert_eq!(working_hours(&partial_day(Some(9), None)), vec![]);
+		assert_eq!(working_hours(&partial_day(None, Some(17))), vec![]);
+	}
+
+	#[test]
+	fn test_get_working_hours_with_start_and_end_time() {
+		let date = NaiveDate::from_ymd_opt(2024, 6, 5).unwrap();
+		let hour = |hour| NaiveTime::from_hms_opt(hour, 0, 0).unwrap();
+		let working_hours =
+			|calendar: &CustomCalendar| HolidayProvider::get_working_hours(date, calendar, None, hour(9), hour(17));
+
+		assert_eq!(
+			working_hours(&partial_day(Some(6), Some(10))),
+			vec![(hour(10), hour(17))]
+		);
+		assert_eq!(
+			working_hours(&partial_day(Some(15), Some(20))),
+			vec![(hour(9), hour(15))]
+		);
+		// a holiday in the middle of the day keeps the afternoon
+		let midday = partial_day(Some(11), Some(14));
+		assert_eq!(working_hours(&midday), vec![(hour(9), hour(11)), (hour(14), hour(17))]);
+		let at = |hour| date.and_hms_opt(hour, 0, 0).unwrap();
+		assert!(HolidayProvider::is_working_time(
+			at(10),
+			&midday,
+			None,
+			hour(9),
+			hour(17)
+		));
+		assert!(!HolidayProvider::is_working_time(
+			at(12),
+			&midday,
+			None,
+			hour(9),
+			hour(17)
+		));
+		assert!(HolidayProvider::is_working_time(
+			at(15),
+			&midday,
+			None,
+			hour(9),
+			hour(17)
+		));
+		assert_eq!(working_hours(&partial_day(Some(8), Some(18))), vec![]);
+	}
+
+	#[test]
+	fn test_is_holiday_at() {
+		let good_friday = Utc.with_ymd_and_hms(2024, 3, 29, 15, 0, 0).unwrap();
+		assert!(!HolidayProvider::is_holiday_at(
+			good_friday,
+			CountryCode::US,
+			Some("US-TX")
+		));
+		assert!(HolidayProvider::is_holiday_at(
+			good_friday,
+			CountryCode::DE,
+			Some("DE-BY")
+		));
+
+		// martial law suspends the days off in ukraine
+		let independence_day = Utc.with_ymd_and_hms(2023, 8, 24, 9, 0, 0).unwrap();
+		assert!(!HolidayProvider::is_holiday_at(independence_day, CountryCode::UA, None));
+	}
+
+	#[test]
+	fn test_is_holiday_at_previous_evening() {
+		// yom kippur is on 2024-10-12, the time in israel is three hours ahead of utc
+		let at = |day, hour| Utc.with_ymd_and_hms(2024, 10, day, hour, 0, 0).unwrap();
+		assert!(!HolidayProvider::is_holiday_at(at(11, 14), CountryCode::IL, None));
+		assert!(HolidayProvider::is_holiday_at(at(11, 15), CountryCode::IL, None));
+		assert!(HolidayProvider::is_holiday_at(at(12, 14), CountryCode::IL, None));
+		assert!(!HolidayProvider::is_holiday_at(at(12, 15), CountryCode::IL, None));
+	}
+}
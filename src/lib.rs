@@ -1,15 +1,37 @@
+// the tests compare booleans with assert_eq
+#![cfg_attr(test, allow(clippy::bool_assert_comparison))]
+// map_or(true, ...) is kept instead of is_none_or, which would raise the minimum rust version to 1.82
+#![allow(clippy::unnecessary_map_or)]
+
+pub mod calendars;
+
+pub mod customcalendar;
+pub use customcalendar::CustomCalendar;
+
 pub mod datesystem;
 #[allow(deprecated)]
 pub use datesystem::DateSystem;
 
+pub mod holidaycalendar;
+pub use holidaycalendar::HolidayCalendar;
+
 pub mod holidayprovider;
 #[allow(deprecated)]
 pub use holidayprovider::HolidayProvider;
 
+pub mod proclamations;
+pub use proclamations::ProclamationRegistry;
+
 mod providers;
 
+pub mod teamcalendar;
+pub use teamcalendar::TeamCalendar;
+
+pub mod translations;
+pub use translations::TranslationCatalog;
+
 pub mod types;
 pub use types::{
-	countrycode::CountryCode, occurrence::Occurrence, publicholiday::PublicHoliday,
-	publicholidaytype::PublicHolidayType,
+	countrycode::CountryCode, market::Market, occurrence::Occurrence, publicholiday::PublicHoliday,
+	publicholidaytype::PublicHolidayType, recurrence::Recurrence,
 };
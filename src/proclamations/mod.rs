@@ -0,0 +1,198 @@
+use std::sync::RwLock;
+
+use chrono::{Datelike, NaiveDate};
+use lazy_static::lazy_static;
+
+use crate::types::{countrycode::CountryCode, proclaimedholiday::ProclaimedHoliday, publicholiday::PublicHoliday};
+
+const PROCLAIMED: &str = include_str!("proclaimed.json");
+
+lazy_static! {
+	static ref PROCLAMATIONS: RwLock<Vec<ProclaimedHoliday>> = RwLock::new(serde_json::from_str(PROCLAIMED).unwrap());
+}
+
+pub struct ProclamationRegistry {}
+
+/// Registry of the one-off holidays which were proclaimed by the governments, like jubilees, state funerals or moved
+/// bank holidays
+///
+/// The registry contains the proclamations which are known to the crate and is merged into the holidays of the
+/// national providers. Proclamations can be added at runtime, one by one or from a json file, and are shared by the
+/// whole process, so new holidays can be used without waiting for a new release.
+///
+/// # Example
+///
+/// ```
+/// use horus::{holidayprovider::HolidayProvider, proclamations::ProclamationRegistry, types::countrycode::CountryCode};
+///
+/// let count = ProclamationRegistry::load(
+///     r#"[{
+///         "date": "2031-06-13",
+///         "local_name": "Fête de la Victoire",
+///         "name": "Victory Day",
+///         "country_code": "FR",
+///         "source": "https://www.legifrance.gouv.fr"
+///     }]"#,
+/// )
+/// .unwrap();
+///
+/// assert_eq!(count, 1);
+/// assert_eq!(HolidayProvider::get_holidays(2031, CountryCode::FR)[0].to_string(), "2031-06-13 Victory Day");
+/// ```
+impl ProclamationRegistry {
+	/// Gets all proclamations of the country for the given year
+	///
+	/// # Example
+	///
+	/// ```
+	/// use horus::{proclamations::ProclamationRegistry, types::countrycode::CountryCode};
+	///
+	/// let proclamations = ProclamationRegistry::get(2022, CountryCode::GB);
+	///
+	/// assert_eq!(proclamations[2].name, "Queen’s State Funeral");
+	/// assert!(proclamations[2].source.starts_with("https://www.gov.uk/"));
+	/// ```
+	pub fn get(year: i32, country_code: CountryCode) -> Vec<ProclaimedHoliday> {
+		let mut proclamations: Vec<ProclaimedHoliday> = PROCLAMATIONS
+			.read()
+			.unwrap()
+			.iter()
+			.filter(|proclamation| proclamation.country_code == country_code && proclamation.date.year() == year)
+			.cloned()
+			.collect();
+		proclamations.sort_by_key(|a| a.date);
+
+		proclamations
+	}
+
+	/// Adds the proclamation, an existing proclamation with the same country, date and name will be overridden
+	pub fn add(proclamation: ProclaimedHoliday) {
+		let mut proclamations = PROCLAMATIONS.write().unwrap();
+		proclamations.retain(|existing| {
+			existing.country_code != proclamation.country_code
+				|| existing.date != proclamation.date
+				|| existing.name != proclamation.name
+		});
+		proclamations.push(proclamation);
+	}
+
+	/// Adds all proclamations of the json array, which contains objects with the fields of [`ProclaimedHoliday`].
+	/// Returns the number of added proclamations or the error if the json is invalid, in which case nothing is added.
+	pub fn load(json: &str) -> Result<usize, serde_json::Error> {
+		let proclamations: Vec<ProclaimedHoliday> = serde_json::from_str(json)?;
+		let count = proclamations.len();
+		for proclamation in proclamations {
+			Self::add(proclamation);
+		}
+
+		Ok(count)
+	}
+
+	/// Removes all proclamations of the country on the given date. Returns true if a proclamation was removed.
+	pub fn remove(country_code: CountryCode, date: NaiveDate) -> bool {
+		let mut proclamations = PROCLAMATIONS.write().unwrap();
+		let count = proclamations.len();
+		proclamations.retain(|proclamation| proclamation.country_code != country_code || proclamation.date != date);
+
+		proclamations.len() != count
+	}
+
+	/// Adds the proclamations of the country to the holidays and removes the regular dates of the moved holidays
+	pub(crate) fn apply(year: i32, country_code: CountryCode, holidays: &mut Vec<PublicHoliday>) {
+		let proclamations = Self::get(year, country_code);
+		if proclamations.is_empty() {
+			return;
+		}
+
+		holidays.retain(|holiday| {
+			!proclamations
+				.iter()
+				.any(|proclamation| proclamation.replaces == Some(holiday.date) && proclamation.name == holiday.name)
+		});
+		holidays.extend(proclamations.iter().map(|proclamation| proclamation.to_holiday()));
+		holidays.sort_by_key(|a| a.date);
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use chrono::NaiveDate;
+
+	use crate::{
+		holidayprovider::HolidayProvider,
+		types::{countrycode::CountryCode, proclaimedholiday::ProclaimedHoliday, publicholidaytype::PublicHolidayType},
+	};
+
+	use super::ProclamationRegistry;
+
+	#[test]
+	fn test_get() {
+		let result: Vec<String> = ProclamationRegistry::get(2022, CountryCode::GB)
+			.iter()
+			.map(|proclamation| proclamation.to_holiday().to_string())
+			.collect();
+		assert_eq!(
+			result,
+			vec![
+				"2022-06-02 Spring Bank Holiday",
+				"2022-06-03 Queen’s Platinum Jubilee",
+				"2022-09-19 Queen’s State Funeral",
+			]
+		);
+		assert_eq!(ProclamationRegistry::get(2018, CountryCode::PL).len(), 1);
+		assert!(ProclamationRegistry::get(2024, CountryCode::GB).is_empty());
+	}
+
+	#[test]
+	fn test_moved_holidays() {
+		let holidays = HolidayProvider::get_holidays(2020, CountryCode::GB);
+		let early_may: Vec<String> = holidays
+			.iter()
+			.filter(|holiday| holiday.name == "Early May Bank Holiday")
+			.map(|holiday| holiday.date.to_string())
+			.collect();
+		assert_eq!(early_may, vec!["2020-05-08"]);
+	}
+
+	#[test]
+	fn test_add_and_remove() {
+		let date = NaiveDate::from_ymd_opt(2033, 3, 1).unwrap();
+		let mut proclamation = ProclaimedHoliday::new(date, "Test", "Test Day", CountryCode::DE, "Test");
+		proclamation
+			.set_counties(vec!["DE-BE".to_string()])
+			.set_holiday_type(PublicHolidayType::Bank);
+		ProclamationRegistry::add(proclamation.clone());
+		ProclamationRegistry::add(proclamation);
+		assert_eq!(ProclamationRegistry::get(2033, CountryCode::DE).len(), 1);
+
+		let holidays = HolidayProvider::get_holidays(2033, CountryCode::DE);
+		let holiday = holidays.iter().find(|holiday| holiday.date == date).unwrap();
+		assert!(holiday.is_in_county("DE-BE"));
+		assert!(!holiday.is_in_county("DE-BY"));
+		assert_eq!(holiday.holiday_type, PublicHolidayType::Bank);
+
+		assert!(ProclamationRegistry::remove(CountryCode::DE, date));
+		assert!(!ProclamationRegistry::remove(CountryCode::DE, date));
+		assert!(ProclamationRegistry::get(2033, CountryCode::DE).is_empty());
+	}
+
+	#[test]
+	fn test_load() {
+		let json = r#"[{
+			"date": "2034-07-30",
+			"local_name": "Sommerfest",
+			"name": "Summer Festival",
+			"country_code": "AT",
+			"holiday_type": "Observance",
+			"replaces": null,
+			"source": "Test"
+		}]"#;
+		assert_eq!(ProclamationRegistry::load(json).unwrap(), 1);
+		let result = ProclamationRegistry::get(2034, CountryCode::AT);
+		assert_eq!(result[0].holiday_type, PublicHolidayType::Observance);
+		assert_eq!(result[0].counties, None);
+
+		assert!(ProclamationRegistry::load(r#"[{"date": "2034-07-31"}]"#).is_err());
+		assert_eq!(ProclamationRegistry::get(2034, CountryCode::AT).len(), 1);
+	}
+}
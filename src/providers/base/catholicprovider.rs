@@ -1,33 +1,21 @@
 pub use chrono::*;
 
-use crate::types::{countrycode::CountryCode, publicholiday::PublicHoliday, publicholidaytype::PublicHolidayType};
+use crate::types::{
+	computus::Computus, countrycode::CountryCode, moveablefeast::MoveableFeast, publicholiday::PublicHoliday,
+};
 
 pub struct CatholicProvider {}
 
 impl CatholicProvider {
-	fn get_easter_sunday(year: i32) -> DateTime<Utc> {
-		//should be
-		//Easter Monday  28 Mar 2005  17 Apr 2006  9 Apr 2007  24 Mar 2008
+	const COMPUTUS: Computus = Computus::Gregorian;
 
-		//Oudin's Algorithm - http://www.smart.net/~mmontes/oudin.html
-		let g = year % 19;
-		let c = year / 100;
-		let h = (c - c / 4 - (8 * c + 13) / 25 + 19 * g + 15) % 30;
-		let i = h - (h / 28) * (1 - (h / 28) * (29 / (h + 1)) * ((21 - g) / 11));
-
-		let mut day: u32 = (i - ((year + (year / 4) + i + 2 - c + (c / 4)) % 7) + 28) as u32;
-		let mut month = 3;
-
-		if day > 31 {
-			month += 1;
-			day -= 31;
-		}
-
-		Utc.with_ymd_and_hms(year, month, day, 0, 0, 0).unwrap()
+	#[allow(dead_code)]
+	pub(crate) fn get_easter_sunday(year: i32) -> NaiveDate {
+		Self::COMPUTUS.easter_sunday(year)
 	}
 
-	pub(crate) fn advent_sunday(year: i32) -> DateTime<Utc> {
-		let christmas_date = Utc.with_ymd_and_hms(year, 12, 24, 0, 0, 0).unwrap();
+	pub(crate) fn advent_sunday(year: i32) -> NaiveDate {
+		let christmas_date = NaiveDate::from_ymd_opt(year, 12, 24).unwrap();
 		let days_to_advent: i64 = (21 + christmas_date.weekday().num_days_from_sunday()).into();
 
 		christmas_date - Duration::days(days_to_advent)
@@ -35,99 +23,35 @@ impl CatholicProvider {
 
 	#[allow(dead_code)]
 	pub(crate) fn maundy_thursday(local_name: &str, year: i32, country_code: CountryCode) -> PublicHoliday {
-		PublicHoliday::new_non_fixed(
-			Self::get_easter_sunday(year) - Duration::days(3),
-			local_name,
-			"Maundy Thursday",
-			country_code,
-			None,
-			None,
-			PublicHolidayType::Public,
-		)
+		MoveableFeast::MaundyThursday.holiday(Self::COMPUTUS, local_name, year, country_code)
 	}
 
 	pub(crate) fn good_friday(local_name: &str, year: i32, country_code: CountryCode) -> PublicHoliday {
-		PublicHoliday::new_non_fixed(
-			Self::get_easter_sunday(year) - Duration::days(2),
-			local_name,
-			"Good Friday",
-			country_code,
-			None,
-			None,
-			PublicHolidayType::Public,
-		)
+		MoveableFeast::GoodFriday.holiday(Self::COMPUTUS, local_name, year, country_code)
 	}
 
 	pub(crate) fn easter_sunday(local_name: &str, year: i32, country_code: CountryCode) -> PublicHoliday {
-		PublicHoliday::new_non_fixed(
-			Self::get_easter_sunday(year),
-			local_name,
-			"Easter Sunday",
-			country_code,
-			None,
-			None,
-			PublicHolidayType::Public,
-		)
-	}
-
-	pub(crate) fn easter_monday(localname: &str, year: i32, countrycode: CountryCode) -> PublicHoliday {
-		PublicHoliday::new_non_fixed(
-			Self::get_easter_sunday(year) + Duration::days(1),
-			localname,
-			"Easter Monday",
-			countrycode,
-			None,
-			None,
-			PublicHolidayType::Public,
-		)
-	}
-
-	pub(crate) fn ascension_day(localname: &str, year: i32, countrycode: CountryCode) -> PublicHoliday {
-		PublicHoliday::new_non_fixed(
-			Self::get_easter_sunday(year) + Duration::days(39),
-			localname,
-			"Ascension Day",
-			countrycode,
-			None,
-			None,
-			PublicHolidayType::Public,
-		)
-	}
-
-	pub(crate) fn pentecost(localname: &str, year: i32, countrycode: CountryCode) -> PublicHoliday {
-		PublicHoliday::new_non_fixed(
-			Self::get_easter_sunday(year) + Duration::days(49),
-			localname,
-			"Pentecost",
-			countrycode,
-			None,
-			None,
-			PublicHolidayType::Public,
-		)
-	}
-
-	pub(crate) fn whit_monday(localname: &str, year: i32, countrycode: CountryCode) -> PublicHoliday {
-		PublicHoliday::new_non_fixed(
-			Self::get_easter_sunday(year) + Duration::days(50),
-			localname,
-			"Whit Monday",
-			countrycode,
-			None,
-			None,
-			PublicHolidayType::Public,
-		)
-	}
-
-	pub(crate) fn corpus_christi(localname: &str, year: i32, countrycode: CountryCode) -> PublicHoliday {
-		PublicHoliday::new_non_fixed(
-			Self::get_easter_sunday(year) + Duration::days(60),
-			localname,
-			"Corpus Christi",
-			countrycode,
-			None,
-			None,
-			PublicHolidayType::Public,
-		)
+		MoveableFeast::EasterSunday.holiday(Self::COMPUTUS, local_name, year, country_code)
+	}
+
+	pub(crate) fn easter_monday(local_name: &str, year: i32, country_code: CountryCode) -> PublicHoliday {
+		MoveableFeast::EasterMonday.holiday(Self::COMPUTUS, local_name, year, country_code)
+	}
+
+	pub(crate) fn ascension_day(local_name: &str, year: i32, country_code: CountryCode) -> PublicHoliday {
+		MoveableFeast::AscensionDay.holiday(Self::COMPUTUS, local_name, year, country_code)
+	}
+
+	pub(crate) fn pentecost(local_name: &str, year: i32, country_code: CountryCode) -> PublicHoliday {
+		MoveableFeast::Pentecost.holiday(Self::COMPUTUS, local_name, year, country_code)
+	}
+
+	pub(crate) fn whit_monday(local_name: &str, year: i32, country_code: CountryCode) -> PublicHoliday {
+		MoveableFeast::WhitMonday.holiday(Self::COMPUTUS, local_name, year, country_code)
+	}
+
+	pub(crate) fn corpus_christi(local_name: &str, year: i32, country_code: CountryCode) -> PublicHoliday {
+		MoveableFeast::CorpusChristi.holiday(Self::COMPUTUS, local_name, year, country_code)
 	}
 }
 
@@ -156,48 +80,48 @@ mod tests {
 	#[test]
 	fn test_maundy_thursday() {
 		let result = CatholicProvider::maundy_thursday("test", 2022, CountryCode::DE);
-		assert_eq!(result.to_string(), "2022-04-14T00:00:00Z Maundy Thursday");
+		assert_eq!(result.to_string(), "2022-04-14 Maundy Thursday");
 	}
 
 	#[test]
 	fn test_good_friday() {
 		let result = CatholicProvider::good_friday("test", 2022, CountryCode::DE);
-		assert_eq!(result.to_string(), "2022-04-15T00:00:00Z Good Friday");
+		assert_eq!(result.to_string(), "2022-04-15 Good Friday");
 	}
 
 	#[test]
 	fn test_easter_sunday() {
 		let result = CatholicProvider::easter_sunday("test", 2022, CountryCode::DE);
-		assert_eq!(result.to_string(), "2022-04-17T00:00:00Z Easter Sunday");
+		assert_eq!(result.to_string(), "2022-04-17 Easter Sunday");
 	}
 
 	#[test]
 	fn test_easter_monday() {
 		let result = CatholicProvider::easter_monday("test", 2022, CountryCode::DE);
-		assert_eq!(result.to_string(), "2022-04-18T00:00:00Z Easter Monday");
+		assert_eq!(result.to_string(), "2022-04-18 Easter Monday");
 	}
 
 	#[test]
 	fn test_ascension_day() {
 		let result = CatholicProvider::ascension_day("test", 2022, CountryCode::DE);
-		assert_eq!(result.to_string(), "2022-05-26T00:00:00Z Ascension Day");
+		assert_eq!(result.to_string(), "2022-05-26 Ascension Day");
 	}
 
 	#[test]
 	fn test_pentecost() {
 		let result = CatholicProvider::pentecost("test", 2022, CountryCode::DE);
-		assert_eq!(result.to_string(), "2022-06-05T00:00:00Z Pentecost");
+		assert_eq!(result.to_string(), "2022-06-05 Pentecost");
 	}
 
 	#[test]
 	fn test_whit_monday() {
 		let result = CatholicProvider::whit_monday("test", 2022, CountryCode::DE);
-		assert_eq!(result.to_string(), "2022-06-06T00:00:00Z Whit Monday");
+		assert_eq!(result.to_string(), "2022-06-06 Whit Monday");
 	}
 
 	#[test]
 	fn test_corpus_christi() {
 		let result = CatholicProvider::corpus_christi("test", 2022, CountryCode::DE);
-		assert_eq!(result.to_string(), "2022-06-16T00:00:00Z Corpus Christi");
+		assert_eq!(result.to_string(), "2022-06-16 Corpus Christi");
 	}
 }
@@ -0,0 +1,35 @@
+use crate::{
+	providers::locations::unitedkingdomholidayprovider::UnitedKingdomHolidayProvider,
+	types::{
+		countrycode::CountryCode, holidayid::HolidayId, publicholiday::PublicHoliday,
+		publicholidaytype::PublicHolidayType,
+	},
+};
+
+pub struct ChannelIslandsProvider {}
+
+impl ChannelIslandsProvider {
+	/// Gets the bank holidays of the channel islands, which are the bank holidays of england and the liberation day
+	/// from the german occupation on the 9th of may
+	pub(crate) fn get_holidays(id: HolidayId, year: i32, country_code: CountryCode) -> Vec<PublicHoliday> {
+		let mut holidays = UnitedKingdomHolidayProvider::get_england_holidays(year, country_code);
+		if year >= 1946 {
+			holidays.push(PublicHoliday::new_fixed(
+				id,
+				year,
+				5,
+				9,
+				"Liberation Day",
+				"Liberation Day",
+				country_code,
+				Some(1946),
+				None,
+				PublicHolidayType::Public,
+			));
+		}
+
+		holidays.sort_by_key(|a| a.date);
+
+		holidays
+	}
+}
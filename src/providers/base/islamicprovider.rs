@@ -0,0 +1,98 @@
+use crate::{
+	calendars::hijri::HijriCalendar,
+	types::{countrycode::CountryCode, hijridate::HijriDate, holidayid::HolidayId, publicholiday::PublicHoliday},
+};
+
+/// Holidays of the islamic (hijri) calendar, which are estimated because they depend on the sighting of the moon
+pub struct IslamicProvider {}
+
+impl IslamicProvider {
+	#[allow(dead_code)]
+	pub(crate) fn islamic_new_year(
+		calendar: HijriCalendar,
+		local_name: &str,
+		year: i32,
+		country_code: CountryCode,
+	) -> Vec<PublicHoliday> {
+		HijriDate::new(calendar, HijriCalendar::MUHARRAM, 1).holidays(
+			HolidayId::ISLAMIC_NEW_YEAR,
+			local_name,
+			"Islamic New Year",
+			year,
+			country_code,
+		)
+	}
+
+	#[allow(dead_code)]
+	pub(crate) fn mawlid(
+		calendar: HijriCalendar,
+		local_name: &str,
+		year: i32,
+		country_code: CountryCode,
+	) -> Vec<PublicHoliday> {
+		HijriDate::new(calendar, HijriCalendar::RABI_AL_AWWAL, 12).holidays(
+			HolidayId::PROPHETS_BIRTHDAY,
+			local_name,
+			"Prophet's Birthday",
+			year,
+			country_code,
+		)
+	}
+
+	/// Eid al-Fitr at the end of Ramadan, lasting the given number of days
+	pub(crate) fn eid_al_fitr(
+		calendar: HijriCalendar,
+		local_name: &str,
+		days: u32,
+		year: i32,
+		country_code: CountryCode,
+	) -> Vec<PublicHoliday> {
+		(1..=days)
+			.flat_map(|day| {
+				HijriDate::new(calendar, HijriCalendar::SHAWWAL, day).holidays(
+					HolidayId::EID_AL_FITR,
+					local_name,
+					"Eid al-Fitr",
+					year,
+					country_code,
+				)
+			})
+			.collect()
+	}
+
+	pub(crate) fn arafat_day(
+		calendar: HijriCalendar,
+		local_name: &str,
+		year: i32,
+		country_code: CountryCode,
+	) -> Vec<PublicHoliday> {
+		HijriDate::new(calendar, HijriCalendar::DHU_AL_HIJJAH, 9).holidays(
+			HolidayId::ARAFAT_DAY,
+			local_name,
+			"Arafat Day",
+			year,
+			country_code,
+		)
+	}
+
+	/// Eid al-Adha on the 10th Dhu al-Hijjah, lasting the given number of days
+	pub(crate) fn eid_al_adha(
+		calendar: HijriCalendar,
+		local_name: &str,
+		days: u32,
+		year: i32,
+		country_code: CountryCode,
+	) -> Vec<PublicHoliday> {
+		(10..10 + days)
+			.flat_map(|day| {
+				HijriDate::new(calendar, HijriCalendar::DHU_AL_HIJJAH, day).holidays(
+					HolidayId::EID_AL_ADHA,
+					local_name,
+					"Eid al-Adha",
+					year,
+					country_code,
+				)
+			})
+			.collect()
+	}
+}
@@ -0,0 +1,65 @@
+use chrono::{Duration, NaiveDate};
+
+use crate::{
+	calendars::lunisolar::LunisolarCalendar,
+	types::{
+		countrycode::CountryCode, holidayid::HolidayId, publicholiday::PublicHoliday,
+		publicholidaytype::PublicHolidayType,
+	},
+};
+
+/// Holidays of the chinese lunisolar calendar and its variants
+pub struct LunisolarProvider {}
+
+impl LunisolarProvider {
+	/// Holiday on the given month and day of the lunisolar year which starts in the given gregorian year, shifted by
+	/// the given number of days. If the year isn't supported by the calendar, None will be returned.
+	#[allow(clippy::too_many_arguments)]
+	pub(crate) fn lunar_holiday(
+		id: HolidayId,
+		calendar: LunisolarCalendar,
+		month: u32,
+		day: u32,
+		offset_days: i64,
+		local_name: &str,
+		name: &str,
+		year: i32,
+		country_code: CountryCode,
+	) -> Option<PublicHoliday> {
+		let date = calendar.to_gregorian(year, month, false, day)? + Duration::days(offset_days);
+
+		Some(Self::holiday(id, date, local_name, name, country_code))
+	}
+
+	/// Holiday on the day of the given solar term (longitude of the sun in degrees) in the time zone of the calendar
+	pub(crate) fn solar_term_holiday(
+		id: HolidayId,
+		calendar: LunisolarCalendar,
+		longitude: f64,
+		local_name: &str,
+		name: &str,
+		year: i32,
+		country_code: CountryCode,
+	) -> PublicHoliday {
+		Self::holiday(id, calendar.solar_term(year, longitude), local_name, name, country_code)
+	}
+
+	fn holiday(
+		id: HolidayId,
+		date: NaiveDate,
+		local_name: &str,
+		name: &str,
+		country_code: CountryCode,
+	) -> PublicHoliday {
+		PublicHoliday::new_non_fixed(
+			id,
+			date,
+			local_name,
+			name,
+			country_code,
+			None,
+			None,
+			PublicHolidayType::Public,
+		)
+	}
+}
@@ -1,2 +1,5 @@
 pub(crate) mod catholicprovider;
+pub(crate) mod channelislandsprovider;
+pub(crate) mod islamicprovider;
+pub(crate) mod lunisolarprovider;
 pub(crate) mod orthodoxprovider;
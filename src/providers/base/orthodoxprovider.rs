@@ -1,124 +1,67 @@
 use chrono::*;
 
-use crate::types::{countrycode::CountryCode, publicholiday::PublicHoliday, publicholidaytype::PublicHolidayType};
+use crate::{
+	calendars::CalendarSystem,
+	types::{
+		computus::Computus, countrycode::CountryCode, fixeddate::FixedDate, holidayid::HolidayId,
+		moveablefeast::MoveableFeast, publicholiday::PublicHoliday,
+	},
+};
 
 pub struct OrthodoxProvider {}
 
 impl OrthodoxProvider {
-	pub(crate) fn get_orthodox_easter(year: i32) -> DateTime<Utc> {
-		// credits https://gist.github.com/georgekosmidis/7f2cbabbd57ef879e95d990f0c356106#file-getorthodoxeaster-cs
-		let a: i32 = year % 19;
-		let b: i32 = year % 7;
-		let c: i32 = year % 4;
+	const COMPUTUS: Computus = Computus::Julian;
 
-		let d: i32 = (19 * a + 16) % 30;
-		let e: i32 = (2 * c + 4 * b + 6 * d) % 7;
-		let f: i32 = (19 * a + 16) % 30;
-
-		let key: i32 = f + e + 3;
-		let month: i32 = if key > 30 { 5 } else { 4 };
-		let day: i32 = if key > 30 { key - 30 } else { key };
+	#[allow(dead_code)]
+	pub(crate) fn get_orthodox_easter(year: i32) -> NaiveDate {
+		Self::COMPUTUS.easter_sunday(year)
+	}
 
-		Utc.with_ymd_and_hms(year, month as u32, day as u32, 0, 0, 0).unwrap()
+	/// Christmas on the 25th of december of the julian calendar, which is the 7th of january from 1901 until 2100
+	pub(crate) fn christmas_day(
+		id: HolidayId,
+		local_name: &str,
+		name: &str,
+		year: i32,
+		country_code: CountryCode,
+	) -> Option<PublicHoliday> {
+		FixedDate::new(CalendarSystem::Julian, 12, 25).holiday(id, local_name, name, year, country_code)
 	}
 
 	#[allow(dead_code)]
-	pub(crate) fn maundy_thursday(localname: &str, year: i32, countrycode: CountryCode) -> PublicHoliday {
-		PublicHoliday::new_non_fixed(
-			Self::get_orthodox_easter(year) - Duration::days(3),
-			localname,
-			"Maundy Thursday",
-			countrycode,
-			None,
-			None,
-			PublicHolidayType::Public,
-		)
-	}
-
-	pub(crate) fn good_friday(localname: &str, year: i32, countrycode: CountryCode) -> PublicHoliday {
-		PublicHoliday::new_non_fixed(
-			Self::get_orthodox_easter(year) - Duration::days(2),
-			localname,
-			"Good Friday",
-			countrycode,
-			None,
-			None,
-			PublicHolidayType::Public,
-		)
-	}
-
-	pub(crate) fn easter_sunday(localname: &str, year: i32, countrycode: CountryCode) -> PublicHoliday {
-		PublicHoliday::new_non_fixed(
-			Self::get_orthodox_easter(year),
-			localname,
-			"Easter Sunday",
-			countrycode,
-			None,
-			None,
-			PublicHolidayType::Public,
-		)
-	}
-
-	pub(crate) fn easter_monday(localname: &str, year: i32, countrycode: CountryCode) -> PublicHoliday {
-		PublicHoliday::new_non_fixed(
-			Self::get_orthodox_easter(year) + Duration::days(1),
-			localname,
-			"Easter Monday",
-			countrycode,
-			None,
-			None,
-			PublicHolidayType::Public,
-		)
+	pub(crate) fn maundy_thursday(local_name: &str, year: i32, country_code: CountryCode) -> PublicHoliday {
+		MoveableFeast::MaundyThursday.holiday(Self::COMPUTUS, local_name, year, country_code)
+	}
+
+	pub(crate) fn good_friday(local_name: &str, year: i32, country_code: CountryCode) -> PublicHoliday {
+		MoveableFeast::GoodFriday.holiday(Self::COMPUTUS, local_name, year, country_code)
+	}
+
+	pub(crate) fn easter_sunday(local_name: &str, year: i32, country_code: CountryCode) -> PublicHoliday {
+		MoveableFeast::EasterSunday.holiday(Self::COMPUTUS, local_name, year, country_code)
+	}
+
+	pub(crate) fn easter_monday(local_name: &str, year: i32, country_code: CountryCode) -> PublicHoliday {
+		MoveableFeast::EasterMonday.holiday(Self::COMPUTUS, local_name, year, country_code)
 	}
 
 	#[allow(dead_code)]
-	pub(crate) fn ascension_day(localname: &str, year: i32, countrycode: CountryCode) -> PublicHoliday {
-		PublicHoliday::new_non_fixed(
-			Self::get_orthodox_easter(year) + Duration::days(39),
-			localname,
-			"Ascension Day",
-			countrycode,
-			None,
-			None,
-			PublicHolidayType::Public,
-		)
-	}
-
-	pub(crate) fn pentecost(localname: &str, year: i32, countrycode: CountryCode) -> PublicHoliday {
-		PublicHoliday::new_non_fixed(
-			Self::get_orthodox_easter(year) + Duration::days(49),
-			localname,
-			"Pentecost",
-			countrycode,
-			None,
-			None,
-			PublicHolidayType::Public,
-		)
-	}
-
-	pub(crate) fn whit_monday(localname: &str, year: i32, countrycode: CountryCode) -> PublicHoliday {
-		PublicHoliday::new_non_fixed(
-			Self::get_orthodox_easter(year) + Duration::days(50),
-			localname,
-			"Whit Monday",
-			countrycode,
-			None,
-			None,
-			PublicHolidayType::Public,
-		)
+	pub(crate) fn ascension_day(local_name: &str, year: i32, country_code: CountryCode) -> PublicHoliday {
+		MoveableFeast::AscensionDay.holiday(Self::COMPUTUS, local_name, year, country_code)
+	}
+
+	pub(crate) fn pentecost(local_name: &str, year: i32, country_code: CountryCode) -> PublicHoliday {
+		MoveableFeast::Pentecost.holiday(Self::COMPUTUS, local_name, year, country_code)
+	}
+
+	pub(crate) fn whit_monday(local_name: &str, year: i32, country_code: CountryCode) -> PublicHoliday {
+		MoveableFeast::WhitMonday.holiday(Self::COMPUTUS, local_name, year, country_code)
 	}
 
 	#[allow(dead_code)]
-	pub(crate) fn corpus_christi(localname: &str, year: i32, countrycode: CountryCode) -> PublicHoliday {
-		PublicHoliday::new_non_fixed(
-			Self::get_orthodox_easter(year) + Duration::days(60),
-			localname,
-			"Corpus Christi",
-			countrycode,
-			None,
-			None,
-			PublicHolidayType::Public,
-		)
+	pub(crate) fn corpus_christi(local_name: &str, year: i32, country_code: CountryCode) -> PublicHoliday {
+		MoveableFeast::CorpusChristi.holiday(Self::COMPUTUS, local_name, year, country_code)
 	}
 }
 
@@ -126,7 +69,7 @@ impl OrthodoxProvider {
 mod tests {
 	use chrono::Datelike;
 
-	use crate::types::countrycode::CountryCode;
+	use crate::types::{countrycode::CountryCode, holidayid::HolidayId};
 
 	use super::OrthodoxProvider;
 
@@ -137,51 +80,61 @@ mod tests {
 		assert_eq!(result.month(), 4);
 	}
 
+	#[test]
+	fn test_christmas_day() {
+		let result =
+			OrthodoxProvider::christmas_day(HolidayId::CHRISTMAS_DAY, "test", "Christmas Day", 2022, CountryCode::RU);
+		assert_eq!(result.unwrap().to_string(), "2022-01-07 Christmas Day");
+		let result =
+			OrthodoxProvider::christmas_day(HolidayId::CHRISTMAS_DAY, "test", "Christmas Day", 2101, CountryCode::RU);
+		assert_eq!(result.unwrap().to_string(), "2101-01-08 Christmas Day");
+	}
+
 	#[test]
 	fn test_maundy_thursday() {
 		let result = OrthodoxProvider::maundy_thursday("test", 2022, CountryCode::DE);
-		assert_eq!(result.to_string(), "2022-04-21T00:00:00Z Maundy Thursday");
+		assert_eq!(result.to_string(), "2022-04-21 Maundy Thursday");
 	}
 
 	#[test]
 	fn test_good_friday() {
 		let result = OrthodoxProvider::good_friday("test", 2022, CountryCode::DE);
-		assert_eq!(result.to_string(), "2022-04-22T00:00:00Z Good Friday");
+		assert_eq!(result.to_string(), "2022-04-22 Good Friday");
 	}
 
 	#[test]
 	fn test_easter_sunday() {
 		let result = OrthodoxProvider::easter_sunday("test", 2022, CountryCode::DE);
-		assert_eq!(result.to_string(), "2022-04-24T00:00:00Z Easter Sunday");
+		assert_eq!(result.to_string(), "2022-04-24 Easter Sunday");
 	}
 
 	#[test]
 	fn test_easter_monday() {
 		let result = OrthodoxProvider::easter_monday("test", 2022, CountryCode::DE);
-		assert_eq!(result.to_string(), "2022-04-25T00:00:00Z Easter Monday");
+		assert_eq!(result.to_string(), "2022-04-25 Easter Monday");
 	}
 
 	#[test]
 	fn test_ascension_day() {
 		let result = OrthodoxProvider::ascension_day("test", 2022, CountryCode::DE);
-		assert_eq!(result.to_string(), "2022-06-02T00:00:00Z Ascension Day");
+		assert_eq!(result.to_string(), "2022-06-02 Ascension Day");
 	}
 
 	#[test]
 	fn test_pentecost() {
 		let result = OrthodoxProvider::pentecost("test", 2022, CountryCode::DE);
-		assert_eq!(result.to_string(), "2022-06-12T00:00:00Z Pentecost");
+		assert_eq!(result.to_string(), "2022-06-12 Pentecost");
 	}
 
 	#[test]
 	fn test_whit_monday() {
 		let result = OrthodoxProvider::whit_monday("test", 2022, CountryCode::DE);
-		assert_eq!(result.to_string(), "2022-06-13T00:00:00Z Whit Monday");
+		assert_eq!(result.to_string(), "2022-06-13 Whit Monday");
 	}
 
 	#[test]
 	fn test_corpus_christi() {
 		let result = OrthodoxProvider::corpus_christi("test", 2022, CountryCode::DE);
-		assert_eq!(result.to_string(), "2022-06-23T00:00:00Z Corpus Christi");
+		assert_eq!(result.to_string(), "2022-06-23 Corpus Christi");
 	}
 }
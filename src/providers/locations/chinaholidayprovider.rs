@@ -0,0 +1,323 @@
+use chrono::NaiveDate;
+
+use crate::{
+	calendars::lunisolar::LunisolarCalendar,
+	providers::base::lunisolarprovider::LunisolarProvider,
+	types::{
+		countrycode::CountryCode, holidayid::HolidayId, publicholiday::PublicHoliday,
+		publicholidaytype::PublicHolidayType, workingdayoverride::WorkingDayOverride,
+	},
+};
+
+pub struct ChinaHolidayProvider {}
+
+impl ChinaHolidayProvider {
+	const COUNTRYCODE: CountryCode = CountryCode::CN;
+	const CALENDAR: LunisolarCalendar = LunisolarCalendar::China;
+
+	pub fn get_holidays(year: i32) -> Vec<PublicHoliday> {
+		let mut holidays = vec![PublicHoliday::new_fixed(
+			HolidayId::NEW_YEARS_DAY,
+			year,
+			1,
+			1,
+			"元旦",
+			"New Year's Day",
+			Self::COUNTRYCODE,
+			None,
+			None,
+			PublicHolidayType::Public,
+		)];
+
+		holidays.extend(Self::get_spring_festival(year));
+
+		if year >= 2008 {
+			holidays.push(LunisolarProvider::solar_term_holiday(
+				HolidayId::QINGMING_FESTIVAL,
+				Self::CALENDAR,
+				15.0,
+				"清明节",
+				"Qingming Festival",
+				year,
+				Self::COUNTRYCODE,
+			));
+			holidays.extend(LunisolarProvider::lunar_holiday(
+				HolidayId::DRAGON_BOAT_FESTIVAL,
+				Self::CALENDAR,
+				5,
+				5,
+				0,
+				"端午节",
+				"Dragon Boat Festival",
+				year,
+				Self::COUNTRYCODE,
+			));
+			holidays.extend(LunisolarProvider::lunar_holiday(
+				HolidayId::MID_AUTUMN_FESTIVAL,
+				Self::CALENDAR,
+				8,
+				15,
+				0,
+				"中秋节",
+				"Mid-Autumn Festival",
+				year,
+				Self::COUNTRYCODE,
+			));
+		}
+
+		// the labour day holiday lasted three days until 2007 and two days since 2025
+		let labour_days = if (2000..2008).contains(&year) {
+			3
+		} else if year >= 2025 {
+			2
+		} else {
+			1
+		};
+		for day in 1..=labour_days {
+			holidays.push(PublicHoliday::new_fixed(
+				HolidayId::LABOUR_DAY,
+				year,
+				5,
+				day,
+				"劳动节",
+				"Labour Day",
+				Self::COUNTRYCODE,
+				None,
+				None,
+				PublicHolidayType::Public,
+			));
+		}
+
+		for day in 1..=3 {
+			holidays.push(PublicHoliday::new_fixed(
+				HolidayId::CN_NATIONAL_DAY,
+				year,
+				10,
+				day,
+				"国庆节",
+				"National Day",
+				Self::COUNTRYCODE,
+				None,
+				None,
+				PublicHolidayType::Public,
+			));
+		}
+
+		holidays.extend(Self::get_transferred_days_off(year));
+
+		holidays.sort_by_key(|a| a.date);
+
+		holidays
+	}
+
+	/// Gets the weekdays around the holidays which are days off by the notice of the state council, because a holiday
+	/// falls on a weekend or a weekend day is declared as working day. The notices are published every year and are
+	/// only known for 2020 to 2025.
+	fn get_transferred_days_off(year: i32) -> Vec<PublicHoliday> {
+		let days: &[(u32, u32)] = match year {
+			2020 => &[
+				(1, 24),
+				(1, 28),
+				(1, 29),
+				(1, 30),
+				(1, 31),
+				(4, 6),
+				(5, 4),
+				(5, 5),
+				(6, 26),
+				(10, 5),
+				(10, 6),
+				(10, 7),
+				(10, 8),
+			],
+			2021 => &[
+				(2, 11),
+				(2, 15),
+				(2, 16),
+				(2, 17),
+				(4, 5),
+				(5, 3),
+				(5, 4),
+				(5, 5),
+				(9, 20),
+				(10, 4),
+				(10, 5),
+				(10, 6),
+				(10, 7),
+			],
+			2022 => &[
+				(1, 3),
+				(1, 31),
+				(2, 4),
+				(4, 4),
+				(5, 2),
+				(5, 3),
+				(5, 4),
+				(9, 12),
+				(10, 4),
+				(10, 5),
+				(10, 6),
+				(10, 7),
+			],
+			2023 => &[
+				(1, 2),
+				(1, 25),
+				(1, 26),
+				(1, 27),
+				(5, 2),
+				(5, 3),
+				(6, 23),
+				(10, 4),
+				(10, 5),
+				(10, 6),
+			],
+			2024 => &[
+				(2, 13),
+				(2, 14),
+				(2, 15),
+				(2, 16),
+				(4, 5),
+				(5, 2),
+				(5, 3),
+				(9, 16),
+				(10, 4),
+				(10, 7),
+			],
+			2025 => &[(2, 3), (2, 4), (5, 5), (6, 2), (10, 7), (10, 8)],
+			_ => &[],
+		};
+
+		days.iter()
+			.map(|(month, day)| {
+				PublicHoliday::new_non_fixed(
+					HolidayId::TRANSFERRED_DAY_OFF,
+					NaiveDate::from_ymd_opt(year, *month, *day).unwrap(),
+					"调休",
+					"Transferred day off",
+					Self::COUNTRYCODE,
+					None,
+					None,
+					PublicHolidayType::Public,
+				)
+			})
+			.collect()
+	}
+
+	/// Gets the weekend days which are working days by the notice of the state council to make up for the days off
+	/// around the holidays. The notices don't pair the working days with the days off, so the days off are holidays
+	/// and the working days aren't transferred. The notices are only known for 2020 to 2025, so there are no overrides
+	/// for the other years.
+	pub fn get_working_day_overrides(year: i32) -> Vec<WorkingDayOverride> {
+		let days: &[(u32, u32)] = match year {
+			2020 => &[(1, 19), (4, 26), (5, 9), (6, 28), (9, 27), (10, 10)],
+			2021 => &[(2, 7), (2, 20), (4, 25), (5, 8), (9, 18), (9, 26), (10, 9)],
+			2022 => &[(1, 29), (1, 30), (4, 2), (4, 24), (5, 7), (10, 8), (10, 9)],
+			2023 => &[(1, 28), (1, 29), (4, 23), (5, 6), (6, 25), (10, 7), (10, 8)],
+			2024 => &[(2, 4), (2, 18), (4, 7), (4, 28), (5, 11), (9, 14), (9, 29), (10, 12)],
+			2025 => &[(1, 26), (2, 8), (4, 27), (9, 28), (10, 11)],
+			_ => &[],
+		};
+
+		days.iter()
+			.map(|(month, day)| {
+				WorkingDayOverride::new(
+					NaiveDate::from_ymd_opt(year, *month, *day).unwrap(),
+					"补班",
+					"Working day",
+					Self::COUNTRYCODE,
+					None,
+				)
+			})
+			.collect()
+	}
+
+	/// The spring festival lasts three days, which included the new year's eve from 2008 until 2013. Since 2025 the
+	/// new year's eve is an additional holiday.
+	fn get_spring_festival(year: i32) -> Vec<PublicHoliday> {
+		let with_eve = (2008..2014).contains(&year) || year >= 2025;
+		let first_day = if with_eve { -1 } else { 0 };
+		let last_day = if (2008..2014).contains(&year) { 1 } else { 2 };
+
+		(first_day..=last_day)
+			.filter_map(|offset_days| {
+				let (id, local_name, name) = if offset_days < 0 {
+					(HolidayId::LUNAR_NEW_YEARS_EVE, "除夕", "Spring Festival Eve")
+				} else {
+					(HolidayId::LUNAR_NEW_YEAR, "春节", "Spring Festival")
+				};
+				LunisolarProvider::lunar_holiday(
+					id,
+					Self::CALENDAR,
+					1,
+					1,
+					offset_days,
+					local_name,
+					name,
+					year,
+					Self::COUNTRYCODE,
+				)
+			})
+			.collect()
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use chrono::{Datelike, NaiveDate, Weekday};
+
+	use super::ChinaHolidayProvider;
+	use crate::{holidayprovider::HolidayProvider, types::countrycode::CountryCode};
+
+	#[test]
+	fn test_get_holidays() {
+		let result = ChinaHolidayProvider::get_holidays(2024);
+		assert_eq!(result.len(), 21);
+		assert_eq!(result[1].to_string(), "2024-02-10 Spring Festival");
+		assert_eq!(result[4].to_string(), "2024-02-13 Transferred day off");
+		assert_eq!(result[8].to_string(), "2024-04-04 Qingming Festival");
+		assert_eq!(result[13].to_string(), "2024-06-10 Dragon Boat Festival");
+		assert_eq!(result[15].to_string(), "2024-09-17 Mid-Autumn Festival");
+	}
+
+	#[test]
+	fn test_get_holidays_since_2025() {
+		let result = ChinaHolidayProvider::get_holidays(2025);
+		assert_eq!(result.len(), 19);
+		assert_eq!(result[1].to_string(), "2025-01-28 Spring Festival Eve");
+		assert_eq!(result[9].to_string(), "2025-05-02 Labour Day");
+	}
+
+	#[test]
+	fn test_transferred_days_off_and_working_days() {
+		for year in 2020..=2025 {
+			let holidays = ChinaHolidayProvider::get_holidays(year);
+			for day_off in holidays.iter().filter(|holiday| holiday.name == "Transferred day off") {
+				assert!(
+					!matches!(day_off.date.weekday(), Weekday::Sat | Weekday::Sun),
+					"{day_off}"
+				);
+				assert_eq!(
+					holidays.iter().filter(|holiday| holiday.date == day_off.date).count(),
+					1,
+					"{day_off}"
+				);
+			}
+			for working_day in ChinaHolidayProvider::get_working_day_overrides(year) {
+				assert!(
+					matches!(working_day.date.weekday(), Weekday::Sat | Weekday::Sun),
+					"{working_day}"
+				);
+			}
+		}
+	}
+
+	#[test]
+	fn test_is_business_day() {
+		let date = |month, day| NaiveDate::from_ymd_opt(2024, month, day).unwrap();
+		assert!(HolidayProvider::is_business_day(date(2, 4), CountryCode::CN, None));
+		assert!(!HolidayProvider::is_business_day(date(2, 14), CountryCode::CN, None));
+		assert!(!HolidayProvider::is_business_day(date(2, 16), CountryCode::CN, None));
+		assert!(!HolidayProvider::is_business_day(date(2, 17), CountryCode::CN, None));
+		assert!(HolidayProvider::is_business_day(date(2, 18), CountryCode::CN, None));
+	}
+}
@@ -1,12 +1,224 @@
 use std::collections::HashMap;
 
-use chrono::{Duration, TimeZone, Utc};
+use chrono::{Duration, NaiveDate};
 
 use crate::{
 	providers::base::catholicprovider::CatholicProvider,
-	types::{countrycode::CountryCode, publicholiday::PublicHoliday, publicholidaytype::PublicHolidayType},
+	types::{
+		countrycode::CountryCode, holidayid::HolidayId, holidayperiod::HolidayPeriod, publicholiday::PublicHoliday,
+		publicholidaytype::PublicHolidayType,
+	},
 };
 
+/// Local and english name of school holidays
+type SchoolHolidayName = (&'static str, &'static str);
+
+const WINTER: SchoolHolidayName = ("Winterferien", "Winter holidays");
+const EASTER: SchoolHolidayName = ("Osterferien", "Easter holidays");
+const WHITSUN: SchoolHolidayName = ("Pfingstferien", "Whitsun holidays");
+const SUMMER: SchoolHolidayName = ("Sommerferien", "Summer holidays");
+const AUTUMN: SchoolHolidayName = ("Herbstferien", "Autumn holidays");
+const CHRISTMAS: SchoolHolidayName = ("Weihnachtsferien", "Christmas holidays");
+
+/// County, name, first day (month, day) and last day (month, day) of school holidays. The holidays end in the following
+/// year if the last day is before the first day.
+type SchoolHolidays = (&'static str, SchoolHolidayName, (u32, u32), (u32, u32));
+
+/// School holidays of the federal states which start in the year, as published by the conference of the ministers of
+/// education. Single days off, like the day after ascension day, are listed with the holidays of the season.
+const SCHOOL_HOLIDAYS_2024: &[SchoolHolidays] = &[
+	("DE-BW", EASTER, (3, 23), (4, 5)),
+	("DE-BW", WHITSUN, (5, 21), (5, 31)),
+	("DE-BW", SUMMER, (7, 25), (9, 7)),
+	("DE-BW", AUTUMN, (10, 28), (10, 31)),
+	("DE-BW", CHRISTMAS, (12, 23), (1, 4)),
+	("DE-BY", WINTER, (2, 12), (2, 16)),
+	("DE-BY", EASTER, (3, 25), (4, 6)),
+	("DE-BY", WHITSUN, (5, 21), (6, 1)),
+	("DE-BY", SUMMER, (7, 29), (9, 9)),
+	("DE-BY", AUTUMN, (10, 28), (10, 31)),
+	("DE-BY", AUTUMN, (11, 20), (11, 20)),
+	("DE-BY", CHRISTMAS, (12, 23), (1, 3)),
+	("DE-BE", WINTER, (2, 5), (2, 10)),
+	("DE-BE", EASTER, (3, 25), (4, 5)),
+	("DE-BE", WHITSUN, (5, 10), (5, 10)),
+	("DE-BE", WHITSUN, (5, 21), (5, 21)),
+	("DE-BE", SUMMER, (7, 18), (8, 30)),
+	("DE-BE", AUTUMN, (10, 21), (11, 2)),
+	("DE-BE", CHRISTMAS, (12, 23), (12, 31)),
+	("DE-BB", WINTER, (2, 5), (2, 10)),
+	("DE-BB", EASTER, (3, 25), (4, 5)),
+	("DE-BB", WHITSUN, (5, 10), (5, 10)),
+	("DE-BB", SUMMER, (7, 18), (8, 30)),
+	("DE-BB", AUTUMN, (10, 21), (10, 30)),
+	("DE-BB", CHRISTMAS, (12, 23), (12, 31)),
+	("DE-HB", WINTER, (2, 1), (2, 2)),
+	("DE-HB", EASTER, (3, 18), (3, 28)),
+	("DE-HB", WHITSUN, (5, 10), (5, 10)),
+	("DE-HB", WHITSUN, (5, 21), (5, 21)),
+	("DE-HB", SUMMER, (6, 24), (8, 2)),
+	("DE-HB", AUTUMN, (10, 4), (10, 18)),
+	("DE-HB", CHRISTMAS, (12, 23), (1, 4)),
+	("DE-HH", WINTER, (2, 2), (2, 2)),
+	("DE-HH", EASTER, (3, 18), (3, 28)),
+	("DE-HH", WHITSUN, (5, 6), (5, 10)),
+	("DE-HH", SUMMER, (7, 18), (8, 28)),
+	("DE-HH", AUTUMN, (10, 21), (11, 1)),
+	("DE-HH", CHRISTMAS, (12, 20), (1, 3)),
+	("DE-HE", EASTER, (3, 25), (4, 13)),
+	("DE-HE", SUMMER, (7, 15), (8, 23)),
+	("DE-HE", AUTUMN, (10, 14), (10, 26)),
+	("DE-HE", CHRISTMAS, (12, 23), (1, 13)),
+	("DE-MV", WINTER, (2, 5), (2, 16)),
+	("DE-MV", EASTER, (3, 25), (4, 3)),
+	("DE-MV", WHITSUN, (5, 10), (5, 10)),
+	("DE-MV", WHITSUN, (5, 17), (5, 21)),
+	("DE-MV", SUMMER, (7, 22), (8, 30)),
+	("DE-MV", AUTUMN, (9, 30), (10, 2)),
+	("DE-MV", AUTUMN, (10, 31), (11, 1)),
+	("DE-MV", CHRISTMAS, (12, 23), (1, 4)),
+	("DE-NI", WINTER, (2, 1), (2, 2)),
+	("DE-NI", EASTER, (3, 18), (3, 28)),
+	("DE-NI", WHITSUN, (5, 10), (5, 10)),
+	("DE-NI", WHITSUN, (5, 21), (5, 21)),
+	("DE-NI", SUMMER, (6, 24), (8, 2)),
+	("DE-NI", AUTUMN, (10, 4), (10, 18)),
+	("DE-NI", CHRISTMAS, (12, 23), (1, 4)),
+	("DE-NW", EASTER, (3, 25), (4, 6)),
+	("DE-NW", WHITSUN, (5, 21), (5, 21)),
+	("DE-NW", SUMMER, (7, 8), (8, 20)),
+	("DE-NW", AUTUMN, (10, 14), (10, 26)),
+	("DE-NW", CHRISTMAS, (12, 23), (1, 6)),
+	("DE-RP", EASTER, (3, 25), (4, 2)),
+	("DE-RP", SUMMER, (7, 15), (8, 23)),
+	("DE-RP", AUTUMN, (10, 14), (10, 25)),
+	("DE-RP", CHRISTMAS, (12, 23), (1, 8)),
+	("DE-SL", WINTER, (2, 12), (2, 16)),
+	("DE-SL", EASTER, (3, 25), (4, 5)),
+	("DE-SL", SUMMER, (7, 15), (8, 23)),
+	("DE-SL", AUTUMN, (10, 14), (10, 25)),
+	("DE-SL", CHRISTMAS, (12, 23), (1, 3)),
+	("DE-SN", WINTER, (2, 12), (2, 23)),
+	("DE-SN", EASTER, (3, 28), (4, 5)),
+	("DE-SN", WHITSUN, (5, 10), (5, 10)),
+	("DE-SN", SUMMER, (6, 20), (8, 2)),
+	("DE-SN", AUTUMN, (10, 7), (10, 18)),
+	("DE-SN", CHRISTMAS, (12, 23), (1, 3)),
+	("DE-ST", WINTER, (2, 5), (2, 10)),
+	("DE-ST", EASTER, (3, 25), (3, 30)),
+	("DE-ST", WHITSUN, (5, 21), (5, 24)),
+	("DE-ST", SUMMER, (6, 24), (8, 2)),
+	("DE-ST", AUTUMN, (11, 4), (11, 8)),
+	("DE-ST", CHRISTMAS, (12, 19), (1, 4)),
+	("DE-SH", EASTER, (4, 2), (4, 19)),
+	("DE-SH", WHITSUN, (5, 10), (5, 10)),
+	("DE-SH", SUMMER, (7, 22), (8, 31)),
+	("DE-SH", AUTUMN, (10, 21), (11, 2)),
+	("DE-SH", CHRISTMAS, (12, 19), (1, 7)),
+	("DE-TH", WINTER, (2, 12), (2, 16)),
+	("DE-TH", EASTER, (4, 2), (4, 13)),
+	("DE-TH", WHITSUN, (5, 10), (5, 10)),
+	("DE-TH", SUMMER, (6, 20), (7, 30)),
+	("DE-TH", AUTUMN, (9, 30), (10, 11)),
+	("DE-TH", CHRISTMAS, (12, 23), (1, 3)),
+];
+
+const SCHOOL_HOLIDAYS_2025: &[SchoolHolidays] = &[
+	("DE-BW", EASTER, (4, 14), (4, 26)),
+	("DE-BW", WHITSUN, (6, 10), (6, 20)),
+	("DE-BW", SUMMER, (7, 31), (9, 13)),
+	("DE-BW", AUTUMN, (10, 27), (10, 31)),
+	("DE-BW", CHRISTMAS, (12, 22), (1, 5)),
+	("DE-BY", WINTER, (3, 3), (3, 7)),
+	("DE-BY", EASTER, (4, 14), (4, 25)),
+	("DE-BY", WHITSUN, (6, 10), (6, 20)),
+	("DE-BY", SUMMER, (8, 1), (9, 15)),
+	("DE-BY", AUTUMN, (11, 3), (11, 7)),
+	("DE-BY", AUTUMN, (11, 19), (11, 19)),
+	("DE-BY", CHRISTMAS, (12, 22), (1, 5)),
+	("DE-BE", WINTER, (2, 3), (2, 8)),
+	("DE-BE", EASTER, (4, 14), (4, 25)),
+	("DE-BE", WHITSUN, (5, 2), (5, 2)),
+	("DE-BE", WHITSUN, (5, 30), (5, 30)),
+	("DE-BE", SUMMER, (7, 24), (9, 6)),
+	("DE-BE", AUTUMN, (10, 20), (11, 1)),
+	("DE-BE", CHRISTMAS, (12, 22), (1, 2)),
+	("DE-BB", WINTER, (2, 3), (2, 8)),
+	("DE-BB", EASTER, (4, 14), (4, 25)),
+	("DE-BB", WHITSUN, (5, 30), (5, 30)),
+	("DE-BB", SUMMER, (7, 24), (9, 6)),
+	("DE-BB", AUTUMN, (10, 20), (11, 1)),
+	("DE-BB", CHRISTMAS, (12, 22), (1, 2)),
+	("DE-HB", WINTER, (2, 3), (2, 4)),
+	("DE-HB", EASTER, (4, 7), (4, 19)),
+	("DE-HB", WHITSUN, (5, 30), (5, 30)),
+	("DE-HB", WHITSUN, (6, 10), (6, 10)),
+	("DE-HB", SUMMER, (7, 3), (8, 13)),
+	("DE-HB", AUTUMN, (10, 13), (10, 25)),
+	("DE-HB", CHRISTMAS, (12, 22), (1, 5)),
+	("DE-HH", WINTER, (1, 31), (1, 31)),
+	("DE-HH", EASTER, (3, 10), (3, 21)),
+	("DE-HH", WHITSUN, (5, 26), (5, 30)),
+	("DE-HH", SUMMER, (7, 24), (9, 3)),
+	("DE-HH", AUTUMN, (10, 20), (10, 31)),
+	("DE-HH", CHRISTMAS, (12, 17), (1, 2)),
+	("DE-HE", EASTER, (4, 7), (4, 25)),
+	("DE-HE", SUMMER, (7, 7), (8, 15)),
+	("DE-HE", AUTUMN, (10, 6), (10, 18)),
+	("DE-HE", CHRISTMAS, (12, 22), (1, 10)),
+	("DE-MV", WINTER, (2, 3), (2, 14)),
+	("DE-MV", EASTER, (4, 14), (4, 23)),
+	("DE-MV", WHITSUN, (5, 30), (5, 30)),
+	("DE-MV", WHITSUN, (6, 6), (6, 10)),
+	("DE-MV", SUMMER, (7, 28), (9, 6)),
+	("DE-MV", AUTUMN, (10, 20), (10, 25)),
+	("DE-MV", CHRISTMAS, (12, 22), (1, 3)),
+	("DE-NI", WINTER, (2, 3), (2, 4)),
+	("DE-NI", EASTER, (4, 7), (4, 19)),
+	("DE-NI", WHITSUN, (5, 30), (5, 30)),
+	("DE-NI", WHITSUN, (6, 10), (6, 10)),
+	("DE-NI", SUMMER, (7, 3), (8, 13)),
+	("DE-NI", AUTUMN, (10, 13), (10, 25)),
+	("DE-NI", CHRISTMAS, (12, 22), (1, 5)),
+	("DE-NW", EASTER, (4, 14), (4, 26)),
+	("DE-NW", WHITSUN, (6, 10), (6, 10)),
+	("DE-NW", SUMMER, (7, 14), (8, 26)),
+	("DE-NW", AUTUMN, (10, 13), (10, 25)),
+	("DE-NW", CHRISTMAS, (12, 22), (1, 6)),
+	("DE-RP", EASTER, (4, 14), (4, 25)),
+	("DE-RP", SUMMER, (7, 7), (8, 15)),
+	("DE-RP", AUTUMN, (10, 13), (10, 24)),
+	("DE-RP", CHRISTMAS, (12, 22), (1, 7)),
+	("DE-SL", WINTER, (2, 24), (3, 4)),
+	("DE-SL", EASTER, (4, 14), (4, 25)),
+	("DE-SL", SUMMER, (7, 7), (8, 14)),
+	("DE-SL", AUTUMN, (10, 13), (10, 24)),
+	("DE-SL", CHRISTMAS, (12, 22), (1, 2)),
+	("DE-SN", WINTER, (2, 17), (3, 1)),
+	("DE-SN", EASTER, (4, 18), (4, 25)),
+	("DE-SN", WHITSUN, (5, 30), (5, 30)),
+	("DE-SN", SUMMER, (6, 28), (8, 8)),
+	("DE-SN", AUTUMN, (10, 6), (10, 18)),
+	("DE-SN", CHRISTMAS, (12, 22), (1, 2)),
+	("DE-ST", WINTER, (1, 27), (1, 31)),
+	("DE-ST", EASTER, (4, 7), (4, 19)),
+	("DE-ST", WHITSUN, (5, 30), (5, 30)),
+	("DE-ST", SUMMER, (6, 28), (8, 8)),
+	("DE-ST", AUTUMN, (10, 13), (10, 25)),
+	("DE-ST", CHRISTMAS, (12, 22), (1, 5)),
+	("DE-SH", EASTER, (4, 11), (4, 25)),
+	("DE-SH", WHITSUN, (5, 30), (5, 30)),
+	("DE-SH", SUMMER, (7, 28), (9, 6)),
+	("DE-SH", AUTUMN, (10, 20), (10, 30)),
+	("DE-SH", CHRISTMAS, (12, 19), (1, 6)),
+	("DE-TH", WINTER, (2, 3), (2, 8)),
+	("DE-TH", EASTER, (4, 7), (4, 19)),
+	("DE-TH", WHITSUN, (5, 30), (5, 30)),
+	("DE-TH", SUMMER, (6, 28), (8, 8)),
+	("DE-TH", AUTUMN, (10, 6), (10, 18)),
+	("DE-TH", CHRISTMAS, (12, 22), (1, 3)),
+];
+
 pub struct GermanHolidayProvider {}
 
 impl GermanHolidayProvider {
@@ -42,6 +254,7 @@ impl GermanHolidayProvider {
 	pub fn get_holidays(year: i32) -> Vec<PublicHoliday> {
 		let mut holidays = vec![
 			PublicHoliday::new_fixed(
+				HolidayId::NEW_YEARS_DAY,
 				year,
 				1,
 				1,
@@ -53,6 +266,7 @@ impl GermanHolidayProvider {
 				PublicHolidayType::Public,
 			),
 			PublicHoliday::new_fixed(
+				HolidayId::EPIPHANY,
 				year,
 				1,
 				6,
@@ -64,6 +278,7 @@ impl GermanHolidayProvider {
 				PublicHolidayType::Public,
 			),
 			PublicHoliday::new_fixed(
+				HolidayId::INTERNATIONAL_WOMENS_DAY,
 				year,
 				3,
 				8,
@@ -75,18 +290,17 @@ impl GermanHolidayProvider {
 				PublicHolidayType::Public,
 			),
 			CatholicProvider::good_friday("Karfreitag", year, Self::COUNTRYCODE),
-			CatholicProvider::easter_sunday("Ostersonntag", 2022, Self::COUNTRYCODE),
+			CatholicProvider::easter_sunday("Ostersonntag", year, Self::COUNTRYCODE),
 		];
-		let holiday_length = &holidays.len();
-		if let Some(holiday) = holidays.get_mut(*holiday_length) {
+		if let Some(holiday) = holidays.last_mut() {
 			holiday.set_counties(vec!["DE-BB".to_string(), "DE-HE".to_string()]);
 		}
 		holidays.push(CatholicProvider::easter_monday("Ostermontag", year, Self::COUNTRYCODE));
-		let holiday_length = &holidays.len();
-		if let Some(holiday) = holidays.get_mut(*holiday_length) {
+		if let Some(holiday) = holidays.last_mut() {
 			holiday.set_launch_year(1642);
 		}
 		holidays.push(PublicHoliday::new_fixed(
+			HolidayId::LABOUR_DAY,
 			year,
 			5,
 			1,
@@ -103,8 +317,7 @@ impl GermanHolidayProvider {
 			Self::COUNTRYCODE,
 		));
 		holidays.push(CatholicProvider::pentecost("Pfingstsonntag", year, Self::COUNTRYCODE));
-		let holiday_length = &holidays.len();
-		if let Some(holiday) = holidays.get_mut(*holiday_length) {
+		if let Some(holiday) = holidays.last_mut() {
 			holiday.set_counties(vec!["DE-BB".to_string(), "DE-HE".to_string()]);
 		}
 		holidays.push(CatholicProvider::whit_monday("Pfingstmontag", year, Self::COUNTRYCODE));
@@ -113,18 +326,59 @@ impl GermanHolidayProvider {
 			year,
 			Self::COUNTRYCODE,
 		));
-		let holiday_length = &holidays.len();
-		if let Some(holiday) = holidays.get_mut(*holiday_length) {
-			holiday.set_counties(vec![
-				"DE-BW".to_string(),
-				"DE-BY".to_string(),
-				"DE-HE".to_string(),
-				"DE-NW".to_string(),
-				"DE-RP".to_string(),
-				"DE-SL".to_string(),
-			]);
+		if let Some(holiday) = holidays.last_mut() {
+			holiday
+				.set_counties(vec![
+					"DE-BW".to_string(),
+					"DE-BY".to_string(),
+					"DE-HE".to_string(),
+					"DE-NW".to_string(),
+					"DE-RP".to_string(),
+					"DE-SL".to_string(),
+					"DE-SN".to_string(),
+					"DE-TH".to_string(),
+				])
+				.set_localities(
+					"DE-SN",
+					vec![
+						"Crostwitz".to_string(),
+						"Nebelschütz".to_string(),
+						"Panschwitz-Kuckau".to_string(),
+						"Räckelwitz".to_string(),
+						"Ralbitz-Rosenthal".to_string(),
+					],
+				)
+				.set_localities(
+					"DE-TH",
+					vec![
+						"16061".to_string(),
+						"Anrode".to_string(),
+						"Dünwald".to_string(),
+						"Rodeberg".to_string(),
+						"Südeichsfeld".to_string(),
+						"Buttlar".to_string(),
+						"Geisa".to_string(),
+						"Schleid".to_string(),
+					],
+				);
+		}
+		holidays.push(PublicHoliday::new_fixed(
+			HolidayId::DE_AUGSBURG_PEACE_FESTIVAL,
+			year,
+			8,
+			8,
+			"Augsburger Hohes Friedensfest",
+			"Augsburg Peace Festival",
+			Self::COUNTRYCODE,
+			Some(1650),
+			Some(vec!["DE-BY".to_string()]),
+			PublicHolidayType::Public,
+		));
+		if let Some(holiday) = holidays.last_mut() {
+			holiday.set_localities("DE-BY", vec!["09761000".to_string(), "Augsburg".to_string()]);
 		}
 		holidays.push(PublicHoliday::new_fixed(
+			HolidayId::ASSUMPTION_DAY,
 			year,
 			8,
 			15,
@@ -132,10 +386,82 @@ impl GermanHolidayProvider {
 			"Assumption Day",
 			Self::COUNTRYCODE,
 			None,
-			Some(vec!["DE-SL".to_string()]),
+			Some(vec!["DE-BY".to_string(), "DE-SL".to_string()]),
 			PublicHolidayType::Public,
 		));
+		if let Some(holiday) = holidays.last_mut() {
+			// the holiday applies to the bavarian municipalities with a predominantly catholic population, which are
+			// published by the bavarian state office for statistics after each census. The predominantly protestant
+			// minority is excluded by the keys of its independent cities and districts in franconia, together with
+			// the names of their towns, so catholic municipalities within these districts are excluded as well.
+			holiday.set_excluded_localities(
+				"DE-BY",
+				vec![
+					"09462000".to_string(),
+					"Bayreuth".to_string(),
+					"09463000".to_string(),
+					"Coburg".to_string(),
+					"09464000".to_string(),
+					"Hof".to_string(),
+					"09472".to_string(),
+					"09473".to_string(),
+					"09475".to_string(),
+					"09477".to_string(),
+					"09479".to_string(),
+					"09561000".to_string(),
+					"Ansbach".to_string(),
+					"09562000".to_string(),
+					"Erlangen".to_string(),
+					"09563000".to_string(),
+					"Fürth".to_string(),
+					"09564000".to_string(),
+					"Nürnberg".to_string(),
+					"09565000".to_string(),
+					"Schwabach".to_string(),
+					"09571".to_string(),
+					"09573".to_string(),
+					"09574".to_string(),
+					"09575".to_string(),
+					"09576".to_string(),
+					"09577".to_string(),
+					"Bad Berneck im Fichtelgebirge".to_string(),
+					"Bad Rodach".to_string(),
+					"Bad Windsheim".to_string(),
+					"Creußen".to_string(),
+					"Feuchtwangen".to_string(),
+					"Gefrees".to_string(),
+					"Goldkronach".to_string(),
+					"Gunzenhausen".to_string(),
+					"Heilsbronn".to_string(),
+					"Helmbrechts".to_string(),
+					"Hersbruck".to_string(),
+					"Kulmbach".to_string(),
+					"Lauf an der Pegnitz".to_string(),
+					"Marktredwitz".to_string(),
+					"Münchberg".to_string(),
+					"Naila".to_string(),
+					"Neuendettelsau".to_string(),
+					"Neustadt an der Aisch".to_string(),
+					"Neustadt bei Coburg".to_string(),
+					"Oberasbach".to_string(),
+					"Pegnitz".to_string(),
+					"Rehau".to_string(),
+					"Rödental".to_string(),
+					"Roth".to_string(),
+					"Rothenburg ob der Tauber".to_string(),
+					"Schwarzenbach an der Saale".to_string(),
+					"Selb".to_string(),
+					"Stein".to_string(),
+					"Thurnau".to_string(),
+					"Treuchtlingen".to_string(),
+					"Weißenburg in Bayern".to_string(),
+					"Wunsiedel".to_string(),
+					"Zirndorf".to_string(),
+				],
+			);
+		}
 		holidays.push(PublicHoliday::new_fixed(
+			HolidayId::DE_WORLD_CHILDRENS_DAY,
 			year,
 			9,
 			20,
@@ -147,6 +473,7 @@ impl GermanHolidayProvider {
 			PublicHolidayType::Public,
 		));
 		holidays.push(PublicHoliday::new_fixed(
+			HolidayId::DE_GERMAN_UNITY_DAY,
 			year,
 			10,
 			3,
@@ -158,6 +485,7 @@ impl GermanHolidayProvider {
 			PublicHolidayType::Public,
 		));
 		holidays.push(PublicHoliday::new_fixed(
+			HolidayId::ALL_SAINTS_DAY,
 			year,
 			11,
 			1,
@@ -175,6 +503,7 @@ impl GermanHolidayProvider {
 			PublicHolidayType::Public,
 		));
 		holidays.push(PublicHoliday::new_fixed(
+			HolidayId::CHRISTMAS_DAY,
 			year,
 			12,
 			25,
@@ -186,6 +515,7 @@ impl GermanHolidayProvider {
 			PublicHolidayType::Public,
 		));
 		holidays.push(PublicHoliday::new_fixed(
+			HolidayId::ST_STEPHENS_DAY,
 			year,
 			12,
 			26,
@@ -209,11 +539,44 @@ impl GermanHolidayProvider {
 
 		holidays.push(Self::get_reformation_day(year, Self::COUNTRYCODE));
 
-		holidays.sort_by(|a, b| a.date.cmp(&b.date));
+		holidays.sort_by_key(|a| a.date);
 
 		holidays
 	}
 
+	/// Gets the school holidays of the federal states which start in the given year, from the winter holidays to the
+	/// christmas holidays, which end in the following year. Only the school holidays which start in 2024 and 2025 are
+	/// available.
+	pub fn get_school_holidays(year: i32) -> Vec<HolidayPeriod> {
+		let school_holidays: &[SchoolHolidays] = match year {
+			2024 => SCHOOL_HOLIDAYS_2024,
+			2025 => SCHOOL_HOLIDAYS_2025,
+			_ => &[],
+		};
+
+		school_holidays
+			.iter()
+			.map(
+				|(county, (local_name, name), (start_month, start_day), (end_month, end_day))| {
+					let start = NaiveDate::from_ymd_opt(year, *start_month, *start_day).unwrap();
+					let mut end = NaiveDate::from_ymd_opt(year, *end_month, *end_day).unwrap();
+					if end < start {
+						end = NaiveDate::from_ymd_opt(year + 1, *end_month, *end_day).unwrap();
+					}
+					HolidayPeriod::new(
+						start,
+						end,
+						local_name,
+						name,
+						Self::COUNTRYCODE,
+						Some(vec![county.to_string()]),
+						PublicHolidayType::School,
+					)
+				},
+			)
+			.collect()
+	}
+
 	fn get_reformation_day(year: i32, country_code: CountryCode) -> PublicHoliday {
 		let local_name = "Reformationstag";
 		let english_name = "Reformation Day";
@@ -221,6 +584,7 @@ impl GermanHolidayProvider {
 		if year == 2017 {
 			//In commemoration of the 500th anniversary of the beginning of the Reformation, it was unique as a whole German holiday
 			return PublicHoliday::new_fixed(
+				HolidayId::DE_REFORMATION_DAY,
 				year,
 				10,
 				31,
@@ -251,6 +615,7 @@ impl GermanHolidayProvider {
 		}
 
 		PublicHoliday::new_fixed(
+			HolidayId::DE_REFORMATION_DAY,
 			year,
 			10,
 			31,
@@ -270,6 +635,7 @@ impl GermanHolidayProvider {
 
 		if (1934..1939).contains(&year) {
 			Some(PublicHoliday::new_non_fixed(
+				HolidayId::DE_REPENTANCE_AND_PRAYER_DAY,
 				day_of_prayer,
 				local_name,
 				english_name,
@@ -280,6 +646,7 @@ impl GermanHolidayProvider {
 			))
 		} else if (1945..1980).contains(&year) {
 			Some(PublicHoliday::new_non_fixed(
+				HolidayId::DE_REPENTANCE_AND_PRAYER_DAY,
 				day_of_prayer,
 				local_name,
 				english_name,
@@ -301,6 +668,7 @@ impl GermanHolidayProvider {
 			))
 		} else if (1981..1989).contains(&year) {
 			Some(PublicHoliday::new_non_fixed(
+				HolidayId::DE_REPENTANCE_AND_PRAYER_DAY,
 				day_of_prayer,
 				local_name,
 				english_name,
@@ -323,6 +691,7 @@ impl GermanHolidayProvider {
 			))
 		} else if (1990..1994).contains(&year) {
 			Some(PublicHoliday::new_non_fixed(
+				HolidayId::DE_REPENTANCE_AND_PRAYER_DAY,
 				day_of_prayer,
 				local_name,
 				english_name,
@@ -333,6 +702,7 @@ impl GermanHolidayProvider {
 			))
 		} else if year >= 1995 {
 			Some(PublicHoliday::new_non_fixed(
+				HolidayId::DE_REPENTANCE_AND_PRAYER_DAY,
 				day_of_prayer,
 				local_name,
 				english_name,
@@ -347,12 +717,13 @@ impl GermanHolidayProvider {
 	}
 
 	fn get_liberation_day(year: i32) -> Option<PublicHoliday> {
-		let day_of_prayer = Utc.with_ymd_and_hms(2020, 5, 8, 0, 0, 0).unwrap();
+		let day_of_prayer = NaiveDate::from_ymd_opt(2020, 5, 8).unwrap();
 		let local_name = "Tag der Befreiung";
 		let english_name = "Liberation Day";
 
 		if year == 2020 {
 			Some(PublicHoliday::new_non_fixed(
+				HolidayId::DE_LIBERATION_DAY,
 				day_of_prayer,
 				local_name,
 				english_name,
@@ -374,11 +745,94 @@ mod tests {
 	#[test]
 	fn test_get_holidays() {
 		let result = GermanHolidayProvider::get_holidays(2022);
-		assert_eq!(result.len(), 19);
+		assert_eq!(result.len(), 20);
 		assert_eq!(result.is_empty(), false);
 		assert_eq!(result[5].local_name, "Ostermontag".to_string());
 	}
 
+	#[test]
+	fn test_regional_holidays() {
+		let result = GermanHolidayProvider::get_holidays(2025);
+		let easter_sunday = result.iter().find(|holiday| holiday.name == "Easter Sunday").unwrap();
+		assert_eq!(easter_sunday.to_string(), "2025-04-20 Easter Sunday");
+		assert!(!easter_sunday.is_in_county("DE-BY"));
+		let corpus_christi = result.iter().find(|holiday| holiday.name == "Corpus Christi").unwrap();
+		assert!(corpus_christi.is_in_county("DE-BW"));
+		assert!(!corpus_christi.is_in_county("DE-BE"));
+	}
+
+	#[test]
+	fn test_local_holidays() {
+		let result = GermanHolidayProvider::get_holidays(2025);
+		let single = |name: &str| {
+			let holidays: Vec<_> = result.iter().filter(|holiday| holiday.name == name).collect();
+			assert_eq!(holidays.len(), 1, "{name} is listed more than once");
+			holidays[0]
+		};
+		let assumption_day = single("Assumption Day");
+		assert!(assumption_day.is_in_county("DE-SL") && assumption_day.is_in_location("DE-SL", None));
+		assert!(!assumption_day.is_in_county("DE-BY"));
+		assert!(assumption_day.is_in_location("DE-BY", Some("09162000")));
+		assert!(assumption_day.is_in_location("DE-BY", Some("MÜNCHEN")));
+		assert!(!assumption_day.is_in_location("DE-BY", Some("Nürnberg")));
+		assert!(!assumption_day.is_in_location("DE-BY", None));
+		assert!(!assumption_day.is_in_location("DE-BE", Some("München")));
+		assert!(assumption_day.is_in_location("DE-BY", Some("Altötting")));
+		assert!(assumption_day.is_in_location("DE-BY", Some("Garmisch-Partenkirchen")));
+		assert!(assumption_day.is_in_location("DE-BY", Some("09180117")));
+		assert!(!assumption_day.is_in_location("DE-BY", Some("09564000")));
+		assert!(!assumption_day.is_in_location("DE-BY", Some("09475163")));
+		let corpus_christi = single("Corpus Christi");
+		assert!(corpus_christi.is_in_county("DE-BW"));
+		assert!(corpus_christi.is_in_location("DE-SN", Some("Crostwitz")));
+		assert!(!corpus_christi.is_in_location("DE-SN", Some("Dresden")));
+		// every municipality of the district eichsfeld matches the key of the district
+		assert!(corpus_christi.is_in_location("DE-TH", Some("16061115")));
+		assert!(!corpus_christi.is_in_location("DE-TH", Some("16051000")));
+	}
+
+	#[test]
+	fn test_get_school_holidays() {
+		let result = GermanHolidayProvider::get_school_holidays(2024);
+		let nw: Vec<String> = result
+			.iter()
+			.filter(|period| period.is_in_county("DE-NW"))
+			.map(|period| period.to_string())
+			.collect();
+		assert_eq!(
+			nw,
+			vec![
+				"2024-03-25..2024-04-06 Easter holidays",
+				"2024-05-21..2024-05-21 Whitsun holidays",
+				"2024-07-08..2024-08-20 Summer holidays",
+				"2024-10-14..2024-10-26 Autumn holidays",
+				"2024-12-23..2025-01-06 Christmas holidays",
+			]
+		);
+		for year in [2024, 2025] {
+			let result = GermanHolidayProvider::get_school_holidays(year);
+			for county in GermanHolidayProvider::get_counties().keys() {
+				let periods: Vec<_> = result.iter().filter(|period| period.is_in_county(county)).collect();
+				assert_eq!(
+					periods.iter().filter(|period| period.name == "Summer holidays").count(),
+					1
+				);
+				assert_eq!(
+					periods
+						.iter()
+						.filter(|period| period.name == "Christmas holidays")
+						.count(),
+					1
+				);
+				assert!(
+					periods.windows(2).all(|pair| pair[0].end < pair[1].start),
+					"{county} {year}"
+				);
+			}
+		}
+		assert!(GermanHolidayProvider::get_school_holidays(2023).is_empty());
+	}
+
 	#[test]
 	fn test_get_counties() {
 		let result = GermanHolidayProvider::get_counties();
@@ -1,8 +1,9 @@
-use chrono::Duration;
-
 use crate::{
 	providers::base::orthodoxprovider::OrthodoxProvider,
-	types::{countrycode::CountryCode, publicholiday::PublicHoliday, publicholidaytype::PublicHolidayType},
+	types::{
+		computus::Computus, countrycode::CountryCode, holidayid::HolidayId, moveablefeast::MoveableFeast,
+		publicholiday::PublicHoliday, publicholidaytype::PublicHolidayType,
+	},
 };
 
 pub struct GreeceHolidayProvider {}
@@ -11,10 +12,9 @@ impl GreeceHolidayProvider {
 	const COUNTRY_CODE: CountryCode = CountryCode::GR;
 
 	pub fn get_holidays(year: i32) -> Vec<PublicHoliday> {
-		let easter_sunday = OrthodoxProvider::get_orthodox_easter(year);
-
 		let mut holidays = vec![
 			PublicHoliday::new_fixed(
+				HolidayId::NEW_YEARS_DAY,
 				year,
 				1,
 				1,
@@ -26,6 +26,7 @@ impl GreeceHolidayProvider {
 				PublicHolidayType::Public,
 			),
 			PublicHoliday::new_fixed(
+				HolidayId::EPIPHANY,
 				year,
 				1,
 				6,
@@ -36,16 +37,9 @@ impl GreeceHolidayProvider {
 				None,
 				PublicHolidayType::Public,
 			),
-			PublicHoliday::new_non_fixed(
-				easter_sunday - Duration::days(48),
-				"Καθαρά Δευτέρα",
-				"Clean Monday",
-				Self::COUNTRY_CODE,
-				None,
-				None,
-				PublicHolidayType::Public,
-			),
+			MoveableFeast::CleanMonday.holiday(Computus::Julian, "Καθαρά Δευτέρα", year, Self::COUNTRY_CODE),
 			PublicHoliday::new_fixed(
+				HolidayId::ANNUNCIATION,
 				year,
 				3,
 				25,
@@ -57,6 +51,7 @@ impl GreeceHolidayProvider {
 				PublicHolidayType::Public,
 			),
 			PublicHoliday::new_fixed(
+				HolidayId::GR_INDEPENDENCE_DAY,
 				year,
 				3,
 				25,
@@ -71,6 +66,7 @@ impl GreeceHolidayProvider {
 			OrthodoxProvider::easter_sunday("Κυριακή του Πάσχα", year, Self::COUNTRY_CODE),
 			OrthodoxProvider::easter_monday("Δευτέρα του Πάσχα", year, Self::COUNTRY_CODE),
 			PublicHoliday::new_fixed(
+				HolidayId::LABOUR_DAY,
 				year,
 				5,
 				1,
@@ -84,6 +80,7 @@ impl GreeceHolidayProvider {
 			OrthodoxProvider::pentecost("Πεντηκοστή'", year, Self::COUNTRY_CODE),
 			OrthodoxProvider::whit_monday("Δευτέρα Πεντηκοστής", year, Self::COUNTRY_CODE),
 			PublicHoliday::new_fixed(
+				HolidayId::ASSUMPTION_DAY,
 				year,
 				8,
 				15,
@@ -95,6 +92,7 @@ impl GreeceHolidayProvider {
 				PublicHolidayType::Public,
 			),
 			PublicHoliday::new_fixed(
+				HolidayId::GR_OCHI_DAY,
 				year,
 				10,
 				28,
@@ -106,6 +104,7 @@ impl GreeceHolidayProvider {
 				PublicHolidayType::Public,
 			),
 			PublicHoliday::new_fixed(
+				HolidayId::CHRISTMAS_DAY,
 				year,
 				12,
 				25,
@@ -117,6 +116,7 @@ impl GreeceHolidayProvider {
 				PublicHolidayType::Public,
 			),
 			PublicHoliday::new_fixed(
+				HolidayId::GR_SYNAXIS_OF_THE_THEOTOKOS,
 				year,
 				12,
 				26,
@@ -129,7 +129,7 @@ impl GreeceHolidayProvider {
 			),
 		];
 
-		holidays.sort_by(|a, b| a.date.cmp(&b.date));
+		holidays.sort_by_key(|a| a.date);
 
 		holidays
 	}
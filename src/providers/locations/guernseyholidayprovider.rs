@@ -0,0 +1,30 @@
+use crate::{
+	providers::base::channelislandsprovider::ChannelIslandsProvider,
+	types::{countrycode::CountryCode, holidayid::HolidayId, publicholiday::PublicHoliday},
+};
+
+pub struct GuernseyHolidayProvider {}
+
+impl GuernseyHolidayProvider {
+	const COUNTRYCODE: CountryCode = CountryCode::GG;
+
+	/// Gets the bank holidays of guernsey, which are the bank holidays of england and the liberation day
+	pub fn get_holidays(year: i32) -> Vec<PublicHoliday> {
+		ChannelIslandsProvider::get_holidays(HolidayId::GG_LIBERATION_DAY, year, Self::COUNTRYCODE)
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::GuernseyHolidayProvider;
+
+	#[test]
+	fn test_get_holidays() {
+		let result = GuernseyHolidayProvider::get_holidays(2025);
+		assert_eq!(result.len(), 9);
+		assert_eq!(result[4].to_string(), "2025-05-09 Liberation Day");
+		assert!(GuernseyHolidayProvider::get_holidays(1945)
+			.iter()
+			.all(|holiday| holiday.name != "Liberation Day"));
+	}
+}
@@ -0,0 +1,87 @@
+use chrono::{Duration, NaiveDate, Weekday};
+
+use crate::{
+	datesystem::DateSystem,
+	providers::locations::unitedkingdomholidayprovider::UnitedKingdomHolidayProvider,
+	types::{
+		countrycode::CountryCode, holidayid::HolidayId, publicholiday::PublicHoliday,
+		publicholidaytype::PublicHolidayType,
+	},
+};
+
+pub struct IsleOfManHolidayProvider {}
+
+impl IsleOfManHolidayProvider {
+	const COUNTRYCODE: CountryCode = CountryCode::IM;
+
+	/// Gets the bank holidays of the isle of man, which are the bank holidays of england, the senior race day of the
+	/// tourist trophy and the tynwald day. If the tynwald day falls on a weekend, the monday after is a holiday.
+	pub fn get_holidays(year: i32) -> Vec<PublicHoliday> {
+		let mut holidays = UnitedKingdomHolidayProvider::get_england_holidays(year, Self::COUNTRYCODE);
+
+		// the senior race closes the tt races on the friday of the second week after the last monday in may
+		let senior_race_day = DateSystem::find_last_day(year, 5, Weekday::Mon).unwrap() + Duration::days(11);
+		holidays.push(PublicHoliday::new_non_fixed(
+			HolidayId::IM_TT_SENIOR_RACE_DAY,
+			senior_race_day,
+			"TT Senior Race Day",
+			"TT Senior Race Day",
+			Self::COUNTRYCODE,
+			None,
+			None,
+			PublicHolidayType::Public,
+		));
+
+		let tynwald_day = DateSystem::shift(NaiveDate::from_ymd_opt(year, 7, 5).unwrap(), 2, 1, None);
+		holidays.push(PublicHoliday::new_non_fixed(
+			HolidayId::IM_TYNWALD_DAY,
+			tynwald_day,
+			"Tynwald Day",
+			"Tynwald Day",
+			Self::COUNTRYCODE,
+			None,
+			None,
+			PublicHolidayType::Public,
+		));
+
+		holidays.sort_by_key(|a| a.date);
+
+		holidays
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::IsleOfManHolidayProvider;
+
+	#[test]
+	fn test_get_holidays() {
+		let result = IsleOfManHolidayProvider::get_holidays(2024);
+		assert_eq!(result.len(), 10);
+		assert!(result
+			.iter()
+			.any(|holiday| holiday.to_string() == "2024-07-05 Tynwald Day"));
+		// tynwald day on a saturday is observed on monday
+		let result = IsleOfManHolidayProvider::get_holidays(2025);
+		assert!(result
+			.iter()
+			.any(|holiday| holiday.to_string() == "2025-07-07 Tynwald Day"));
+	}
+
+	#[test]
+	fn test_senior_race_day() {
+		for (year, date) in [
+			(2022, "2022-06-10"),
+			(2023, "2023-06-09"),
+			(2024, "2024-06-07"),
+			(2025, "2025-06-06"),
+		] {
+			let result = IsleOfManHolidayProvider::get_holidays(year);
+			let senior_race_day = result
+				.iter()
+				.find(|holiday| holiday.name == "TT Senior Race Day")
+				.unwrap();
+			assert_eq!(senior_race_day.date.to_string(), date);
+		}
+	}
+}
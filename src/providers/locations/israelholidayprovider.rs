@@ -0,0 +1,159 @@
+use chrono::{Datelike, Duration, NaiveDate, Weekday};
+
+use crate::{
+	calendars::hebrew::HebrewCalendar,
+	types::{
+		countrycode::CountryCode, holidayid::HolidayId, publicholiday::PublicHoliday,
+		publicholidaytype::PublicHolidayType,
+	},
+};
+
+pub struct IsraelHolidayProvider {}
+
+impl IsraelHolidayProvider {
+	const COUNTRYCODE: CountryCode = CountryCode::IL;
+
+	pub fn get_holidays(year: i32) -> Vec<PublicHoliday> {
+		let mut holidays = vec![
+			Self::hebrew_holiday(HolidayId::PASSOVER, year, HebrewCalendar::NISAN, 15, "פסח", "Passover"),
+			Self::hebrew_holiday(
+				HolidayId::PASSOVER_SEVENTH_DAY,
+				year,
+				HebrewCalendar::NISAN,
+				21,
+				"שביעי של פסח",
+				"Seventh day of Passover",
+			),
+			Self::hebrew_holiday(HolidayId::SHAVUOT, year, HebrewCalendar::SIVAN, 6, "שבועות", "Shavuot"),
+			Self::hebrew_holiday(
+				HolidayId::ROSH_HASHANAH,
+				year,
+				HebrewCalendar::TISHREI,
+				1,
+				"ראש השנה",
+				"Rosh Hashanah",
+			),
+			Self::hebrew_holiday(
+				HolidayId::ROSH_HASHANAH,
+				year,
+				HebrewCalendar::TISHREI,
+				2,
+				"ראש השנה",
+				"Rosh Hashanah",
+			),
+			Self::hebrew_holiday(
+				HolidayId::YOM_KIPPUR,
+				year,
+				HebrewCalendar::TISHREI,
+				10,
+				"יום כיפור",
+				"Yom Kippur",
+			),
+			Self::hebrew_holiday(HolidayId::SUKKOT, year, HebrewCalendar::TISHREI, 15, "סוכות", "Sukkot"),
+			Self::hebrew_holiday(
+				HolidayId::SIMCHAT_TORAH,
+				year,
+				HebrewCalendar::TISHREI,
+				22,
+				"שמחת תורה",
+				"Simchat Torah",
+			),
+		];
+
+		if let Some(independence_day) = Self::independence_day(year) {
+			holidays.push(Some(Self::holiday(
+				HolidayId::IL_INDEPENDENCE_DAY,
+				independence_day,
+				"יום העצמאות",
+				"Independence Day",
+				Some(1949),
+			)));
+		}
+
+		let mut holidays: Vec<PublicHoliday> = holidays.into_iter().flatten().collect();
+		holidays.sort_by_key(|a| a.date);
+
+		holidays
+	}
+
+	/// Yom Ha'atzmaut takes place on the 5th Iyar. It is brought forward to thursday if it falls on a friday or
+	/// saturday and postponed to tuesday if it falls on a monday (since 2004).
+	fn independence_day(year: i32) -> Option<NaiveDate> {
+		if year < 1949 {
+			return None;
+		}
+
+		let date = HebrewCalendar::fixed_date_in_gregorian_year(year, HebrewCalendar::IYAR, 5)?;
+
+		Some(match date.weekday() {
+			Weekday::Fri => date - Duration::days(1),
+			Weekday::Sat => date - Duration::days(2),
+			Weekday::Mon if year >= 2004 => date + Duration::days(1),
+			_ => date,
+		})
+	}
+
+	fn hebrew_holiday(
+		id: HolidayId,
+		year: i32,
+		month: u32,
+		day: u32,
+		local_name: &str,
+		name: &str,
+	) -> Option<PublicHoliday> {
+		let date = HebrewCalendar::fixed_date_in_gregorian_year(year, month, day)?;
+
+		Some(Self::holiday(id, date, local_name, name, None))
+	}
+
+	fn holiday(
+		id: HolidayId,
+		date: NaiveDate,
+		local_name: &str,
+		name: &str,
+		launch_year: Option<i32>,
+	) -> PublicHoliday {
+		let mut holiday = PublicHoliday::new_non_fixed(
+			id,
+			date,
+			local_name,
+			name,
+			Self::COUNTRYCODE,
+			launch_year,
+			None,
+			PublicHolidayType::Public,
+		);
+		holiday.set_starts_previous_evening(true);
+
+		holiday
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::IsraelHolidayProvider;
+
+	#[test]
+	fn test_get_holidays() {
+		let result = IsraelHolidayProvider::get_holidays(2024);
+		assert_eq!(result.len(), 9);
+		assert_eq!(result[0].to_string(), "2024-04-23 Passover");
+		assert_eq!(result[3].to_string(), "2024-06-12 Shavuot");
+		assert_eq!(result[4].to_string(), "2024-10-03 Rosh Hashanah");
+		assert_eq!(result[8].to_string(), "2024-10-24 Simchat Torah");
+		assert!(result.iter().all(|holiday| holiday.starts_previous_evening));
+	}
+
+	#[test]
+	fn test_independence_day() {
+		// postponed from monday
+		let result = IsraelHolidayProvider::get_holidays(2024);
+		assert_eq!(result[2].to_string(), "2024-05-14 Independence Day");
+		// brought forward from friday
+		let result = IsraelHolidayProvider::get_holidays(2022);
+		assert_eq!(result[2].to_string(), "2022-05-05 Independence Day");
+		// no postponement
+		let result = IsraelHolidayProvider::get_holidays(2023);
+		assert_eq!(result[2].to_string(), "2023-04-26 Independence Day");
+	}
+}
@@ -0,0 +1,396 @@
+use chrono::{Datelike, Duration, FixedOffset, NaiveDate, Weekday};
+
+use crate::{
+	datesystem::DateSystem,
+	types::{
+		countrycode::CountryCode, holidayid::HolidayId, occurrence::Occurrence, publicholiday::PublicHoliday,
+		publicholidaytype::PublicHolidayType, season::Season,
+	},
+};
+
+pub struct JapanHolidayProvider {}
+
+impl JapanHolidayProvider {
+	const COUNTRYCODE: CountryCode = CountryCode::JP;
+	/// Japan standard time (UTC+9), which decides the day of the equinoxes
+	const UTC_OFFSET_SECONDS: i32 = 9 * 3600;
+
+	pub fn get_holidays(year: i32) -> Vec<PublicHoliday> {
+		if year < 1949 {
+			return Vec::new();
+		}
+
+		let mut holidays = vec![
+			Self::fixed(HolidayId::NEW_YEARS_DAY, year, 1, 1, "元日", "New Year's Day"),
+			Self::fixed(
+				HolidayId::JP_CONSTITUTION_MEMORIAL_DAY,
+				year,
+				5,
+				3,
+				"憲法記念日",
+				"Constitution Memorial Day",
+			),
+			Self::fixed(HolidayId::CHILDRENS_DAY, year, 5, 5, "こどもの日", "Children's Day"),
+			Self::fixed(HolidayId::JP_CULTURE_DAY, year, 11, 3, "文化の日", "Culture Day"),
+			Self::fixed(
+				HolidayId::JP_LABOUR_THANKSGIVING_DAY,
+				year,
+				11,
+				23,
+				"勤労感謝の日",
+				"Labour Thanksgiving Day",
+			),
+			Self::equinox(
+				HolidayId::JP_VERNAL_EQUINOX_DAY,
+				year,
+				Season::MarchEquinox,
+				"春分の日",
+				"Vernal Equinox Day",
+			),
+			Self::equinox(
+				HolidayId::JP_AUTUMNAL_EQUINOX_DAY,
+				year,
+				Season::SeptemberEquinox,
+				"秋分の日",
+				"Autumnal Equinox Day",
+			),
+		];
+
+		holidays.push(Self::happy_monday(
+			HolidayId::JP_COMING_OF_AGE_DAY,
+			year,
+			1,
+			Occurrence::Second,
+			15,
+			2000,
+			"成人の日",
+			"Coming of Age Day",
+		));
+
+		if year >= 1967 {
+			holidays.push(Self::fixed(
+				HolidayId::JP_NATIONAL_FOUNDATION_DAY,
+				year,
+				2,
+				11,
+				"建国記念の日",
+				"National Foundation Day",
+			));
+		}
+
+		if year >= 1966 {
+			holidays.push(Self::happy_monday(
+				HolidayId::JP_RESPECT_FOR_THE_AGED_DAY,
+				year,
+				9,
+				Occurrence::Third,
+				15,
+				2003,
+				"敬老の日",
+				"Respect for the Aged Day",
+			));
+		}
+
+		holidays.extend(Self::get_emperors_birthday(year));
+		holidays.extend(Self::get_greenery_days(year));
+		holidays.extend(Self::get_marine_day(year));
+		holidays.extend(Self::get_mountain_day(year));
+		holidays.extend(Self::get_sports_day(year));
+		holidays.extend(Self::get_enthronement_days(year));
+
+		holidays.extend(Self::get_citizens_holidays(&holidays));
+		holidays.extend(Self::get_substitute_holidays(&holidays));
+
+		holidays.sort_by_key(|a| a.date);
+
+		holidays
+	}
+
+	fn get_emperors_birthday(year: i32) -> Option<PublicHoliday> {
+		let (month, day) = if year < 1989 {
+			(4, 29)
+		} else if year < 2019 {
+			(12, 23)
+		} else if year > 2019 {
+			(2, 23)
+		} else {
+			return None;
+		};
+
+		Some(Self::fixed(
+			HolidayId::JP_EMPERORS_BIRTHDAY,
+			year,
+			month,
+			day,
+			"天皇誕生日",
+			"Emperor's Birthday",
+		))
+	}
+
+	/// The 29th april was the greenery day from 1989 until 2006 and became the showa day in 2007, when the greenery
+	/// day moved to the 4th may
+	fn get_greenery_days(year: i32) -> Vec<PublicHoliday> {
+		if year >= 2007 {
+			vec![
+				Self::fixed(HolidayId::JP_SHOWA_DAY, year, 4, 29, "昭和の日", "Shōwa Day"),
+				Self::fixed(HolidayId::JP_GREENERY_DAY, year, 5, 4, "みどりの日", "Greenery Day"),
+			]
+		} else if year >= 1989 {
+			vec![Self::fixed(
+				HolidayId::JP_GREENERY_DAY,
+				year,
+				4,
+				29,
+				"みどりの日",
+				"Greenery Day",
+			)]
+		} else {
+			Vec::new()
+		}
+	}
+
+	fn get_marine_day(year: i32) -> Option<PublicHoliday> {
+		let local_name = "海の日";
+		let name = "Marine Day";
+
+		match year {
+			2020 => Some(Self::fixed(HolidayId::JP_MARINE_DAY, year, 7, 23, local_name, name)),
+			2021 => Some(Self::fixed(HolidayId::JP_MARINE_DAY, year, 7, 22, local_name, name)),
+			1996.. => Some(Self::happy_monday(
+				HolidayId::JP_MARINE_DAY,
+				year,
+				7,
+				Occurrence::Third,
+				20,
+				2003,
+				local_name,
+				name,
+			)),
+			_ => None,
+		}
+	}
+
+	fn get_mountain_day(year: i32) -> Option<PublicHoliday> {
+		let local_name = "山の日";
+		let name = "Mountain Day";
+
+		match year {
+			2020 => Some(Self::fixed(HolidayId::JP_MOUNTAIN_DAY, year, 8, 10, local_name, name)),
+			2021 => Some(Self::fixed(HolidayId::JP_MOUNTAIN_DAY, year, 8, 8, local_name, name)),
+			2016.. => Some(Self::fixed(HolidayId::JP_MOUNTAIN_DAY, year, 8, 11, local_name, name)),
+			_ => None,
+		}
+	}
+
+	fn get_sports_day(year: i32) -> Option<PublicHoliday> {
+		let (local_name, name) = if year >= 2020 {
+			("スポーツの日", "Sports Day")
+		} else {
+			("体育の日", "Health and Sports Day")
+		};
+
+		match year {
+			2020 => Some(Self::fixed(HolidayId::JP_SPORTS_DAY, year, 7, 24, local_name, name)),
+			2021 => Some(Self::fixed(HolidayId::JP_SPORTS_DAY, year, 7, 23, local_name, name)),
+			1966.. => Some(Self::happy_monday(
+				HolidayId::JP_SPORTS_DAY,
+				year,
+				10,
+				Occurrence::Second,
+				10,
+				2000,
+				local_name,
+				name,
+			)),
+			_ => None,
+		}
+	}
+
+	fn get_enthronement_days(year: i32) -> Vec<PublicHoliday> {
+		if year == 2019 {
+			vec![
+				Self::fixed(
+					HolidayId::JP_ENTHRONEMENT_DAY,
+					year,
+					5,
+					1,
+					"天皇の即位の日",
+					"Enthronement Day",
+				),
+				Self::fixed(
+					HolidayId::JP_ENTHRONEMENT_CEREMONY_DAY,
+					year,
+					10,
+					22,
+					"即位礼正殿の儀",
+					"Enthronement Ceremony Day",
+				),
+			]
+		} else {
+			Vec::new()
+		}
+	}
+
+	/// A day between two holidays is a holiday as well since 1986
+	fn get_citizens_holidays(holidays: &[PublicHoliday]) -> Vec<PublicHoliday> {
+		let mut citizens_holidays = Vec::new();
+
+		for holiday in holidays.iter().filter(|holiday| holiday.date.year() >= 1986) {
+			let day = holiday.date + Duration::days(1);
+			let next_day = holiday.date + Duration::days(2);
+
+			if day.weekday() != Weekday::Sun
+				&& !Self::contains(holidays, day)
+				&& Self::contains(holidays, next_day)
+				&& !Self::contains(&citizens_holidays, day)
+			{
+				citizens_holidays.push(Self::non_fixed(
+					HolidayId::JP_CITIZENS_HOLIDAY,
+					day,
+					"国民の休日",
+					"Citizens' Holiday",
+				));
+			}
+		}
+
+		citizens_holidays
+	}
+
+	/// A holiday on a sunday is substituted by the next monday since the 12th april 1973. Since 2007 it is
+	/// substituted by the next day which is no holiday.
+	fn get_substitute_holidays(holidays: &[PublicHoliday]) -> Vec<PublicHoliday> {
+		let mut substitute_holidays = Vec::new();
+		let introduction = NaiveDate::from_ymd_opt(1973, 4, 12).unwrap();
+
+		for holiday in holidays
+			.iter()
+			.filter(|holiday| holiday.date.weekday() == Weekday::Sun && holiday.date >= introduction)
+		{
+			let mut day = holiday.date + Duration::days(1);
+			if day.year() >= 2007 {
+				while Self::contains(holidays, day) || Self::contains(&substitute_holidays, day) {
+					day += Duration::days(1);
+				}
+			} else if Self::contains(holidays, day) {
+				continue;
+			}
+
+			substitute_holidays.push(Self::non_fixed(
+				HolidayId::SUBSTITUTE_HOLIDAY,
+				day,
+				"振替休日",
+				"Substitute Holiday",
+			));
+		}
+
+		substitute_holidays
+	}
+
+	/// Holiday on a weekday of the month (happy monday system) since the given year and on a fixed day before
+	#[allow(clippy::too_many_arguments)]
+	fn happy_monday(
+		id: HolidayId,
+		year: i32,
+		month: u32,
+		occurrence: Occurrence,
+		day: u32,
+		since: i32,
+		local_name: &str,
+		name: &str,
+	) -> PublicHoliday {
+		if year >= since {
+			Self::non_fixed(
+				id,
+				DateSystem::find_day(year, month, Weekday::Mon, occurrence).unwrap(),
+				local_name,
+				name,
+			)
+		} else {
+			Self::fixed(id, year, month, day, local_name, name)
+		}
+	}
+
+	fn equinox(id: HolidayId, year: i32, season: Season, local_name: &str, name: &str) -> PublicHoliday {
+		let offset = FixedOffset::east_opt(Self::UTC_OFFSET_SECONDS).unwrap();
+
+		Self::non_fixed(id, DateSystem::find_season(year, season, offset), local_name, name)
+	}
+
+	fn fixed(id: HolidayId, year: i32, month: u32, day: u32, local_name: &str, name: &str) -> PublicHoliday {
+		PublicHoliday::new_fixed(
+			id,
+			year,
+			month,
+			day,
+			local_name,
+			name,
+			Self::COUNTRYCODE,
+			None,
+			None,
+			PublicHolidayType::Public,
+		)
+	}
+
+	fn non_fixed(id: HolidayId, date: NaiveDate, local_name: &str, name: &str) -> PublicHoliday {
+		PublicHoliday::new_non_fixed(
+			id,
+			date,
+			local_name,
+			name,
+			Self::COUNTRYCODE,
+			None,
+			None,
+			PublicHolidayType::Public,
+		)
+	}
+
+	fn contains(holidays: &[PublicHoliday], date: NaiveDate) -> bool {
+		holidays.iter().any(|holiday| holiday.date == date)
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::JapanHolidayProvider;
+
+	#[test]
+	fn test_get_holidays() {
+		let result = JapanHolidayProvider::get_holidays(2024);
+		assert_eq!(result.len(), 21);
+		assert_eq!(result[1].to_string(), "2024-01-08 Coming of Age Day");
+		assert_eq!(result[3].to_string(), "2024-02-12 Substitute Holiday");
+		assert_eq!(result[5].to_string(), "2024-03-20 Vernal Equinox Day");
+		assert_eq!(result[15].to_string(), "2024-09-22 Autumnal Equinox Day");
+		assert_eq!(result[16].to_string(), "2024-09-23 Substitute Holiday");
+	}
+
+	#[test]
+	fn test_get_holidays_citizens_holiday() {
+		let result = JapanHolidayProvider::get_holidays(2026);
+		assert!(result
+			.iter()
+			.any(|holiday| holiday.to_string() == "2026-09-22 Citizens' Holiday"));
+	}
+
+	#[test]
+	fn test_get_holidays_enthronement() {
+		let result = JapanHolidayProvider::get_holidays(2019);
+		let golden_week: Vec<String> = result
+			.iter()
+			.filter(|holiday| holiday.date.format("%m").to_string() == "05" || holiday.name == "Shōwa Day")
+			.map(|holiday| holiday.to_string())
+			.collect();
+		assert_eq!(
+			golden_week,
+			vec![
+				"2019-04-29 Shōwa Day",
+				"2019-05-01 Enthronement Day",
+				"2019-05-02 Citizens' Holiday",
+				"2019-05-03 Constitution Memorial Day",
+				"2019-05-04 Greenery Day",
+				"2019-05-05 Children's Day",
+				"2019-05-06 Substitute Holiday",
+			]
+		);
+	}
+}
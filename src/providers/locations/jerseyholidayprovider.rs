@@ -0,0 +1,27 @@
+use crate::{
+	providers::base::channelislandsprovider::ChannelIslandsProvider,
+	types::{countrycode::CountryCode, holidayid::HolidayId, publicholiday::PublicHoliday},
+};
+
+pub struct JerseyHolidayProvider {}
+
+impl JerseyHolidayProvider {
+	const COUNTRYCODE: CountryCode = CountryCode::JE;
+
+	/// Gets the bank holidays of jersey, which are the bank holidays of england and the liberation day
+	pub fn get_holidays(year: i32) -> Vec<PublicHoliday> {
+		ChannelIslandsProvider::get_holidays(HolidayId::JE_LIBERATION_DAY, year, Self::COUNTRYCODE)
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::JerseyHolidayProvider;
+
+	#[test]
+	fn test_get_holidays() {
+		let result = JerseyHolidayProvider::get_holidays(2024);
+		assert_eq!(result.len(), 9);
+		assert_eq!(result[4].to_string(), "2024-05-09 Liberation Day");
+	}
+}
@@ -1,7 +1,15 @@
+pub mod chinaholidayprovider;
 pub mod germanholidayprovider;
 pub mod greeceholidayprovider;
+pub mod guernseyholidayprovider;
+pub mod isleofmanholidayprovider;
+pub mod israelholidayprovider;
+pub mod japanholidayprovider;
+pub mod jerseyholidayprovider;
 pub mod polandholidayprovider;
 pub mod russiaholidayprovider;
+pub mod saudiarabiaholidayprovider;
+pub mod southkoreaholidayprovider;
 pub mod ukrainholidayprovider;
 pub mod unitedkingdomholidayprovider;
 pub mod unitedstatesholidayprovider;
@@ -1,6 +1,9 @@
 use crate::{
 	providers::base::catholicprovider::CatholicProvider,
-	types::{countrycode::CountryCode, publicholiday::PublicHoliday, publicholidaytype::PublicHolidayType},
+	types::{
+		countrycode::CountryCode, holidayid::HolidayId, publicholiday::PublicHoliday,
+		publicholidaytype::PublicHolidayType,
+	},
 };
 
 pub struct PolandHolidayProvider {}
@@ -16,6 +19,7 @@ impl PolandHolidayProvider {
 	pub fn get_holidays(year: i32) -> Vec<PublicHoliday> {
 		let mut holidays = vec![
 			PublicHoliday::new_fixed(
+				HolidayId::NEW_YEARS_DAY,
 				year,
 				1,
 				1,
@@ -27,6 +31,7 @@ impl PolandHolidayProvider {
 				PublicHolidayType::Public,
 			),
 			PublicHoliday::new_fixed(
+				HolidayId::EPIPHANY,
 				year,
 				1,
 				6,
@@ -40,6 +45,7 @@ impl PolandHolidayProvider {
 			CatholicProvider::easter_sunday("Wielkanoc", year, Self::COUNTRYCODE),
 			CatholicProvider::easter_monday("Drugi Dzień Wielkanocy", year, Self::COUNTRYCODE),
 			PublicHoliday::new_fixed(
+				HolidayId::LABOUR_DAY,
 				year,
 				5,
 				1,
@@ -51,6 +57,7 @@ impl PolandHolidayProvider {
 				PublicHolidayType::Public,
 			),
 			PublicHoliday::new_fixed(
+				HolidayId::PL_CONSTITUTION_DAY,
 				year,
 				5,
 				3,
@@ -64,6 +71,7 @@ impl PolandHolidayProvider {
 			CatholicProvider::pentecost("Zielone Świątki", year, Self::COUNTRYCODE),
 			CatholicProvider::corpus_christi("Boże Ciało", year, Self::COUNTRYCODE),
 			PublicHoliday::new_fixed(
+				HolidayId::ASSUMPTION_DAY,
 				year,
 				8,
 				15,
@@ -75,6 +83,7 @@ impl PolandHolidayProvider {
 				PublicHolidayType::Public,
 			),
 			PublicHoliday::new_fixed(
+				HolidayId::ALL_SAINTS_DAY,
 				year,
 				11,
 				1,
@@ -86,6 +95,7 @@ impl PolandHolidayProvider {
 				PublicHolidayType::Public,
 			),
 			PublicHoliday::new_fixed(
+				HolidayId::PL_INDEPENDENCE_DAY,
 				year,
 				11,
 				11,
@@ -97,6 +107,7 @@ impl PolandHolidayProvider {
 				PublicHolidayType::Public,
 			),
 			PublicHoliday::new_fixed(
+				HolidayId::CHRISTMAS_DAY,
 				year,
 				12,
 				25,
@@ -108,6 +119,7 @@ impl PolandHolidayProvider {
 				PublicHolidayType::Public,
 			),
 			PublicHoliday::new_fixed(
+				HolidayId::ST_STEPHENS_DAY,
 				year,
 				12,
 				26,
@@ -120,21 +132,7 @@ impl PolandHolidayProvider {
 			),
 		];
 
-		if year == 2018 {
-			//100th anniversary
-			holidays.push(PublicHoliday::new_fixed(
-				year,
-				11,
-				12,
-				"Narodowe Święto Niepodległości",
-				"Independence Day",
-				Self::COUNTRYCODE,
-				None,
-				None,
-				PublicHolidayType::Public,
-			));
-		}
-		holidays.sort_by(|a, b| a.date.cmp(&b.date));
+		holidays.sort_by_key(|a| a.date);
 
 		holidays
 	}
@@ -1,4 +1,12 @@
-use crate::types::{countrycode::CountryCode, publicholiday::PublicHoliday, publicholidaytype::PublicHolidayType};
+use chrono::{Datelike, NaiveDate, Weekday};
+
+use crate::{
+	providers::base::orthodoxprovider::OrthodoxProvider,
+	types::{
+		countrycode::CountryCode, holidayid::HolidayId, publicholiday::PublicHoliday,
+		publicholidaytype::PublicHolidayType, workingdayoverride::WorkingDayOverride,
+	},
+};
 
 pub struct RussiaHolidayProvider {}
 
@@ -13,6 +21,7 @@ impl RussiaHolidayProvider {
 	pub fn get_holidays(year: i32) -> Vec<PublicHoliday> {
 		let mut holidays = vec![
 			PublicHoliday::new_fixed(
+				HolidayId::NEW_YEARS_DAY,
 				year,
 				1,
 				1,
@@ -24,6 +33,7 @@ impl RussiaHolidayProvider {
 				PublicHolidayType::Public,
 			),
 			PublicHoliday::new_fixed(
+				HolidayId::RU_NEW_YEAR_HOLIDAY,
 				year,
 				1,
 				2,
@@ -35,6 +45,7 @@ impl RussiaHolidayProvider {
 				PublicHolidayType::Public,
 			),
 			PublicHoliday::new_fixed(
+				HolidayId::RU_NEW_YEAR_HOLIDAY,
 				year,
 				1,
 				3,
@@ -46,6 +57,7 @@ impl RussiaHolidayProvider {
 				PublicHolidayType::Public,
 			),
 			PublicHoliday::new_fixed(
+				HolidayId::RU_NEW_YEAR_HOLIDAY,
 				year,
 				1,
 				4,
@@ -57,6 +69,7 @@ impl RussiaHolidayProvider {
 				PublicHolidayType::Public,
 			),
 			PublicHoliday::new_fixed(
+				HolidayId::RU_NEW_YEAR_HOLIDAY,
 				year,
 				1,
 				5,
@@ -68,6 +81,7 @@ impl RussiaHolidayProvider {
 				PublicHolidayType::Public,
 			),
 			PublicHoliday::new_fixed(
+				HolidayId::RU_NEW_YEAR_HOLIDAY,
 				year,
 				1,
 				6,
@@ -79,17 +93,19 @@ impl RussiaHolidayProvider {
 				PublicHolidayType::Public,
 			),
 			PublicHoliday::new_fixed(
+				HolidayId::RU_NEW_YEAR_HOLIDAY,
 				year,
 				1,
-				7,
-				"Рождество Христово",
-				"Orthodox Christmas Day",
+				8,
+				"Новогодние каникулы",
+				"New Year holiday",
 				Self::COUNTRYCODE,
-				None,
+				Some(2013),
 				None,
 				PublicHolidayType::Public,
 			),
 			PublicHoliday::new_fixed(
+				HolidayId::RU_DEFENDER_OF_THE_FATHERLAND_DAY,
 				year,
 				2,
 				23,
@@ -101,6 +117,7 @@ impl RussiaHolidayProvider {
 				PublicHolidayType::Public,
 			),
 			PublicHoliday::new_fixed(
+				HolidayId::INTERNATIONAL_WOMENS_DAY,
 				year,
 				3,
 				8,
@@ -112,6 +129,7 @@ impl RussiaHolidayProvider {
 				PublicHolidayType::Public,
 			),
 			PublicHoliday::new_fixed(
+				HolidayId::LABOUR_DAY,
 				year,
 				5,
 				1,
@@ -123,6 +141,7 @@ impl RussiaHolidayProvider {
 				PublicHolidayType::Public,
 			),
 			PublicHoliday::new_fixed(
+				HolidayId::VICTORY_DAY,
 				year,
 				5,
 				9,
@@ -134,6 +153,7 @@ impl RussiaHolidayProvider {
 				PublicHolidayType::Public,
 			),
 			PublicHoliday::new_fixed(
+				HolidayId::RU_RUSSIA_DAY,
 				year,
 				6,
 				12,
@@ -145,6 +165,7 @@ impl RussiaHolidayProvider {
 				PublicHolidayType::Public,
 			),
 			PublicHoliday::new_fixed(
+				HolidayId::RU_UNITY_DAY,
 				year,
 				11,
 				4,
@@ -157,21 +178,179 @@ impl RussiaHolidayProvider {
 			),
 		];
 
-		holidays.sort_by(|a, b| a.date.cmp(&b.date));
+		if let Some(christmas_day) = OrthodoxProvider::christmas_day(
+			HolidayId::ORTHODOX_CHRISTMAS_DAY,
+			"Рождество Христово",
+			"Orthodox Christmas Day",
+			year,
+			Self::COUNTRYCODE,
+		) {
+			holidays.push(christmas_day);
+		}
+
+		holidays.extend(Self::get_transferred_days_off(year));
+		if year >= 2013 {
+			let shifted_days_off = Self::get_shifted_days_off(&holidays);
+			holidays.extend(shifted_days_off);
+		}
+
+		holidays.sort_by_key(|a| a.date);
 
 		holidays
 	}
+
+	/// Gets the days off which are transferred from the new year holidays on a weekend to another day of the year by
+	/// the decree of the government. The decrees are published every year and are only known for 2021 to 2025.
+	fn get_transferred_days_off(year: i32) -> Vec<PublicHoliday> {
+		let days: &[(u32, u32)] = match year {
+			2021 => &[(11, 5), (12, 31)],
+			2022 => &[(5, 3), (5, 10)],
+			2023 => &[(2, 24), (5, 8)],
+			2024 => &[(5, 10), (12, 31)],
+			2025 => &[(5, 2), (12, 31)],
+			_ => &[],
+		};
+
+		days.iter()
+			.map(|(month, day)| {
+				PublicHoliday::new_non_fixed(
+					HolidayId::TRANSFERRED_DAY_OFF,
+					NaiveDate::from_ymd_opt(year, *month, *day).unwrap(),
+					"Перенесённый выходной день",
+					"Transferred day off",
+					Self::COUNTRYCODE,
+					None,
+					None,
+					PublicHolidayType::Public,
+				)
+			})
+			.collect()
+	}
+
+	/// Gets the days off which are shifted from a weekend to the next working day, because a holiday falls on the
+	/// weekend. Since 2013 this doesn't apply to the new year holidays, whose days off are transferred by the decree.
+	fn get_shifted_days_off(holidays: &[PublicHoliday]) -> Vec<PublicHoliday> {
+		let mut days_off: Vec<NaiveDate> = Vec::new();
+		for holiday in holidays.iter().filter(|holiday| {
+			holiday.date.month() != 1 && matches!(holiday.date.weekday(), Weekday::Sat | Weekday::Sun)
+		}) {
+			let mut date = holiday.date.succ_opt().unwrap();
+			while matches!(date.weekday(), Weekday::Sat | Weekday::Sun)
+				|| holidays.iter().any(|holiday| holiday.date == date)
+				|| days_off.contains(&date)
+			{
+				date = date.succ_opt().unwrap();
+			}
+			days_off.push(date);
+		}
+
+		days_off
+			.into_iter()
+			.map(|date| {
+				PublicHoliday::new_non_fixed(
+					HolidayId::TRANSFERRED_DAY_OFF,
+					date,
+					"Перенесённый выходной день",
+					"Transferred day off",
+					Self::COUNTRYCODE,
+					None,
+					None,
+					PublicHolidayType::Public,
+				)
+			})
+			.collect()
+	}
+
+	/// Gets the saturdays which are working days by the decree of the government and the days off they are
+	/// transferred to. The decrees are published every year and are only known for 2021 to 2025, so there are no
+	/// overrides for the other years.
+	pub fn get_working_day_overrides(year: i32) -> Vec<WorkingDayOverride> {
+		let transfers: &[(u32, u32, u32, u32)] = match year {
+			2021 => &[(2, 20, 2, 22)],
+			2022 => &[(3, 5, 3, 7)],
+			2024 => &[(4, 27, 4, 29), (11, 2, 4, 30), (12, 28, 12, 30)],
+			2025 => &[(11, 1, 11, 3)],
+			_ => &[],
+		};
+
+		transfers
+			.iter()
+			.map(|(month, day, transferred_month, transferred_day)| {
+				WorkingDayOverride::new(
+					NaiveDate::from_ymd_opt(year, *month, *day).unwrap(),
+					"Рабочий день",
+					"Working day",
+					Self::COUNTRYCODE,
+					NaiveDate::from_ymd_opt(year, *transferred_month, *transferred_day),
+				)
+			})
+			.collect()
+	}
 }
 
 #[cfg(test)]
 mod tests {
+	use chrono::{Datelike, NaiveDate};
+
 	use super::RussiaHolidayProvider;
+	use crate::{holidayprovider::HolidayProvider, types::countrycode::CountryCode};
 
 	#[test]
 	fn test_get_holidays() {
 		let result = RussiaHolidayProvider::get_holidays(2022);
-		assert_eq!(result.len(), 13);
+		assert_eq!(result.len(), 18);
 		assert_eq!(result.is_empty(), false);
 		assert_eq!(result[5].local_name, "Новогодние каникулы");
 	}
+
+	#[test]
+	fn test_get_transferred_days_off() {
+		let result: Vec<String> = RussiaHolidayProvider::get_holidays(2024)
+			.iter()
+			.filter(|holiday| holiday.name == "Transferred day off")
+			.map(|holiday| holiday.date.to_string())
+			.collect();
+		assert_eq!(result, vec!["2024-05-10", "2024-12-31"]);
+		assert_eq!(RussiaHolidayProvider::get_holidays(2025).len(), 18);
+		assert_eq!(RussiaHolidayProvider::get_holidays(2023).len(), 17);
+	}
+
+	#[test]
+	fn test_get_shifted_days_off() {
+		let result: Vec<String> = RussiaHolidayProvider::get_holidays(2022)
+			.iter()
+			.filter(|holiday| holiday.name == "Transferred day off")
+			.map(|holiday| holiday.date.to_string())
+			.collect();
+		assert_eq!(result, vec!["2022-05-02", "2022-05-03", "2022-05-10", "2022-06-13"]);
+	}
+
+	#[test]
+	fn test_business_days_of_production_calendar() {
+		for (year, business_days) in [(2021, 247), (2022, 247), (2023, 247), (2024, 248), (2025, 247)] {
+			let result = NaiveDate::from_ymd_opt(year, 1, 1)
+				.unwrap()
+				.iter_days()
+				.take_while(|date| date.year() == year)
+				.filter(|date| HolidayProvider::is_business_day(*date, CountryCode::RU, None))
+				.count();
+			assert_eq!(result, business_days, "{year}");
+		}
+	}
+
+	#[test]
+	fn test_get_working_day_overrides() {
+		let result = RussiaHolidayProvider::get_working_day_overrides(2024);
+		assert_eq!(result.len(), 3);
+		assert_eq!(result[1].to_string(), "2024-11-02 Working day");
+		assert_eq!(result[1].transferred_to.unwrap().to_string(), "2024-04-30");
+		assert!(RussiaHolidayProvider::get_working_day_overrides(2023).is_empty());
+	}
+
+	#[test]
+	fn test_get_holidays_before_calendar_reform() {
+		let result = RussiaHolidayProvider::get_holidays(1900);
+		assert_eq!(result[0].to_string(), "1900-01-06 Orthodox Christmas Day");
+		assert_eq!(result[1].to_string(), "1900-01-13 New Year's Day");
+	}
 }
@@ -0,0 +1,91 @@
+use crate::{
+	calendars::hijri::HijriCalendar,
+	providers::base::islamicprovider::IslamicProvider,
+	types::{
+		countrycode::CountryCode, holidayid::HolidayId, publicholiday::PublicHoliday,
+		publicholidaytype::PublicHolidayType,
+	},
+};
+
+pub struct SaudiArabiaHolidayProvider {}
+
+impl SaudiArabiaHolidayProvider {
+	const COUNTRYCODE: CountryCode = CountryCode::SA;
+	const CALENDAR: HijriCalendar = HijriCalendar::UmmAlQura;
+
+	pub fn get_holidays(year: i32) -> Vec<PublicHoliday> {
+		let mut holidays = Vec::new();
+
+		if year >= 2022 {
+			holidays.push(PublicHoliday::new_fixed(
+				HolidayId::SA_FOUNDING_DAY,
+				year,
+				2,
+				22,
+				"يوم التأسيس",
+				"Founding Day",
+				Self::COUNTRYCODE,
+				Some(2022),
+				None,
+				PublicHolidayType::Public,
+			));
+		}
+
+		if year >= 2005 {
+			holidays.push(PublicHoliday::new_fixed(
+				HolidayId::SA_NATIONAL_DAY,
+				year,
+				9,
+				23,
+				"اليوم الوطني",
+				"National Day",
+				Self::COUNTRYCODE,
+				Some(2005),
+				None,
+				PublicHolidayType::Public,
+			));
+		}
+
+		holidays.extend(IslamicProvider::eid_al_fitr(
+			Self::CALENDAR,
+			"عيد الفطر",
+			3,
+			year,
+			Self::COUNTRYCODE,
+		));
+		holidays.extend(IslamicProvider::arafat_day(
+			Self::CALENDAR,
+			"يوم عرفة",
+			year,
+			Self::COUNTRYCODE,
+		));
+		holidays.extend(IslamicProvider::eid_al_adha(
+			Self::CALENDAR,
+			"عيد الأضحى",
+			3,
+			year,
+			Self::COUNTRYCODE,
+		));
+
+		holidays.sort_by_key(|a| a.date);
+
+		holidays
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::SaudiArabiaHolidayProvider;
+
+	#[test]
+	fn test_get_holidays() {
+		let result = SaudiArabiaHolidayProvider::get_holidays(2024);
+		assert_eq!(result.len(), 9);
+		assert_eq!(result[0].to_string(), "2024-02-22 Founding Day");
+		assert_eq!(result[1].to_string(), "2024-04-10 Eid al-Fitr");
+		assert_eq!(result[4].to_string(), "2024-06-15 Arafat Day");
+		assert_eq!(result[5].to_string(), "2024-06-16 Eid al-Adha");
+		assert!(result[1].estimated);
+		assert!(!result[8].estimated);
+	}
+}
@@ -0,0 +1,187 @@
+use crate::{
+	calendars::lunisolar::LunisolarCalendar,
+	providers::base::lunisolarprovider::LunisolarProvider,
+	types::{
+		countrycode::CountryCode, holidayid::HolidayId, publicholiday::PublicHoliday,
+		publicholidaytype::PublicHolidayType,
+	},
+};
+
+pub struct SouthKoreaHolidayProvider {}
+
+impl SouthKoreaHolidayProvider {
+	const COUNTRYCODE: CountryCode = CountryCode::KR;
+	const CALENDAR: LunisolarCalendar = LunisolarCalendar::Korea;
+
+	pub fn get_holidays(year: i32) -> Vec<PublicHoliday> {
+		let mut holidays = vec![
+			PublicHoliday::new_fixed(
+				HolidayId::NEW_YEARS_DAY,
+				year,
+				1,
+				1,
+				"신정",
+				"New Year's Day",
+				Self::COUNTRYCODE,
+				None,
+				None,
+				PublicHolidayType::Public,
+			),
+			PublicHoliday::new_fixed(
+				HolidayId::KR_INDEPENDENCE_MOVEMENT_DAY,
+				year,
+				3,
+				1,
+				"삼일절",
+				"Independence Movement Day",
+				Self::COUNTRYCODE,
+				None,
+				None,
+				PublicHolidayType::Public,
+			),
+			PublicHoliday::new_fixed(
+				HolidayId::KR_LIBERATION_DAY,
+				year,
+				8,
+				15,
+				"광복절",
+				"Liberation Day",
+				Self::COUNTRYCODE,
+				None,
+				None,
+				PublicHolidayType::Public,
+			),
+			PublicHoliday::new_fixed(
+				HolidayId::KR_NATIONAL_FOUNDATION_DAY,
+				year,
+				10,
+				3,
+				"개천절",
+				"National Foundation Day",
+				Self::COUNTRYCODE,
+				None,
+				None,
+				PublicHolidayType::Public,
+			),
+			PublicHoliday::new_fixed(
+				HolidayId::CHRISTMAS_DAY,
+				year,
+				12,
+				25,
+				"기독탄신일",
+				"Christmas Day",
+				Self::COUNTRYCODE,
+				None,
+				None,
+				PublicHolidayType::Public,
+			),
+		];
+
+		if year >= 1956 {
+			holidays.push(PublicHoliday::new_fixed(
+				HolidayId::KR_MEMORIAL_DAY,
+				year,
+				6,
+				6,
+				"현충일",
+				"Memorial Day",
+				Self::COUNTRYCODE,
+				Some(1956),
+				None,
+				PublicHolidayType::Public,
+			));
+		}
+
+		if year >= 1975 {
+			holidays.push(PublicHoliday::new_fixed(
+				HolidayId::CHILDRENS_DAY,
+				year,
+				5,
+				5,
+				"어린이날",
+				"Children's Day",
+				Self::COUNTRYCODE,
+				Some(1975),
+				None,
+				PublicHolidayType::Public,
+			));
+			holidays.extend(LunisolarProvider::lunar_holiday(
+				HolidayId::BUDDHAS_BIRTHDAY,
+				Self::CALENDAR,
+				4,
+				8,
+				0,
+				"부처님 오신 날",
+				"Buddha's Birthday",
+				year,
+				Self::COUNTRYCODE,
+			));
+		}
+
+		// hangul day was no public holiday from 1991 until 2012
+		if !(1991..2013).contains(&year) {
+			holidays.push(PublicHoliday::new_fixed(
+				HolidayId::KR_HANGUL_DAY,
+				year,
+				10,
+				9,
+				"한글날",
+				"Hangul Day",
+				Self::COUNTRYCODE,
+				None,
+				None,
+				PublicHolidayType::Public,
+			));
+		}
+
+		// seollal and chuseok last three days since 1989
+		let offsets = if year >= 1989 { -1..=1 } else { 0..=0 };
+		if year >= 1985 {
+			holidays.extend(offsets.clone().filter_map(|offset_days| {
+				LunisolarProvider::lunar_holiday(
+					HolidayId::LUNAR_NEW_YEAR,
+					Self::CALENDAR,
+					1,
+					1,
+					offset_days,
+					"설날",
+					"Seollal",
+					year,
+					Self::COUNTRYCODE,
+				)
+			}));
+		}
+		holidays.extend(offsets.filter_map(|offset_days| {
+			LunisolarProvider::lunar_holiday(
+				HolidayId::MID_AUTUMN_FESTIVAL,
+				Self::CALENDAR,
+				8,
+				15,
+				offset_days,
+				"추석",
+				"Chuseok",
+				year,
+				Self::COUNTRYCODE,
+			)
+		}));
+
+		holidays.sort_by_key(|a| a.date);
+
+		holidays
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::SouthKoreaHolidayProvider;
+
+	#[test]
+	fn test_get_holidays() {
+		let result = SouthKoreaHolidayProvider::get_holidays(2024);
+		assert_eq!(result.len(), 15);
+		assert_eq!(result[1].to_string(), "2024-02-09 Seollal");
+		assert_eq!(result[3].to_string(), "2024-02-11 Seollal");
+		assert_eq!(result[6].to_string(), "2024-05-15 Buddha's Birthday");
+		assert_eq!(result[9].to_string(), "2024-09-16 Chuseok");
+	}
+}
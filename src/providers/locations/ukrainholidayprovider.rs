@@ -1,6 +1,11 @@
+use chrono::NaiveDate;
+
 use crate::{
 	providers::base::orthodoxprovider::OrthodoxProvider,
-	types::{countrycode::CountryCode, publicholiday::PublicHoliday, publicholidaytype::PublicHolidayType},
+	types::{
+		countrycode::CountryCode, holidayid::HolidayId, publicholiday::PublicHoliday,
+		publicholidaytype::PublicHolidayType, suspensionperiod::SuspensionPeriod,
+	},
 };
 
 pub struct UkrainHolidayProvider {}
@@ -11,6 +16,7 @@ impl UkrainHolidayProvider {
 	pub fn get_holidays(year: i32) -> Vec<PublicHoliday> {
 		let mut holidays = vec![
 			PublicHoliday::new_fixed(
+				HolidayId::NEW_YEARS_DAY,
 				year,
 				1,
 				1,
@@ -22,17 +28,7 @@ impl UkrainHolidayProvider {
 				PublicHolidayType::Public,
 			),
 			PublicHoliday::new_fixed(
-				year,
-				1,
-				7,
-				"Різдво",
-				"(Julian) Christmas",
-				Self::COUNTRY_CODE,
-				None,
-				None,
-				PublicHolidayType::Public,
-			),
-			PublicHoliday::new_fixed(
+				HolidayId::INTERNATIONAL_WOMENS_DAY,
 				year,
 				3,
 				8,
@@ -46,6 +42,7 @@ impl UkrainHolidayProvider {
 			OrthodoxProvider::easter_sunday("Великдень", year, Self::COUNTRY_CODE),
 			OrthodoxProvider::pentecost("Трійця", year, Self::COUNTRY_CODE),
 			PublicHoliday::new_fixed(
+				HolidayId::LABOUR_DAY,
 				year,
 				5,
 				1,
@@ -57,17 +54,7 @@ impl UkrainHolidayProvider {
 				PublicHolidayType::Public,
 			),
 			PublicHoliday::new_fixed(
-				year,
-				5,
-				9,
-				"День перемоги над нацизмом у Другій світовій війні",
-				"Victory day over Nazism in World War II",
-				Self::COUNTRY_CODE,
-				None,
-				None,
-				PublicHolidayType::Public,
-			),
-			PublicHoliday::new_fixed(
+				HolidayId::UA_CONSTITUTION_DAY,
 				year,
 				6,
 				28,
@@ -79,6 +66,7 @@ impl UkrainHolidayProvider {
 				PublicHolidayType::Public,
 			),
 			PublicHoliday::new_fixed(
+				HolidayId::UA_INDEPENDENCE_DAY,
 				year,
 				8,
 				24,
@@ -89,38 +77,106 @@ impl UkrainHolidayProvider {
 				None,
 				PublicHolidayType::Public,
 			),
-			PublicHoliday::new_fixed(
+		];
+
+		// the day of remembrance and victory was moved to may 8 in 2023, when may 9 had already passed
+		if year < 2024 {
+			holidays.push(PublicHoliday::new_fixed(
+				HolidayId::UA_VICTORY_OVER_NAZISM_DAY,
+				year,
+				5,
+				9,
+				"День перемоги над нацизмом у Другій світовій війні",
+				"Victory day over Nazism in World War II",
+				Self::COUNTRY_CODE,
+				None,
+				None,
+				PublicHolidayType::Public,
+			));
+		} else {
+			holidays.push(PublicHoliday::new_fixed(
+				HolidayId::UA_VICTORY_OVER_NAZISM_DAY,
+				year,
+				5,
+				8,
+				"День пам'яті та перемоги над нацизмом у Другій світовій війні 1939 – 1945 років",
+				"Victory day over Nazism in World War II",
+				Self::COUNTRY_CODE,
+				Some(2024),
+				None,
+				PublicHolidayType::Public,
+			));
+		}
+
+		if year >= 2015 {
+			// moved from the feast of the intercession on october 14 to october 1 in 2023
+			let day = if year < 2023 { 14 } else { 1 };
+			holidays.push(PublicHoliday::new_fixed(
+				HolidayId::UA_DEFENDERS_DAY,
 				year,
 				10,
-				14,
+				day,
 				"День захисника України",
 				"Defender of Ukraine Day",
 				Self::COUNTRY_CODE,
-				None,
+				Some(2015),
 				None,
 				PublicHolidayType::Public,
-			),
-			PublicHoliday::new_fixed(
+			));
+		}
+
+		if year >= 2017 {
+			holidays.push(PublicHoliday::new_fixed(
+				HolidayId::CHRISTMAS_DAY,
 				year,
 				12,
 				25,
 				"Різдво",
 				"(Gregorian and Revised Julian) Christmas",
 				Self::COUNTRY_CODE,
-				None,
+				Some(2017),
 				None,
 				PublicHolidayType::Public,
-			),
-		];
+			));
+		}
 
-		holidays.sort_by(|a, b| a.date.cmp(&b.date));
+		// christmas is only celebrated on december 25 since 2023, so january 7 was a holiday for the last time in 2023
+		if year <= 2023 {
+			if let Some(christmas_day) = OrthodoxProvider::christmas_day(
+				HolidayId::ORTHODOX_CHRISTMAS_DAY,
+				"Різдво",
+				"(Julian) Christmas",
+				year,
+				Self::COUNTRY_CODE,
+			) {
+				holidays.push(christmas_day);
+			}
+		}
+
+		holidays.sort_by_key(|a| a.date);
 
 		holidays
 	}
+
+	/// Gets the periods in which the holidays don't give a day off. Under martial law, which was imposed on february
+	/// 24, 2022, the holidays are working days since the law on labour relations under martial law came into force.
+	pub fn get_suspension_periods() -> Vec<SuspensionPeriod> {
+		vec![SuspensionPeriod::new(
+			NaiveDate::from_ymd_opt(2022, 3, 24).unwrap(),
+			None,
+			"Воєнний стан",
+			"Martial law",
+			Self::COUNTRY_CODE,
+		)]
+	}
 }
 
 #[cfg(test)]
 mod tests {
+	use chrono::NaiveDate;
+
+	use crate::{holidayprovider::HolidayProvider, types::countrycode::CountryCode};
+
 	use super::UkrainHolidayProvider;
 
 	#[test]
@@ -133,4 +189,50 @@ mod tests {
 			"День перемоги над нацизмом у Другій світовій війні"
 		);
 	}
+
+	#[test]
+	fn test_rule_changes() {
+		let dates = |year: i32| -> Vec<String> {
+			UkrainHolidayProvider::get_holidays(year)
+				.iter()
+				.map(|holiday| holiday.date.format("%m-%d").to_string())
+				.collect()
+		};
+		let result = dates(2016);
+		assert!(result.contains(&"01-07".to_string()));
+		assert!(!result.contains(&"12-25".to_string()));
+		let result = dates(2023);
+		assert!(result.contains(&"01-07".to_string()));
+		assert!(result.contains(&"05-09".to_string()));
+		assert!(result.contains(&"10-01".to_string()));
+		let result = dates(2024);
+		assert_eq!(result.len(), 10);
+		assert!(!result.contains(&"01-07".to_string()));
+		assert!(result.contains(&"05-08".to_string()));
+		assert!(!result.contains(&"10-14".to_string()));
+	}
+
+	#[test]
+	fn test_suspension_periods() {
+		let holidays = HolidayProvider::get_holidays(2022, CountryCode::UA);
+		let non_working: Vec<String> = holidays
+			.iter()
+			.filter(|holiday| holiday.non_working)
+			.map(|holiday| holiday.to_string())
+			.collect();
+		assert_eq!(
+			non_working,
+			vec![
+				"2022-01-01 New Year's Day",
+				"2022-01-07 (Julian) Christmas",
+				"2022-03-08 International Women's Day",
+			]
+		);
+		assert_eq!(holidays.len(), 11);
+
+		let christmas_day = NaiveDate::from_ymd_opt(2023, 12, 25).unwrap();
+		assert!(HolidayProvider::is_business_day(christmas_day, CountryCode::UA, None));
+		let new_years_day = NaiveDate::from_ymd_opt(2021, 1, 1).unwrap();
+		assert!(!HolidayProvider::is_business_day(new_years_day, CountryCode::UA, None));
+	}
 }
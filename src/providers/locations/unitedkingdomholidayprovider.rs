@@ -1,12 +1,14 @@
 use std::collections::HashMap;
 
-use chrono::{Datelike, TimeZone, Utc, Weekday};
+use chrono::{Datelike, Duration, NaiveDate, Weekday};
 
 use crate::{
+	calendars::reform::CalendarReform,
 	datesystem::DateSystem,
+	holidayprovider::HolidayProvider,
 	providers::base::catholicprovider::CatholicProvider,
 	types::{
-		countrycode::CountryCode, occurrence::Occurrence, publicholiday::PublicHoliday,
+		countrycode::CountryCode, holidayid::HolidayId, occurrence::Occurrence, publicholiday::PublicHoliday,
 		publicholidaytype::PublicHolidayType,
 	},
 };
@@ -37,12 +39,13 @@ impl UnitedKingdomHolidayProvider {
 
 		let mut holidays = Vec::new();
 
-		let new_years_day = Utc.with_ymd_and_hms(year, 1, 1, 0, 0, 0).unwrap();
+		let new_years_day = NaiveDate::from_ymd_opt(year, 1, 1).unwrap();
 		if new_years_day.weekday() == Weekday::Sat || new_years_day.weekday() == Weekday::Sun {
 			let new_year_day_monday = DateSystem::find_day(year, 1, Weekday::Mon, Occurrence::First);
 			let new_year_day_tuesday = DateSystem::find_day(year, 1, Weekday::Tue, Occurrence::First);
 
 			holidays.push(PublicHoliday::new_non_fixed(
+				HolidayId::NEW_YEARS_DAY,
 				new_years_day,
 				"New Year's Day",
 				"New Year's Day",
@@ -53,6 +56,7 @@ impl UnitedKingdomHolidayProvider {
 			));
 
 			holidays.push(PublicHoliday::new_non_fixed(
+				HolidayId::NEW_YEARS_DAY,
 				new_year_day_monday.unwrap(),
 				"New Year's Day",
 				"New Year's Day",
@@ -62,6 +66,7 @@ impl UnitedKingdomHolidayProvider {
 				PublicHolidayType::Public,
 			));
 			holidays.push(PublicHoliday::new_non_fixed(
+				HolidayId::NEW_YEARS_DAY,
 				new_year_day_tuesday.unwrap(),
 				"New Year's Day",
 				"New Year's Day",
@@ -72,6 +77,7 @@ impl UnitedKingdomHolidayProvider {
 			));
 		} else {
 			holidays.push(PublicHoliday::new_non_fixed(
+				HolidayId::NEW_YEARS_DAY,
 				new_years_day,
 				"New Year's Day",
 				"New Year's Day",
@@ -82,8 +88,9 @@ impl UnitedKingdomHolidayProvider {
 			));
 		}
 
-		let new_year_day2 = DateSystem::shift(Utc.with_ymd_and_hms(year, 1, 2, 0, 0, 0).unwrap(), 2, 1, None);
+		let new_year_day2 = DateSystem::shift(NaiveDate::from_ymd_opt(year, 1, 2).unwrap(), 2, 1, None);
 		holidays.push(PublicHoliday::new_non_fixed(
+			HolidayId::NEW_YEARS_DAY,
 			new_year_day2,
 			"New Year's Day",
 			"New Year's Day",
@@ -94,6 +101,7 @@ impl UnitedKingdomHolidayProvider {
 		));
 
 		holidays.push(PublicHoliday::new_fixed(
+			HolidayId::ST_PATRICKS_DAY,
 			year,
 			3,
 			17,
@@ -111,12 +119,12 @@ impl UnitedKingdomHolidayProvider {
 			year,
 			Self::COUNTRYCODE,
 		));
-		let holiday_length = &holidays.len();
-		if let Some(holiday) = holidays.get_mut(*holiday_length) {
+		if let Some(holiday) = holidays.last_mut() {
 			holiday.set_counties(vec!["GB-ENG".to_string(), "GB-WLS".to_string(), "GB-NIR".to_string()]);
 		}
 
 		holidays.push(PublicHoliday::new_fixed(
+			HolidayId::GB_BATTLE_OF_THE_BOYNE,
 			year,
 			7,
 			12,
@@ -128,6 +136,7 @@ impl UnitedKingdomHolidayProvider {
 			PublicHolidayType::Public,
 		));
 		holidays.push(PublicHoliday::new_fixed(
+			HolidayId::ST_ANDREWS_DAY,
 			year,
 			11,
 			30,
@@ -141,6 +150,7 @@ impl UnitedKingdomHolidayProvider {
 
 		if let Some(first_monday_in_august) = first_monday_in_august {
 			holidays.push(PublicHoliday::new_non_fixed(
+				HolidayId::GB_SUMMER_BANK_HOLIDAY,
 				first_monday_in_august,
 				"Summer Bank Holiday",
 				"Summer Bank Holiday",
@@ -152,6 +162,7 @@ impl UnitedKingdomHolidayProvider {
 		}
 		if let Some(last_monday_in_august) = last_monday_in_august {
 			holidays.push(PublicHoliday::new_non_fixed(
+				HolidayId::GB_SUMMER_BANK_HOLIDAY,
 				last_monday_in_august,
 				"Summer Bank Holiday",
 				"Summer Bank Holiday",
@@ -172,23 +183,16 @@ impl UnitedKingdomHolidayProvider {
 			holidays.push(spring_bank_holiday);
 		}
 
-		let queens_platinum_jubilee = Self::queens_platinum_jubilee(year);
-		if let Some(queens_platinum_jubilee) = queens_platinum_jubilee {
-			holidays.push(queens_platinum_jubilee);
-		}
-
-		let queens_state_funeral = Self::queens_state_funeral(year);
-		if let Some(queens_state_funeral) = queens_state_funeral {
-			holidays.push(queens_state_funeral);
-		}
-
-		let coronation_bank_holiday = Self::coronation_bank_holiday(year);
-		if let Some(coronation_bank_holiday) = coronation_bank_holiday {
-			holidays.push(coronation_bank_holiday);
-		}
+		holidays.extend(Self::scottish_local_holidays(year));
 
-		let christmas_day = DateSystem::shift(Utc.with_ymd_and_hms(year, 12, 25, 0, 0, 0).unwrap(), 2, 2, None);
+		let christmas_day = DateSystem::shift(
+			CalendarReform::fixed_date(Self::COUNTRYCODE, year, 12, 25).unwrap(),
+			2,
+			2,
+			None,
+		);
 		holidays.push(PublicHoliday::new_non_fixed(
+			HolidayId::CHRISTMAS_DAY,
 			christmas_day,
 			"Christmas Day",
 			"Christmas Day",
@@ -198,8 +202,14 @@ impl UnitedKingdomHolidayProvider {
 			PublicHolidayType::Public,
 		));
 
-		let sankt_stehpen_day = DateSystem::shift(Utc.with_ymd_and_hms(year, 12, 26, 0, 0, 0).unwrap(), 2, 2, None);
+		let sankt_stehpen_day = DateSystem::shift(
+			CalendarReform::fixed_date(Self::COUNTRYCODE, year, 12, 26).unwrap(),
+			2,
+			2,
+			None,
+		);
 		holidays.push(PublicHoliday::new_non_fixed(
+			HolidayId::BOXING_DAY,
 			sankt_stehpen_day,
 			"Boxing Day",
 			"St. Stephen's Day",
@@ -208,136 +218,168 @@ impl UnitedKingdomHolidayProvider {
 			None,
 			PublicHolidayType::Public,
 		));
-		holidays.sort_by(|a, b| a.date.cmp(&b.date));
+		holidays.sort_by_key(|a| a.date);
 
 		holidays
 	}
 
-	fn spring_bank_holiday(year: i32) -> Option<PublicHoliday> {
-		let name = "Spring Bank Holiday";
-
-		if year == 2022 {
-			//https://www.gov.uk/government/news/extra-bank-holiday-to-mark-the-queens-platinum-jubilee-in-2022
-			Some(PublicHoliday::new_non_fixed(
-				Utc.with_ymd_and_hms(year, 6, 2, 0, 0, 0).unwrap(),
-				name,
-				name,
-				Self::COUNTRYCODE,
-				None,
-				None,
-				PublicHolidayType::Public,
-			))
-		} else {
-			let last_monday_in_may = DateSystem::find_last_day(year, 5, Weekday::Mon);
-			Some(PublicHoliday::new_non_fixed(
-				last_monday_in_may.unwrap(),
-				name,
-				name,
-				Self::COUNTRYCODE,
-				Some(1971),
-				None,
-				PublicHolidayType::Public,
-			))
-		}
+	/// Gets the bank holidays of england for the given country code, including the proclaimed bank holidays, which are
+	/// the base of the bank holidays of the crown dependencies and the london stock exchange
+	pub(crate) fn get_england_holidays(year: i32, country_code: CountryCode) -> Vec<PublicHoliday> {
+		HolidayProvider::get_country_holidays(year, Self::COUNTRYCODE)
+			.into_iter()
+			.filter(|holiday| holiday.is_in_county("GB-ENG"))
+			.map(|mut holiday| {
+				holiday.country_code = country_code;
+				holiday.counties = None;
+				holiday
+			})
+			.collect()
 	}
 
-	fn queens_platinum_jubilee(year: i32) -> Option<PublicHoliday> {
-		if year == 2022 {
-			//Majesty Queen Elizabeth II’s
-			Some(PublicHoliday::new_fixed(
-				year,
-				6,
-				3,
-				"Queen’s Platinum Jubilee",
-				"Queen’s Platinum Jubilee",
-				Self::COUNTRYCODE,
-				None,
-				None,
-				PublicHolidayType::Public,
-			))
-		} else {
-			None
-		}
-	}
+	/// Gets the local holidays of the scottish council areas, which are set by the councils in addition to the bank
+	/// holidays of scotland
+	fn scottish_local_holidays(year: i32) -> Vec<PublicHoliday> {
+		let glasgow = || vec!["GB-GLG".to_string(), "Glasgow".to_string()];
+		let edinburgh = || vec!["GB-EDH".to_string(), "Edinburgh".to_string()];
+		// the victoria day is on the last monday before may 25
+		let may_18 = NaiveDate::from_ymd_opt(year, 5, 18).unwrap();
+		let victoria_day = may_18 + Duration::days((7 - may_18.weekday().num_days_from_monday() as i64) % 7);
 
-	fn queens_state_funeral(year: i32) -> Option<PublicHoliday> {
-		if year == 2022 {
-			//Majesty Queen Elizabeth II’s (https://www.gov.uk/government/news/bank-holiday-announced-for-her-majesty-queen-elizabeth-iis-state-funeral-on-monday-19-september)
-			Some(PublicHoliday::new_fixed(
-				year,
-				9,
-				19,
-				"Queen’s State Funeral",
-				"Queen’s State Funeral",
-				Self::COUNTRYCODE,
-				None,
-				None,
-				PublicHolidayType::Public,
-			))
-		} else {
-			None
+		let mut holidays = Vec::new();
+		let local_holidays = [
+			(
+				DateSystem::find_day(year, 4, Weekday::Mon, Occurrence::Third),
+				HolidayId::GB_EDINBURGH_SPRING_HOLIDAY,
+				"Edinburgh Spring Holiday",
+				edinburgh(),
+			),
+			(
+				Some(victoria_day),
+				HolidayId::GB_VICTORIA_DAY,
+				"Victoria Day",
+				edinburgh(),
+			),
+			(
+				DateSystem::find_day(year, 7, Weekday::Mon, Occurrence::Third),
+				HolidayId::GB_GLASGOW_FAIR,
+				"Glasgow Fair",
+				glasgow(),
+			),
+			(
+				DateSystem::find_day(year, 9, Weekday::Mon, Occurrence::Third),
+				HolidayId::GB_EDINBURGH_AUTUMN_HOLIDAY,
+				"Edinburgh Autumn Holiday",
+				edinburgh(),
+			),
+			(
+				DateSystem::find_last_day(year, 9, Weekday::Mon),
+				HolidayId::GB_GLASGOW_SEPTEMBER_WEEKEND,
+				"Glasgow September Weekend",
+				glasgow(),
+			),
+		];
+		for (date, id, name, localities) in local_holidays {
+			if let Some(date) = date {
+				let mut holiday = PublicHoliday::new_non_fixed(
+					id,
+					date,
+					name,
+					name,
+					Self::COUNTRYCODE,
+					None,
+					Some(vec!["GB-SCT".to_string()]),
+					PublicHolidayType::Public,
+				);
+				holiday.set_localities("GB-SCT", localities);
+				holidays.push(holiday);
+			}
 		}
+
+		holidays
 	}
 
-	fn coronation_bank_holiday(year: i32) -> Option<PublicHoliday> {
-		if year == 2023 {
-			//Bank holiday proclaimed in honour of the coronation of His Majesty King Charles III
-			//https://www.gov.uk/government/news/bank-holiday-proclaimed-in-honour-of-the-coronation-of-his-majesty-king-charles-iii
-			Some(PublicHoliday::new_fixed(
-				year,
-				5,
-				8,
-				"Coronation Bank Holiday",
-				"Coronation Bank Holiday",
-				Self::COUNTRYCODE,
-				None,
-				None,
-				PublicHolidayType::Public,
-			))
-		} else {
-			None
-		}
+	fn spring_bank_holiday(year: i32) -> Option<PublicHoliday> {
+		let name = "Spring Bank Holiday";
+		let last_monday_in_may = DateSystem::find_last_day(year, 5, Weekday::Mon);
+		Some(PublicHoliday::new_non_fixed(
+			HolidayId::GB_SPRING_BANK_HOLIDAY,
+			last_monday_in_may.unwrap(),
+			name,
+			name,
+			Self::COUNTRYCODE,
+			Some(1971),
+			None,
+			PublicHolidayType::Public,
+		))
 	}
 
 	fn early_may_bank_holiday(year: i32) -> Option<PublicHoliday> {
 		let name = "Early May Bank Holiday";
-
-		if year == 2020 {
-			//https://www.bbc.co.uk/news/uk-48565417
-			let second_friday_in_may = DateSystem::find_day(year, 5, Weekday::Fri, Occurrence::Second);
-			Some(PublicHoliday::new_non_fixed(
-				second_friday_in_may.unwrap(),
-				name,
-				name,
-				Self::COUNTRYCODE,
-				Some(1978),
-				None,
-				PublicHolidayType::Public,
-			))
-		} else {
-			let first_monday_in_may = DateSystem::find_day(year, 5, Weekday::Mon, Occurrence::First);
-			Some(PublicHoliday::new_non_fixed(
-				first_monday_in_may.unwrap(),
-				name,
-				name,
-				Self::COUNTRYCODE,
-				Some(1978),
-				None,
-				PublicHolidayType::Public,
-			))
-		}
+		let first_monday_in_may = DateSystem::find_day(year, 5, Weekday::Mon, Occurrence::First);
+		Some(PublicHoliday::new_non_fixed(
+			HolidayId::GB_EARLY_MAY_BANK_HOLIDAY,
+			first_monday_in_may.unwrap(),
+			name,
+			name,
+			Self::COUNTRYCODE,
+			Some(1978),
+			None,
+			PublicHolidayType::Public,
+		))
 	}
 }
 
 #[cfg(test)]
 mod tests {
+	use crate::{holidayprovider::HolidayProvider, types::countrycode::CountryCode};
+
 	use super::UnitedKingdomHolidayProvider;
 
 	#[test]
 	fn test_get_holidays() {
 		let result = UnitedKingdomHolidayProvider::get_holidays(2022);
-		assert_eq!(result.len(), 17);
+		assert_eq!(result.len(), 20);
 		assert_eq!(result.is_empty(), false);
 		assert_eq!(result[5].local_name, "Good Friday");
+		// the platinum jubilee and the state funeral are added from the proclamations
+		assert_eq!(HolidayProvider::get_holidays(2022, CountryCode::GB).len(), 22);
+	}
+
+	#[test]
+	fn test_christmas_before_calendar_reform() {
+		let result = UnitedKingdomHolidayProvider::get_holidays(1700);
+		let christmas_day = result.iter().find(|holiday| holiday.name == "Christmas Day").unwrap();
+		// julian christmas 1699 took place on january 4, 1700 in the gregorian calendar
+		assert_eq!(christmas_day.date.to_string(), "1700-01-04");
+	}
+
+	#[test]
+	fn test_scottish_local_holidays() {
+		let result = UnitedKingdomHolidayProvider::get_holidays(2024);
+		let local_holiday = |name: &str| result.iter().find(|holiday| holiday.name == name).unwrap();
+		let glasgow_fair = local_holiday("Glasgow Fair");
+		assert_eq!(glasgow_fair.date.to_string(), "2024-07-15");
+		assert!(!glasgow_fair.is_in_county("GB-SCT"));
+		assert!(glasgow_fair.is_in_location("GB-SCT", Some("Glasgow")));
+		assert!(glasgow_fair.is_in_location("GB-SCT", Some("GB-GLG")));
+		assert!(!glasgow_fair.is_in_location("GB-SCT", Some("Edinburgh")));
+		assert!(!glasgow_fair.is_in_location("GB-SCT", None));
+		assert_eq!(local_holiday("Edinburgh Spring Holiday").date.to_string(), "2024-04-15");
+		assert_eq!(local_holiday("Victoria Day").date.to_string(), "2024-05-20");
+		assert_eq!(local_holiday("Edinburgh Autumn Holiday").date.to_string(), "2024-09-16");
+		assert_eq!(
+			local_holiday("Glasgow September Weekend").date.to_string(),
+			"2024-09-30"
+		);
+	}
+
+	#[test]
+	fn test_get_england_holidays() {
+		let result = UnitedKingdomHolidayProvider::get_england_holidays(2024, CountryCode::JE);
+		assert_eq!(result.len(), 8);
+		assert!(result
+			.iter()
+			.all(|holiday| holiday.global() && holiday.country_code == CountryCode::JE));
 	}
 }
@@ -1,13 +1,15 @@
 use std::collections::HashMap;
 
-use chrono::{TimeZone, Utc, Weekday};
+use chrono::{NaiveDate, Weekday};
+use chrono_tz::Tz;
 
 use crate::{
 	datesystem::DateSystem,
 	providers::base::catholicprovider::CatholicProvider,
 	types::{
-		countrycode::CountryCode, occurrence::Occurrence, publicholiday::PublicHoliday,
-		publicholidaytype::PublicHolidayType,
+		computus::Computus, countrycode::CountryCode, holidayid::HolidayId, moveablefeast::MoveableFeast,
+		occurrence::Occurrence, publicholiday::PublicHoliday, publicholidaytype::PublicHolidayType,
+		recurrence::Recurrence,
 	},
 };
 
@@ -16,7 +18,33 @@ pub struct UnitedStatesHolidayProvider {}
 impl UnitedStatesHolidayProvider {
 	const COUNTRYCODE: CountryCode = CountryCode::US;
 
-	/// Gets a hashmap of the available counties of germany
+	/// Gets the time zone of the given state or territory. States with more than one time zone use the zone of the
+	/// largest part of the state. Without a state the eastern time will be returned.
+	pub fn get_time_zone(county: Option<&str>) -> Tz {
+		match county {
+			Some("US-AK") => Tz::America__Anchorage,
+			Some("US-AZ") => Tz::America__Phoenix,
+			Some("US-CA" | "US-NV" | "US-OR" | "US-WA") => Tz::America__Los_Angeles,
+			Some("US-CO" | "US-MT" | "US-NM" | "US-UT" | "US-WY") => Tz::America__Denver,
+			Some("US-ID") => Tz::America__Boise,
+			Some(
+				"US-AL" | "US-AR" | "US-IA" | "US-IL" | "US-KS" | "US-LA" | "US-MN" | "US-MO" | "US-MS" | "US-ND"
+				| "US-NE" | "US-OK" | "US-SD" | "US-TN" | "US-TX" | "US-WI",
+			) => Tz::America__Chicago,
+			Some("US-HI") => Tz::Pacific__Honolulu,
+			Some("US-IN") => Tz::America__Indiana__Indianapolis,
+			Some("US-KY") => Tz::America__Kentucky__Louisville,
+			Some("US-MI") => Tz::America__Detroit,
+			Some("US-AS") => Tz::Pacific__Pago_Pago,
+			Some("US-GU") => Tz::Pacific__Guam,
+			Some("US-MP") => Tz::Pacific__Saipan,
+			Some("US-PR") => Tz::America__Puerto_Rico,
+			Some("US-VI") => Tz::America__St_Thomas,
+			_ => Tz::America__New_York,
+		}
+	}
+
+	/// Gets a hashmap of the available states, the federal district and the inhabited territories of the united states
 	pub fn get_counties() -> HashMap<String, String> {
 		HashMap::from([
 			("US-AL".to_string(), "Alabama".to_string()),
@@ -69,6 +97,12 @@ impl UnitedStatesHolidayProvider {
 			("US-WV".to_string(), "West Virginia".to_string()),
 			("US-WI".to_string(), "Wisconsin".to_string()),
 			("US-WY".to_string(), "Wyoming".to_string()),
+			("US-DC".to_string(), "District of Columbia".to_string()),
+			("US-AS".to_string(), "American Samoa".to_string()),
+			("US-GU".to_string(), "Guam".to_string()),
+			("US-MP".to_string(), "Northern Mariana Islands".to_string()),
+			("US-PR".to_string(), "Puerto Rico".to_string()),
+			("US-VI".to_string(), "U.S. Virgin Islands".to_string()),
 		])
 	}
 
@@ -87,8 +121,9 @@ impl UnitedStatesHolidayProvider {
 
 		let mut holidays = Vec::new();
 
-		let new_years_day = DateSystem::shift(Utc.with_ymd_and_hms(year, 1, 1, 0, 0, 0).unwrap(), -1, 1, None);
+		let new_years_day = DateSystem::shift(NaiveDate::from_ymd_opt(year, 1, 1).unwrap(), -1, 1, None);
 		holidays.push(PublicHoliday::new_non_fixed(
+			HolidayId::NEW_YEARS_DAY,
 			new_years_day,
 			"New Year's Day",
 			"New Year's Day",
@@ -100,6 +135,7 @@ impl UnitedStatesHolidayProvider {
 
 		if let Some(third_monday_in_january) = third_monday_in_january {
 			holidays.push(PublicHoliday::new_non_fixed(
+				HolidayId::US_MARTIN_LUTHER_KING_DAY,
 				third_monday_in_january,
 				"Martin Luther King, Jr. Day",
 				"Martin Luther King, Jr. Day",
@@ -111,6 +147,7 @@ impl UnitedStatesHolidayProvider {
 		}
 		if let Some(third_monday_in_february) = third_monday_in_february {
 			holidays.push(PublicHoliday::new_non_fixed(
+				HolidayId::US_WASHINGTONS_BIRTHDAY,
 				third_monday_in_february,
 				"Presidents Day",
 				"Washington's Birthday",
@@ -122,6 +159,7 @@ impl UnitedStatesHolidayProvider {
 		}
 		if let Some(last_monday_in_may) = last_monday_in_may {
 			holidays.push(PublicHoliday::new_non_fixed(
+				HolidayId::US_MEMORIAL_DAY,
 				last_monday_in_may,
 				"Memorial Day",
 				"Memorial Day",
@@ -132,8 +170,7 @@ impl UnitedStatesHolidayProvider {
 			));
 		}
 		holidays.push(CatholicProvider::good_friday("Good Friday", year, Self::COUNTRYCODE));
-		let holiday_length = &holidays.len();
-		if let Some(holiday) = holidays.get_mut(*holiday_length) {
+		if let Some(holiday) = holidays.last_mut() {
 			holiday.set_counties(vec![
 				"US-CT".to_string(),
 				"US-DE".to_string(),
@@ -148,14 +185,14 @@ impl UnitedStatesHolidayProvider {
 			]);
 		}
 		holidays.push(CatholicProvider::good_friday("Good Friday", year, Self::COUNTRYCODE));
-		let holiday_length = &holidays.len();
-		if let Some(holiday) = holidays.get_mut(*holiday_length) {
+		if let Some(holiday) = holidays.last_mut() {
 			holiday.set_counties(vec!["US-TX".to_string()]);
 			holiday.set_holiday_type(PublicHolidayType::Optional);
 		}
 		if year >= 2021 {
-			let june_teenth = DateSystem::shift(Utc.with_ymd_and_hms(year, 6, 19, 0, 0, 0).unwrap(), -1, 1, None);
+			let june_teenth = DateSystem::shift(NaiveDate::from_ymd_opt(year, 6, 19).unwrap(), -1, 1, None);
 			holidays.push(PublicHoliday::new_non_fixed(
+				HolidayId::US_JUNETEENTH,
 				june_teenth,
 				"Juneteenth",
 				"Juneteenth",
@@ -166,8 +203,9 @@ impl UnitedStatesHolidayProvider {
 			));
 		}
 
-		let independence_day = DateSystem::shift(Utc.with_ymd_and_hms(year, 7, 4, 0, 0, 0).unwrap(), -1, 1, None);
+		let independence_day = DateSystem::shift(NaiveDate::from_ymd_opt(year, 7, 4).unwrap(), -1, 1, None);
 		holidays.push(PublicHoliday::new_non_fixed(
+			HolidayId::US_INDEPENDENCE_DAY,
 			independence_day,
 			"Independence Day",
 			"Independence Day",
@@ -179,6 +217,7 @@ impl UnitedStatesHolidayProvider {
 
 		if let Some(first_monday_in_september) = first_monday_in_september {
 			holidays.push(PublicHoliday::new_non_fixed(
+				HolidayId::US_LABOR_DAY,
 				first_monday_in_september,
 				"Labor Day",
 				"Labor Day",
@@ -191,6 +230,7 @@ impl UnitedStatesHolidayProvider {
 
 		if let Some(second_monday_in_october) = second_monday_in_october {
 			holidays.push(PublicHoliday::new_non_fixed(
+				HolidayId::US_COLUMBUS_DAY,
 				second_monday_in_october,
 				"Columbus Day",
 				"Columbus Day",
@@ -236,8 +276,9 @@ impl UnitedStatesHolidayProvider {
 			));
 		}
 
-		let veterans_day = DateSystem::shift(Utc.with_ymd_and_hms(year, 11, 11, 0, 0, 0).unwrap(), -1, 1, None);
+		let veterans_day = DateSystem::shift(NaiveDate::from_ymd_opt(year, 11, 11).unwrap(), -1, 1, None);
 		holidays.push(PublicHoliday::new_non_fixed(
+			HolidayId::US_VETERANS_DAY,
 			veterans_day,
 			"Veterans Day",
 			"Veterans Day",
@@ -249,6 +290,7 @@ impl UnitedStatesHolidayProvider {
 
 		if let Some(fourth_thursday_in_november) = fourth_thursday_in_november {
 			holidays.push(PublicHoliday::new_non_fixed(
+				HolidayId::US_THANKSGIVING,
 				fourth_thursday_in_november,
 				"Thanksgiving Day",
 				"Thanksgiving Day",
@@ -259,8 +301,9 @@ impl UnitedStatesHolidayProvider {
 			));
 		}
 
-		let christmas_day = DateSystem::shift(Utc.with_ymd_and_hms(year, 12, 25, 0, 0, 0).unwrap(), -1, 1, None);
+		let christmas_day = DateSystem::shift(NaiveDate::from_ymd_opt(year, 12, 25).unwrap(), -1, 1, None);
 		holidays.push(PublicHoliday::new_non_fixed(
+			HolidayId::CHRISTMAS_DAY,
 			christmas_day,
 			"Christmas Day",
 			"Christmas Day",
@@ -270,59 +313,431 @@ impl UnitedStatesHolidayProvider {
 			PublicHolidayType::Public,
 		));
 
-		if (year - 1) % 4 == 0 {
-			if year >= 1937 {
-				holidays.push(PublicHoliday::new_fixed(
-					year,
-					1,
-					20,
-					"Inauguration Day",
-					"Inauguration Day",
-					Self::COUNTRYCODE,
-					None,
-					Some(vec![
-						"US-DC".to_string(),
-						"US-LA".to_string(),
-						"US-MD".to_string(),
-						"US-VA".to_string(),
-					]),
-					PublicHolidayType::Public,
+		if let Some(inauguration_day) = Self::inauguration_day(year) {
+			holidays.push(inauguration_day);
+		}
+		if let Some(election_day) = Self::election_day(year) {
+			holidays.push(election_day);
+		}
+
+		holidays.extend(Self::get_state_holidays(year));
+		holidays.extend(Self::get_territory_holidays(year));
+
+		holidays.sort_by_key(|a| a.date);
+
+		holidays
+	}
+
+	/// Gets the holidays which are only observed in some states and the district of columbia
+	fn get_state_holidays(year: i32) -> Vec<PublicHoliday> {
+		let mut holidays = Vec::new();
+
+		let lincolns_birthday = NaiveDate::from_ymd_opt(year, 2, 12).unwrap();
+		holidays.push(Self::state_holiday(
+			HolidayId::US_LINCOLNS_BIRTHDAY,
+			lincolns_birthday,
+			"Lincoln's Birthday",
+			None,
+			&["US-CT", "US-IL", "US-MO", "US-NY"],
+		));
+		holidays.push(MoveableFeast::CarnivalTuesday.rule(Computus::Gregorian).holiday(
+			HolidayId::MARDI_GRAS,
+			"Mardi Gras",
+			"Mardi Gras",
+			year,
+			Self::COUNTRYCODE,
+		));
+		if let Some(holiday) = holidays.last_mut() {
+			holiday.set_counties(vec!["US-LA".to_string()]);
+		}
+		let texas_independence_day = NaiveDate::from_ymd_opt(year, 3, 2).unwrap();
+		holidays.push(Self::state_holiday(
+			HolidayId::US_TEXAS_INDEPENDENCE_DAY,
+			texas_independence_day,
+			"Texas Independence Day",
+			Some(1836),
+			&["US-TX"],
+		));
+		if year >= 1978 {
+			if let Some(first_monday_in_march) = DateSystem::find_day(year, 3, Weekday::Mon, Occurrence::First) {
+				holidays.push(Self::state_holiday(
+					HolidayId::US_CASIMIR_PULASKI_DAY,
+					first_monday_in_march,
+					"Casimir Pulaski Day",
+					Some(1978),
+					&["US-IL"],
 				));
-			} else {
-				holidays.push(PublicHoliday::new_fixed(
-					year,
-					3,
-					4,
-					"Inauguration Day",
-					"Inauguration Day",
-					Self::COUNTRYCODE,
-					None,
-					Some(vec![
-						"US-DC".to_string(),
-						"US-LA".to_string(),
-						"US-MD".to_string(),
-						"US-VA".to_string(),
-					]),
-					PublicHolidayType::Public,
+			}
+		}
+		if let Some(last_monday_in_march) = DateSystem::find_last_day(year, 3, Weekday::Mon) {
+			holidays.push(Self::state_holiday(
+				HolidayId::US_SEWARDS_DAY,
+				last_monday_in_march,
+				"Seward's Day",
+				Some(1918),
+				&["US-AK"],
+			));
+		}
+		if year >= 2000 {
+			let cesar_chavez_day = DateSystem::shift(NaiveDate::from_ymd_opt(year, 3, 31).unwrap(), 0, 1, None);
+			holidays.push(Self::state_holiday(
+				HolidayId::US_CESAR_CHAVEZ_DAY,
+				cesar_chavez_day,
+				"Cesar Chavez Day",
+				Some(2000),
+				&["US-CA"],
+			));
+		}
+		if year >= 2005 {
+			let emancipation_day = DateSystem::shift(NaiveDate::from_ymd_opt(year, 4, 16).unwrap(), -1, 1, None);
+			holidays.push(Self::state_holiday(
+				HolidayId::US_EMANCIPATION_DAY,
+				emancipation_day,
+				"Emancipation Day",
+				Some(2005),
+				&["US-DC"],
+			));
+		}
+		if let Some(third_monday_in_april) = DateSystem::find_day(year, 4, Weekday::Mon, Occurrence::Third) {
+			holidays.push(Self::state_holiday(
+				HolidayId::US_PATRIOTS_DAY,
+				third_monday_in_april,
+				"Patriots' Day",
+				Some(1894),
+				&["US-MA", "US-ME"],
+			));
+		}
+		let truman_day = NaiveDate::from_ymd_opt(year, 5, 8).unwrap();
+		holidays.push(Self::state_holiday(
+			HolidayId::US_TRUMAN_DAY,
+			truman_day,
+			"Truman Day",
+			Some(1949),
+			&["US-MO"],
+		));
+		let kamehameha_day = DateSystem::shift(NaiveDate::from_ymd_opt(year, 6, 11).unwrap(), -1, 1, None);
+		holidays.push(Self::state_holiday(
+			HolidayId::US_KAMEHAMEHA_DAY,
+			kamehameha_day,
+			"Kamehameha Day",
+			Some(1872),
+			&["US-HI"],
+		));
+		let pioneer_day = DateSystem::shift(NaiveDate::from_ymd_opt(year, 7, 24).unwrap(), -1, 1, None);
+		holidays.push(Self::state_holiday(
+			HolidayId::US_PIONEER_DAY,
+			pioneer_day,
+			"Pioneer Day",
+			Some(1849),
+			&["US-UT"],
+		));
+		let alaska_day = NaiveDate::from_ymd_opt(year, 10, 18).unwrap();
+		holidays.push(Self::state_holiday(
+			HolidayId::US_ALASKA_DAY,
+			alaska_day,
+			"Alaska Day",
+			Some(1917),
+			&["US-AK"],
+		));
+		if year >= 2000 {
+			if let Some(last_friday_in_october) = DateSystem::find_last_day(year, 10, Weekday::Fri) {
+				holidays.push(Self::state_holiday(
+					HolidayId::US_NEVADA_DAY,
+					last_friday_in_october,
+					"Nevada Day",
+					Some(2000),
+					&["US-NV"],
 				));
 			}
 		}
 
-		holidays.sort_by(|a, b| a.date.cmp(&b.date));
+		holidays
+	}
+
+	/// Gets the holidays of the territories puerto rico, guam, the virgin islands, american samoa and the northern
+	/// mariana islands
+	fn get_territory_holidays(year: i32) -> Vec<PublicHoliday> {
+		let mut holidays = vec![
+			PublicHoliday::new_fixed(
+				HolidayId::EPIPHANY,
+				year,
+				1,
+				6,
+				"Día de Reyes",
+				"Epiphany",
+				Self::COUNTRYCODE,
+				None,
+				Some(vec!["US-PR".to_string(), "US-VI".to_string()]),
+				PublicHolidayType::Public,
+			),
+			PublicHoliday::new_fixed(
+				HolidayId::US_TRANSFER_DAY,
+				year,
+				3,
+				31,
+				"Transfer Day",
+				"Transfer Day",
+				Self::COUNTRYCODE,
+				Some(1917),
+				Some(vec!["US-VI".to_string()]),
+				PublicHolidayType::Public,
+			),
+			PublicHoliday::new_fixed(
+				HolidayId::US_AMERICAN_SAMOA_FLAG_DAY,
+				year,
+				4,
+				17,
+				"Flag Day",
+				"American Samoa Flag Day",
+				Self::COUNTRYCODE,
+				Some(1900),
+				Some(vec!["US-AS".to_string()]),
+				PublicHolidayType::Public,
+			),
+			PublicHoliday::new_fixed(
+				HolidayId::US_EMANCIPATION_DAY,
+				year,
+				7,
+				3,
+				"Emancipation Day",
+				"Emancipation Day",
+				Self::COUNTRYCODE,
+				Some(1848),
+				Some(vec!["US-VI".to_string()]),
+				PublicHolidayType::Public,
+			),
+			PublicHoliday::new_fixed(
+				HolidayId::US_GUAM_LIBERATION_DAY,
+				year,
+				7,
+				21,
+				"Liberation Day",
+				"Guam Liberation Day",
+				Self::COUNTRYCODE,
+				Some(1945),
+				Some(vec!["US-GU".to_string()]),
+				PublicHolidayType::Public,
+			),
+		];
+		if year >= 1953 {
+			holidays.push(PublicHoliday::new_fixed(
+				HolidayId::US_PUERTO_RICO_CONSTITUTION_DAY,
+				year,
+				7,
+				25,
+				"Día de la Constitución",
+				"Puerto Rico Constitution Day",
+				Self::COUNTRYCODE,
+				Some(1953),
+				Some(vec!["US-PR".to_string()]),
+				PublicHolidayType::Public,
+			));
+		}
+		if year >= 1976 {
+			holidays.push(PublicHoliday::new_fixed(
+				HolidayId::US_COVENANT_DAY,
+				year,
+				3,
+				24,
+				"Covenant Day",
+				"Covenant Day",
+				Self::COUNTRYCODE,
+				Some(1976),
+				Some(vec!["US-MP".to_string()]),
+				PublicHolidayType::Public,
+			));
+		}
 
 		holidays
 	}
+
+	fn state_holiday(
+		id: HolidayId,
+		date: NaiveDate,
+		name: &str,
+		launch_year: Option<i32>,
+		counties: &[&str],
+	) -> PublicHoliday {
+		PublicHoliday::new_non_fixed(
+			id,
+			date,
+			name,
+			name,
+			Self::COUNTRYCODE,
+			launch_year,
+			Some(counties.iter().map(|county| county.to_string()).collect()),
+			PublicHolidayType::Public,
+		)
+	}
+
+	/// Gets the day of the presidential inauguration, which takes place every four years on january 20, or on march 4
+	/// until 1933. If the day is a sunday, the inauguration takes place on monday.
+	fn inauguration_day(year: i32) -> Option<PublicHoliday> {
+		let (month, day) = if Recurrence::new(1937, 4).occurs_in(year) {
+			(1, 20)
+		} else if Recurrence::new(1793, 4).set_end_year(1933).occurs_in(year) {
+			(3, 4)
+		} else {
+			return None;
+		};
+
+		let date = DateSystem::shift(NaiveDate::from_ymd_opt(year, month, day).unwrap(), 0, 1, None);
+		Some(PublicHoliday::new_non_fixed(
+			HolidayId::US_INAUGURATION_DAY,
+			date,
+			"Inauguration Day",
+			"Inauguration Day",
+			Self::COUNTRYCODE,
+			None,
+			Some(vec![
+				"US-DC".to_string(),
+				"US-LA".to_string(),
+				"US-MD".to_string(),
+				"US-VA".to_string(),
+			]),
+			PublicHolidayType::Public,
+		))
+	}
+
+	/// Gets the day of the general election on the tuesday after the first monday in november, which is a holiday in
+	/// some states in every year and in other states only in the years of the federal elections
+	fn election_day(year: i32) -> Option<PublicHoliday> {
+		let mut counties = vec!["US-NJ".to_string(), "US-NY".to_string()];
+		if year >= 2020 {
+			counties.push("US-VA".to_string());
+		}
+		if Recurrence::new(1848, 2).occurs_in(year) {
+			counties.extend(vec![
+				"US-DE".to_string(),
+				"US-HI".to_string(),
+				"US-IN".to_string(),
+				"US-KY".to_string(),
+				"US-MT".to_string(),
+				"US-WV".to_string(),
+			]);
+		}
+
+		let date = DateSystem::find_day_after(year, 11, Weekday::Tue, Weekday::Mon, Occurrence::First)?;
+		Some(PublicHoliday::new_non_fixed(
+			HolidayId::US_ELECTION_DAY,
+			date,
+			"Election Day",
+			"Election Day",
+			Self::COUNTRYCODE,
+			None,
+			Some(counties),
+			PublicHolidayType::Public,
+		))
+	}
 }
 
 #[cfg(test)]
 mod tests {
+	use chrono_tz::Tz;
+
 	use super::UnitedStatesHolidayProvider;
 
 	#[test]
 	fn test_get_holidays() {
 		let result = UnitedStatesHolidayProvider::get_holidays(2022);
-		assert_eq!(result.len(), 13);
+		assert_eq!(result.len(), 34);
 		assert_eq!(result.is_empty(), false);
-		assert_eq!(result[5].local_name, "Memorial Day");
+		assert_eq!(result.iter().filter(|holiday| holiday.global()).count(), 10);
+		assert_eq!(result[3].local_name, "Lincoln's Birthday");
+	}
+
+	#[test]
+	fn test_inauguration_day() {
+		let result = UnitedStatesHolidayProvider::get_holidays(2025);
+		let inauguration_day = result
+			.iter()
+			.find(|holiday| holiday.name == "Inauguration Day")
+			.unwrap();
+		assert_eq!(inauguration_day.date.to_string(), "2025-01-20");
+		assert!(inauguration_day.is_in_county("US-DC"));
+		// the inauguration of 2013 was moved from sunday to monday
+		let result = UnitedStatesHolidayProvider::get_holidays(2013);
+		assert!(result
+			.iter()
+			.any(|holiday| holiday.to_string() == "2013-01-21 Inauguration Day"));
+		let result = UnitedStatesHolidayProvider::get_holidays(1933);
+		assert!(result
+			.iter()
+			.any(|holiday| holiday.to_string() == "1933-03-04 Inauguration Day"));
+		let result = UnitedStatesHolidayProvider::get_holidays(2026);
+		assert!(!result.iter().any(|holiday| holiday.name == "Inauguration Day"));
+	}
+
+	#[test]
+	fn test_election_day() {
+		let result = UnitedStatesHolidayProvider::get_holidays(2024);
+		let election_day = result.iter().find(|holiday| holiday.name == "Election Day").unwrap();
+		assert_eq!(election_day.date.to_string(), "2024-11-05");
+		assert!(election_day.is_in_county("US-HI"));
+		assert!(election_day.is_in_county("US-NY"));
+		assert!(!election_day.is_in_county("US-CA"));
+		// hawaii only observes the elections in even years
+		let result = UnitedStatesHolidayProvider::get_holidays(2025);
+		let election_day = result.iter().find(|holiday| holiday.name == "Election Day").unwrap();
+		assert_eq!(election_day.date.to_string(), "2025-11-04");
+		assert!(!election_day.is_in_county("US-HI"));
+		assert!(election_day.is_in_county("US-NJ"));
+	}
+
+	#[test]
+	fn test_state_holidays() {
+		let result = UnitedStatesHolidayProvider::get_holidays(2024);
+		let in_state = |name: &str, county: &str| {
+			result
+				.iter()
+				.any(|holiday| holiday.name == name && holiday.is_in_county(county))
+		};
+		assert!(in_state("Patriots' Day", "US-MA"));
+		assert!(in_state("Patriots' Day", "US-ME"));
+		assert!(!in_state("Patriots' Day", "US-NY"));
+		assert!(in_state("Mardi Gras", "US-LA"));
+		assert!(in_state("Pioneer Day", "US-UT"));
+		assert!(in_state("Kamehameha Day", "US-HI"));
+		assert!(in_state("Texas Independence Day", "US-TX"));
+		assert!(in_state("Emancipation Day", "US-DC"));
+		// cesar chavez day on a sunday is observed on monday
+		assert!(result
+			.iter()
+			.any(|holiday| holiday.to_string() == "2024-04-01 Cesar Chavez Day"));
+		assert!(result
+			.iter()
+			.any(|holiday| holiday.to_string() == "2024-02-13 Mardi Gras"));
+	}
+
+	#[test]
+	fn test_territories() {
+		let counties = UnitedStatesHolidayProvider::get_counties();
+		for county in ["US-DC", "US-PR", "US-GU", "US-VI", "US-AS", "US-MP"] {
+			assert!(counties.contains_key(county), "{county} is missing");
+		}
+		let result = UnitedStatesHolidayProvider::get_holidays(2024);
+		assert!(result
+			.iter()
+			.any(|holiday| holiday.name == "Puerto Rico Constitution Day" && holiday.is_in_county("US-PR")));
+		assert!(result
+			.iter()
+			.any(|holiday| holiday.name == "Guam Liberation Day" && holiday.is_in_county("US-GU")));
+		assert_eq!(
+			UnitedStatesHolidayProvider::get_time_zone(Some("US-GU")),
+			Tz::Pacific__Guam
+		);
+	}
+
+	#[test]
+	fn test_get_time_zone() {
+		assert_eq!(UnitedStatesHolidayProvider::get_time_zone(None), Tz::America__New_York);
+		assert_eq!(
+			UnitedStatesHolidayProvider::get_time_zone(Some("US-CA")),
+			Tz::America__Los_Angeles
+		);
+		assert_eq!(
+			UnitedStatesHolidayProvider::get_time_zone(Some("US-HI")),
+			Tz::Pacific__Honolulu
+		);
 	}
 }
@@ -0,0 +1,125 @@
+use chrono::{NaiveDate, Weekday};
+
+use crate::{
+	datesystem::DateSystem,
+	types::{
+		countrycode::CountryCode, holidayid::HolidayId, occurrence::Occurrence, publicholiday::PublicHoliday,
+		publicholidaytype::PublicHolidayType,
+	},
+};
+
+pub struct FederalReserveHolidayProvider {}
+
+impl FederalReserveHolidayProvider {
+	const COUNTRYCODE: CountryCode = CountryCode::US;
+
+	/// Gets the holidays of the federal reserve banks, on which no ACH and Fedwire payments are settled. Unlike the
+	/// federal government, the federal reserve doesn't observe a holiday on a saturday on the friday before, only a
+	/// holiday on a sunday is observed on the monday after.
+	pub fn get_holidays(year: i32) -> Vec<PublicHoliday> {
+		let mut holidays = vec![Self::fixed(HolidayId::NEW_YEARS_DAY, year, 1, 1, "New Year's Day")];
+
+		if year >= 1986 {
+			if let Some(date) = DateSystem::find_day(year, 1, Weekday::Mon, Occurrence::Third) {
+				holidays.push(Self::holiday(
+					HolidayId::US_MARTIN_LUTHER_KING_DAY,
+					date,
+					"Martin Luther King, Jr. Day",
+				));
+			}
+		}
+		if let Some(date) = DateSystem::find_day(year, 2, Weekday::Mon, Occurrence::Third) {
+			holidays.push(Self::holiday(
+				HolidayId::US_WASHINGTONS_BIRTHDAY,
+				date,
+				"Washington's Birthday",
+			));
+		}
+		if let Some(date) = DateSystem::find_last_day(year, 5, Weekday::Mon) {
+			holidays.push(Self::holiday(HolidayId::US_MEMORIAL_DAY, date, "Memorial Day"));
+		}
+		if year >= 2022 {
+			holidays.push(Self::fixed(HolidayId::US_JUNETEENTH, year, 6, 19, "Juneteenth"));
+		}
+		holidays.push(Self::fixed(
+			HolidayId::US_INDEPENDENCE_DAY,
+			year,
+			7,
+			4,
+			"Independence Day",
+		));
+		if let Some(date) = DateSystem::find_day(year, 9, Weekday::Mon, Occurrence::First) {
+			holidays.push(Self::holiday(HolidayId::US_LABOR_DAY, date, "Labor Day"));
+		}
+		if let Some(date) = DateSystem::find_day(year, 10, Weekday::Mon, Occurrence::Second) {
+			holidays.push(Self::holiday(HolidayId::US_COLUMBUS_DAY, date, "Columbus Day"));
+		}
+		holidays.push(Self::fixed(HolidayId::US_VETERANS_DAY, year, 11, 11, "Veterans Day"));
+		if let Some(date) = DateSystem::find_day(year, 11, Weekday::Thu, Occurrence::Fourth) {
+			holidays.push(Self::holiday(HolidayId::US_THANKSGIVING, date, "Thanksgiving Day"));
+		}
+		holidays.push(Self::fixed(HolidayId::CHRISTMAS_DAY, year, 12, 25, "Christmas Day"));
+
+		holidays.sort_by_key(|a| a.date);
+
+		holidays
+	}
+
+	/// Holiday on a fixed date, which is observed on the monday after if it falls on a sunday
+	fn fixed(id: HolidayId, year: i32, month: u32, day: u32, name: &str) -> PublicHoliday {
+		let date = DateSystem::shift(NaiveDate::from_ymd_opt(year, month, day).unwrap(), 0, 1, None);
+		Self::holiday(id, date, name)
+	}
+
+	fn holiday(id: HolidayId, date: NaiveDate, name: &str) -> PublicHoliday {
+		PublicHoliday::new_non_fixed(
+			id,
+			date,
+			name,
+			name,
+			Self::COUNTRYCODE,
+			None,
+			None,
+			PublicHolidayType::Bank,
+		)
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::FederalReserveHolidayProvider;
+
+	#[test]
+	fn test_get_holidays() {
+		let result: Vec<String> = FederalReserveHolidayProvider::get_holidays(2021)
+			.iter()
+			.map(|holiday| holiday.to_string())
+			.collect();
+		assert_eq!(
+			result,
+			vec![
+				"2021-01-01 New Year's Day",
+				"2021-01-18 Martin Luther King, Jr. Day",
+				"2021-02-15 Washington's Birthday",
+				"2021-05-31 Memorial Day",
+				"2021-07-05 Independence Day",
+				"2021-09-06 Labor Day",
+				"2021-10-11 Columbus Day",
+				"2021-11-11 Veterans Day",
+				"2021-11-25 Thanksgiving Day",
+				"2021-12-25 Christmas Day",
+			]
+		);
+	}
+
+	#[test]
+	fn test_saturday_is_not_observed() {
+		let result = FederalReserveHolidayProvider::get_holidays(2026);
+		let independence_day = result
+			.iter()
+			.find(|holiday| holiday.name == "Independence Day")
+			.unwrap();
+		assert_eq!(independence_day.date.to_string(), "2026-07-04");
+		assert!(!result.iter().any(|holiday| holiday.date.to_string() == "2026-07-03"));
+	}
+}
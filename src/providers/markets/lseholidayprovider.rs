@@ -0,0 +1,101 @@
+use chrono::{Datelike, NaiveDate, NaiveTime, Weekday};
+
+use crate::{
+	providers::locations::unitedkingdomholidayprovider::UnitedKingdomHolidayProvider,
+	types::{
+		countrycode::CountryCode, holidayid::HolidayId, publicholiday::PublicHoliday,
+		publicholidaytype::PublicHolidayType,
+	},
+};
+
+pub struct LseHolidayProvider {}
+
+impl LseHolidayProvider {
+	const COUNTRYCODE: CountryCode = CountryCode::GB;
+
+	/// Gets the closing days and early closes of the london stock exchange, which closes on the bank holidays of
+	/// england and wales, including the substitute days, and closes early on christmas eve and new year's eve
+	pub fn get_holidays(year: i32) -> Vec<PublicHoliday> {
+		let mut holidays: Vec<PublicHoliday> =
+			UnitedKingdomHolidayProvider::get_england_holidays(year, Self::COUNTRYCODE)
+				.into_iter()
+				.filter(|holiday| {
+					matches!(
+						holiday.holiday_type,
+						PublicHolidayType::Public | PublicHolidayType::Bank
+					)
+				})
+				.map(|mut holiday| {
+					holiday.set_holiday_type(PublicHolidayType::Bank);
+					holiday
+				})
+				.collect();
+
+		for day in [24, 31] {
+			let date = NaiveDate::from_ymd_opt(year, 12, day).unwrap();
+			if !matches!(date.weekday(), Weekday::Sat | Weekday::Sun) {
+				holidays.push(Self::early_close(date));
+			}
+		}
+
+		holidays.sort_by_key(|a| a.date);
+
+		holidays
+	}
+
+	/// The exchange closes at half past noon instead of half past four
+	fn early_close(date: NaiveDate) -> PublicHoliday {
+		let mut holiday = PublicHoliday::new_non_fixed(
+			HolidayId::EARLY_CLOSE,
+			date,
+			"Early Close",
+			"Early Close",
+			Self::COUNTRYCODE,
+			None,
+			None,
+			PublicHolidayType::Bank,
+		);
+		holiday.set_start_time(NaiveTime::from_hms_opt(12, 30, 0).unwrap());
+		holiday
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::LseHolidayProvider;
+
+	#[test]
+	fn test_get_holidays() {
+		let result: Vec<String> = LseHolidayProvider::get_holidays(2022)
+			.iter()
+			.map(|holiday| holiday.to_string())
+			.collect();
+		assert_eq!(
+			result,
+			vec![
+				"2022-01-03 New Year's Day",
+				"2022-04-15 Good Friday",
+				"2022-04-18 Easter Monday",
+				"2022-05-02 Early May Bank Holiday",
+				"2022-06-02 Spring Bank Holiday",
+				"2022-06-03 Queen’s Platinum Jubilee",
+				"2022-08-29 Summer Bank Holiday",
+				"2022-09-19 Queen’s State Funeral",
+				"2022-12-26 St. Stephen's Day",
+				"2022-12-27 Christmas Day",
+			]
+		);
+	}
+
+	#[test]
+	fn test_get_early_closes() {
+		let result = LseHolidayProvider::get_holidays(2024);
+		let early_closes: Vec<String> = result
+			.iter()
+			.filter(|holiday| holiday.is_partial_day())
+			.map(|holiday| holiday.date.to_string())
+			.collect();
+		assert_eq!(early_closes, vec!["2024-12-24", "2024-12-31"]);
+		assert!(result.iter().all(|holiday| holiday.global()));
+	}
+}
@@ -0,0 +1,5 @@
+pub mod federalreserveholidayprovider;
+pub mod lseholidayprovider;
+pub mod nyseholidayprovider;
+pub mod target2holidayprovider;
+pub mod xetraholidayprovider;
@@ -0,0 +1,222 @@
+use chrono::{Datelike, Duration, NaiveDate, NaiveTime, Weekday};
+
+use crate::{
+	datesystem::DateSystem,
+	providers::base::catholicprovider::CatholicProvider,
+	types::{
+		countrycode::CountryCode, holidayid::HolidayId, occurrence::Occurrence, publicholiday::PublicHoliday,
+		publicholidaytype::PublicHolidayType,
+	},
+};
+
+pub struct NyseHolidayProvider {}
+
+impl NyseHolidayProvider {
+	const COUNTRYCODE: CountryCode = CountryCode::US;
+
+	/// Gets the closing days and early closes of the new york stock exchange. A holiday on a saturday closes the
+	/// exchange on the friday before and a holiday on a sunday on the monday after, except for new year's day, which
+	/// isn't moved into the previous year. Veterans day and columbus day are trading days.
+	pub fn get_holidays(year: i32) -> Vec<PublicHoliday> {
+		let mut holidays = Vec::new();
+
+		let new_years_day = DateSystem::shift(NaiveDate::from_ymd_opt(year, 1, 1).unwrap(), 0, 1, None);
+		holidays.push(Self::closing(HolidayId::NEW_YEARS_DAY, new_years_day, "New Year's Day"));
+
+		if year >= 1998 {
+			if let Some(date) = DateSystem::find_day(year, 1, Weekday::Mon, Occurrence::Third) {
+				holidays.push(Self::closing(
+					HolidayId::US_MARTIN_LUTHER_KING_DAY,
+					date,
+					"Martin Luther King, Jr. Day",
+				));
+			}
+		}
+		if let Some(date) = DateSystem::find_day(year, 2, Weekday::Mon, Occurrence::Third) {
+			holidays.push(Self::closing(
+				HolidayId::US_WASHINGTONS_BIRTHDAY,
+				date,
+				"Washington's Birthday",
+			));
+		}
+		holidays.push(CatholicProvider::good_friday("Good Friday", year, Self::COUNTRYCODE));
+		if let Some(holiday) = holidays.last_mut() {
+			holiday.set_holiday_type(PublicHolidayType::Bank);
+		}
+		if let Some(date) = DateSystem::find_last_day(year, 5, Weekday::Mon) {
+			holidays.push(Self::closing(HolidayId::US_MEMORIAL_DAY, date, "Memorial Day"));
+		}
+		if year >= 2022 {
+			let juneteenth = DateSystem::shift(NaiveDate::from_ymd_opt(year, 6, 19).unwrap(), -1, 1, None);
+			holidays.push(Self::closing(HolidayId::US_JUNETEENTH, juneteenth, "Juneteenth"));
+		}
+
+		let independence_day = DateSystem::shift(NaiveDate::from_ymd_opt(year, 7, 4).unwrap(), -1, 1, None);
+		holidays.push(Self::closing(
+			HolidayId::US_INDEPENDENCE_DAY,
+			independence_day,
+			"Independence Day",
+		));
+		let independence_day_eve = NaiveDate::from_ymd_opt(year, 7, 3).unwrap();
+		if independence_day_eve.weekday().num_days_from_monday() < 4 {
+			holidays.push(Self::early_close(independence_day_eve));
+		}
+
+		if let Some(date) = DateSystem::find_day(year, 9, Weekday::Mon, Occurrence::First) {
+			holidays.push(Self::closing(HolidayId::US_LABOR_DAY, date, "Labor Day"));
+		}
+		if let Some(date) = DateSystem::find_day(year, 11, Weekday::Thu, Occurrence::Fourth) {
+			holidays.push(Self::closing(HolidayId::US_THANKSGIVING, date, "Thanksgiving Day"));
+			holidays.push(Self::early_close(date + Duration::days(1)));
+		}
+
+		let christmas_day = DateSystem::shift(NaiveDate::from_ymd_opt(year, 12, 25).unwrap(), -1, 1, None);
+		holidays.push(Self::closing(HolidayId::CHRISTMAS_DAY, christmas_day, "Christmas Day"));
+		let christmas_eve = NaiveDate::from_ymd_opt(year, 12, 24).unwrap();
+		if christmas_eve.weekday().num_days_from_monday() < 4 {
+			holidays.push(Self::early_close(christmas_eve));
+		}
+
+		holidays.extend(
+			Self::special_closings()
+				.into_iter()
+				.filter(|(date, _, _)| date.year() == year)
+				.map(|(date, id, name)| Self::closing(id, date, name)),
+		);
+
+		holidays.sort_by_key(|a| a.date);
+
+		holidays
+	}
+
+	/// Closings of the exchange which were declared for a single day, like the national days of mourning
+	fn special_closings() -> Vec<(NaiveDate, HolidayId, &'static str)> {
+		vec![
+			(
+				NaiveDate::from_ymd_opt(2001, 9, 11).unwrap(),
+				HolidayId::US_SEPTEMBER_11_ATTACKS,
+				"September 11 Attacks",
+			),
+			(
+				NaiveDate::from_ymd_opt(2001, 9, 12).unwrap(),
+				HolidayId::US_SEPTEMBER_11_ATTACKS,
+				"September 11 Attacks",
+			),
+			(
+				NaiveDate::from_ymd_opt(2001, 9, 13).unwrap(),
+				HolidayId::US_SEPTEMBER_11_ATTACKS,
+				"September 11 Attacks",
+			),
+			(
+				NaiveDate::from_ymd_opt(2001, 9, 14).unwrap(),
+				HolidayId::US_SEPTEMBER_11_ATTACKS,
+				"September 11 Attacks",
+			),
+			(
+				NaiveDate::from_ymd_opt(2004, 6, 11).unwrap(),
+				HolidayId::US_NATIONAL_DAY_OF_MOURNING,
+				"National Day of Mourning",
+			),
+			(
+				NaiveDate::from_ymd_opt(2007, 1, 2).unwrap(),
+				HolidayId::US_NATIONAL_DAY_OF_MOURNING,
+				"National Day of Mourning",
+			),
+			(
+				NaiveDate::from_ymd_opt(2012, 10, 29).unwrap(),
+				HolidayId::US_HURRICANE_SANDY,
+				"Hurricane Sandy",
+			),
+			(
+				NaiveDate::from_ymd_opt(2012, 10, 30).unwrap(),
+				HolidayId::US_HURRICANE_SANDY,
+				"Hurricane Sandy",
+			),
+			(
+				NaiveDate::from_ymd_opt(2018, 12, 5).unwrap(),
+				HolidayId::US_NATIONAL_DAY_OF_MOURNING,
+				"National Day of Mourning",
+			),
+			(
+				NaiveDate::from_ymd_opt(2025, 1, 9).unwrap(),
+				HolidayId::US_NATIONAL_DAY_OF_MOURNING,
+				"National Day of Mourning",
+			),
+		]
+	}
+
+	fn closing(id: HolidayId, date: NaiveDate, name: &str) -> PublicHoliday {
+		PublicHoliday::new_non_fixed(
+			id,
+			date,
+			name,
+			name,
+			Self::COUNTRYCODE,
+			None,
+			None,
+			PublicHolidayType::Bank,
+		)
+	}
+
+	/// The exchange closes at 1 p.m. instead of 4 p.m.
+	fn early_close(date: NaiveDate) -> PublicHoliday {
+		let mut holiday = Self::closing(HolidayId::EARLY_CLOSE, date, "Early Close");
+		holiday.set_start_time(NaiveTime::from_hms_opt(13, 0, 0).unwrap());
+		holiday
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::NyseHolidayProvider;
+
+	#[test]
+	fn test_get_holidays() {
+		let result: Vec<String> = NyseHolidayProvider::get_holidays(2024)
+			.iter()
+			.filter(|holiday| !holiday.is_partial_day())
+			.map(|holiday| holiday.to_string())
+			.collect();
+		assert_eq!(
+			result,
+			vec![
+				"2024-01-01 New Year's Day",
+				"2024-01-15 Martin Luther King, Jr. Day",
+				"2024-02-19 Washington's Birthday",
+				"2024-03-29 Good Friday",
+				"2024-05-27 Memorial Day",
+				"2024-06-19 Juneteenth",
+				"2024-07-04 Independence Day",
+				"2024-09-02 Labor Day",
+				"2024-11-28 Thanksgiving Day",
+				"2024-12-25 Christmas Day",
+			]
+		);
+	}
+
+	#[test]
+	fn test_get_early_closes() {
+		let result: Vec<String> = NyseHolidayProvider::get_holidays(2024)
+			.iter()
+			.filter(|holiday| holiday.is_partial_day())
+			.map(|holiday| holiday.date.to_string())
+			.collect();
+		assert_eq!(result, vec!["2024-07-03", "2024-11-29", "2024-12-24"]);
+		// independence day on a saturday closes the exchange on friday without an early close on thursday
+		let result = NyseHolidayProvider::get_holidays(2020);
+		assert!(result
+			.iter()
+			.any(|holiday| holiday.to_string() == "2020-07-03 Independence Day"));
+		assert!(!result
+			.iter()
+			.any(|holiday| holiday.is_partial_day() && holiday.date.to_string() == "2020-07-02"));
+	}
+
+	#[test]
+	fn test_new_years_day_on_saturday() {
+		let result = NyseHolidayProvider::get_holidays(2021);
+		assert!(!result.iter().any(|holiday| holiday.date.to_string() == "2021-12-31"));
+		let result = NyseHolidayProvider::get_holidays(2022);
+		assert_eq!(result[0].to_string(), "2022-01-01 New Year's Day");
+	}
+}
@@ -0,0 +1,72 @@
+use crate::{
+	providers::base::catholicprovider::CatholicProvider,
+	types::{
+		countrycode::CountryCode, holidayid::HolidayId, publicholiday::PublicHoliday,
+		publicholidaytype::PublicHolidayType,
+	},
+};
+
+pub struct Target2HolidayProvider {}
+
+impl Target2HolidayProvider {
+	const COUNTRYCODE: CountryCode = CountryCode::EU;
+
+	/// Gets the closing days of the TARGET2 payment system, which are the same in every country of the eurosystem.
+	/// National holidays of the member states are settlement days.
+	pub fn get_holidays(year: i32) -> Vec<PublicHoliday> {
+		let mut holidays = vec![
+			Self::closing(HolidayId::NEW_YEARS_DAY, year, 1, 1, "New Year's Day"),
+			CatholicProvider::good_friday("Good Friday", year, Self::COUNTRYCODE),
+			CatholicProvider::easter_monday("Easter Monday", year, Self::COUNTRYCODE),
+			Self::closing(HolidayId::LABOUR_DAY, year, 5, 1, "Labour Day"),
+			Self::closing(HolidayId::CHRISTMAS_DAY, year, 12, 25, "Christmas Day"),
+			Self::closing(HolidayId::CHRISTMAS_HOLIDAY, year, 12, 26, "Christmas Holiday"),
+		];
+		for holiday in holidays.iter_mut() {
+			holiday.set_holiday_type(PublicHolidayType::Bank);
+		}
+
+		holidays.sort_by_key(|a| a.date);
+
+		holidays
+	}
+
+	fn closing(id: HolidayId, year: i32, month: u32, day: u32, name: &str) -> PublicHoliday {
+		PublicHoliday::new_fixed(
+			id,
+			year,
+			month,
+			day,
+			name,
+			name,
+			Self::COUNTRYCODE,
+			None,
+			None,
+			PublicHolidayType::Bank,
+		)
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::Target2HolidayProvider;
+
+	#[test]
+	fn test_get_holidays() {
+		let result: Vec<String> = Target2HolidayProvider::get_holidays(2025)
+			.iter()
+			.map(|holiday| holiday.to_string())
+			.collect();
+		assert_eq!(
+			result,
+			vec![
+				"2025-01-01 New Year's Day",
+				"2025-04-18 Good Friday",
+				"2025-04-21 Easter Monday",
+				"2025-05-01 Labour Day",
+				"2025-12-25 Christmas Day",
+				"2025-12-26 Christmas Holiday",
+			]
+		);
+	}
+}
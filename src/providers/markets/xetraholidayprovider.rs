@@ -0,0 +1,90 @@
+use crate::{
+	providers::base::catholicprovider::CatholicProvider,
+	types::{
+		countrycode::CountryCode, holidayid::HolidayId, publicholiday::PublicHoliday,
+		publicholidaytype::PublicHolidayType,
+	},
+};
+
+pub struct XetraHolidayProvider {}
+
+impl XetraHolidayProvider {
+	const COUNTRYCODE: CountryCode = CountryCode::DE;
+
+	/// Gets the closing days of the xetra trading venue. Holidays on a weekend aren't moved and the regional holidays
+	/// of hesse as well as most national holidays, like german unity day, are trading days.
+	pub fn get_holidays(year: i32) -> Vec<PublicHoliday> {
+		let mut holidays = vec![
+			Self::closing(HolidayId::NEW_YEARS_DAY, year, 1, 1, "Neujahr", "New Year's Day"),
+			CatholicProvider::good_friday("Karfreitag", year, Self::COUNTRYCODE),
+			CatholicProvider::easter_monday("Ostermontag", year, Self::COUNTRYCODE),
+			Self::closing(HolidayId::LABOUR_DAY, year, 5, 1, "Tag der Arbeit", "Labour Day"),
+			Self::closing(HolidayId::CHRISTMAS_EVE, year, 12, 24, "Heiligabend", "Christmas Eve"),
+			Self::closing(
+				HolidayId::CHRISTMAS_DAY,
+				year,
+				12,
+				25,
+				"Erster Weihnachtstag",
+				"Christmas Day",
+			),
+			Self::closing(
+				HolidayId::ST_STEPHENS_DAY,
+				year,
+				12,
+				26,
+				"Zweiter Weihnachtstag",
+				"St. Stephen's Day",
+			),
+			Self::closing(HolidayId::NEW_YEARS_EVE, year, 12, 31, "Silvester", "New Year's Eve"),
+		];
+		for holiday in holidays.iter_mut() {
+			holiday.set_holiday_type(PublicHolidayType::Bank);
+		}
+
+		holidays.sort_by_key(|a| a.date);
+
+		holidays
+	}
+
+	fn closing(id: HolidayId, year: i32, month: u32, day: u32, local_name: &str, name: &str) -> PublicHoliday {
+		PublicHoliday::new_fixed(
+			id,
+			year,
+			month,
+			day,
+			local_name,
+			name,
+			Self::COUNTRYCODE,
+			None,
+			None,
+			PublicHolidayType::Bank,
+		)
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::XetraHolidayProvider;
+
+	#[test]
+	fn test_get_holidays() {
+		let result: Vec<String> = XetraHolidayProvider::get_holidays(2024)
+			.iter()
+			.map(|holiday| holiday.to_string())
+			.collect();
+		assert_eq!(
+			result,
+			vec![
+				"2024-01-01 New Year's Day",
+				"2024-03-29 Good Friday",
+				"2024-04-01 Easter Monday",
+				"2024-05-01 Labour Day",
+				"2024-12-24 Christmas Eve",
+				"2024-12-25 Christmas Day",
+				"2024-12-26 St. Stephen's Day",
+				"2024-12-31 New Year's Eve",
+			]
+		);
+	}
+}
@@ -1,2 +1,3 @@
 pub(crate) mod base;
 pub(crate) mod locations;
+pub(crate) mod markets;
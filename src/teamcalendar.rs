@@ -0,0 +1,232 @@
+use chrono::NaiveDate;
+
+use crate::{holidayprovider::HolidayProvider, types::location::Location};
+
+/// Calendar of a team which is spread over several countries and counties
+///
+/// A location is working on a date if it is a business day of its calendar, which considers the regional holidays of
+/// the county and the locality and the declared working days. The calendar is either the national provider or any
+/// other [`HolidayCalendar`](crate::holidaycalendar::HolidayCalendar), like a custom calendar with company holidays.
+///
+/// # Example
+///
+/// ```
+/// use horus::{teamcalendar::TeamCalendar, types::{countrycode::CountryCode, location::Location}};
+/// use chrono::NaiveDate;
+///
+/// let mut team = TeamCalendar::new();
+/// team.add_location(Location::new(CountryCode::DE, Some("DE-BY")))
+///     .add_location(Location::new(CountryCode::US, Some("US-CA")));
+///
+/// let all_saints_day = NaiveDate::from_ymd_opt(2024, 11, 1).unwrap();
+///
+/// assert_eq!(team.get_absent_locations(all_saints_day)[0].to_string(), "DE-BY");
+/// assert_eq!(team.get_availability(all_saints_day), 0.5);
+/// ```
+#[derive(Debug, Clone, Default)]
+pub struct TeamCalendar {
+	locations: Vec<Location>,
+}
+
+impl TeamCalendar {
+	pub fn new() -> Self {
+		TeamCalendar { locations: Vec::new() }
+	}
+
+	pub fn locations(&self) -> &[Location] {
+		&self.locations
+	}
+
+	pub fn add_location(&mut self, location: Location) -> &mut Self {
+		self.locations.push(location);
+		self
+	}
+
+	/// Gets all dates between the start and the end (including both) on which every location is working
+	///
+	/// # Example
+	///
+	/// ```
+	/// use horus::{teamcalendar::TeamCalendar, types::{countrycode::CountryCode, location::Location}};
+	/// use chrono::NaiveDate;
+	///
+	/// let mut team = TeamCalendar::new();
+	/// team.add_location(Location::new(CountryCode::PL, None))
+	///     .add_location(Location::new(CountryCode::US, Some("US-CA")));
+	///
+	/// let start = NaiveDate::from_ymd_opt(2024, 11, 25).unwrap();
+	/// let end = NaiveDate::from_ymd_opt(2024, 12, 1).unwrap();
+	///
+	/// // thanksgiving on thursday and the weekend
+	/// assert_eq!(team.get_working_days(start, end).len(), 4);
+	/// ```
+	pub fn get_working_days(&self, start: NaiveDate, end: NaiveDate) -> Vec<NaiveDate> {
+		start
+			.iter_days()
+			.take_while(|date| *date <= end)
+			.filter(|date| self.get_absent_locations(*date).is_empty())
+			.collect()
+	}
+
+	/// Gets all locations which are off on the given date
+	pub fn get_absent_locations(&self, date: NaiveDate) -> Vec<&Location> {
+		self.locations
+			.iter()
+			.filter(|location| !Self::is_working(location, date))
+			.collect()
+	}
+
+	/// Gets the fraction of the team which is working on the given date, weighted by the headcount of the locations
+	pub fn get_availability(&self, date: NaiveDate) -> f64 {
+		let headcount: u32 = self.locations.iter().map(|location| location.headcount).sum();
+		if headcount == 0 {
+			return 0.0;
+		}
+
+		let working: u32 = self
+			.locations
+			.iter()
+			.filter(|location| Self::is_working(location, date))
+			.map(|location| location.headcount)
+			.sum();
+
+		working as f64 / headcount as f64
+	}
+
+	/// Gets the fraction of the team which is working for every date between the start and the end (including both)
+	pub fn get_availability_range(&self, start: NaiveDate, end: NaiveDate) -> Vec<(NaiveDate, f64)> {
+		start
+			.iter_days()
+			.take_while(|date| *date <= end)
+			.map(|date| (date, self.get_availability(date)))
+			.collect()
+	}
+
+	fn is_working(location: &Location, date: NaiveDate) -> bool {
+		HolidayProvider::is_business_day_in_locality(
+			date,
+			&*location.calendar,
+			location.county.as_deref(),
+			location.locality.as_deref(),
+		)
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use chrono::NaiveDate;
+
+	use crate::{
+		customcalendar::CustomCalendar,
+		types::{countrycode::CountryCode, location::Location, market::Market, publicholidaytype::PublicHolidayType},
+	};
+
+	use super::TeamCalendar;
+
+	fn location(country_code: CountryCode, county: Option<&str>, headcount: u32) -> Location {
+		let mut location = Location::new(country_code, county);
+		location.set_headcount(headcount);
+		location
+	}
+
+	fn team() -> TeamCalendar {
+		let mut team = TeamCalendar::new();
+		team.add_location(location(CountryCode::DE, Some("DE-BY"), 10))
+			.add_location(location(CountryCode::GB, Some("GB-SCT"), 5))
+			.add_location(location(CountryCode::PL, None, 3))
+			.add_location(location(CountryCode::US, Some("US-CA"), 2));
+		team
+	}
+
+	#[test]
+	fn test_get_working_days() {
+		let start = NaiveDate::from_ymd_opt(2024, 11, 25).unwrap();
+		let end = NaiveDate::from_ymd_opt(2024, 12, 1).unwrap();
+		let result = team().get_working_days(start, end);
+		assert_eq!(
+			result,
+			vec![
+				NaiveDate::from_ymd_opt(2024, 11, 25).unwrap(),
+				NaiveDate::from_ymd_opt(2024, 11, 26).unwrap(),
+				NaiveDate::from_ymd_opt(2024, 11, 27).unwrap(),
+				NaiveDate::from_ymd_opt(2024, 11, 29).unwrap(),
+			]
+		);
+	}
+
+	#[test]
+	fn test_get_absent_locations() {
+		let team = team();
+		let result = team.get_absent_locations(NaiveDate::from_ymd_opt(2024, 11, 1).unwrap());
+		assert_eq!(
+			result.iter().map(|location| location.to_string()).collect::<Vec<_>>(),
+			vec!["DE-BY", "PL"]
+		);
+		// the summer bank holiday in scotland is at the beginning of august
+		let result = team.get_absent_locations(NaiveDate::from_ymd_opt(2024, 8, 5).unwrap());
+		assert_eq!(result.len(), 1);
+		assert_eq!(result[0].to_string(), "GB-SCT");
+	}
+
+	#[test]
+	fn test_get_availability() {
+		let team = team();
+		assert_eq!(
+			team.get_availability(NaiveDate::from_ymd_opt(2024, 11, 1).unwrap()),
+			0.35
+		);
+		assert_eq!(
+			team.get_availability(NaiveDate::from_ymd_opt(2024, 11, 28).unwrap()),
+			0.9
+		);
+		assert_eq!(
+			team.get_availability(NaiveDate::from_ymd_opt(2024, 11, 30).unwrap()),
+			0.0
+		);
+		assert_eq!(
+			TeamCalendar::new().get_availability(NaiveDate::from_ymd_opt(2024, 11, 1).unwrap()),
+			0.0
+		);
+	}
+
+	#[test]
+	fn test_custom_calendar_and_market() {
+		let mut company = CustomCalendar::new(CountryCode::DE);
+		company.set_county("DE-BE").add_fixed_holiday(
+			12,
+			24,
+			"Heiligabend",
+			"Christmas Eve",
+			PublicHolidayType::Public,
+		);
+		let mut team = TeamCalendar::new();
+		team.add_location(Location::from_calendar("Berlin office", company, None))
+			.add_location(Location::from_calendar("Trading desk", Market::NYSE, None));
+
+		let christmas_eve = NaiveDate::from_ymd_opt(2024, 12, 24).unwrap();
+		let result = team.get_absent_locations(christmas_eve);
+		assert_eq!(result.len(), 1);
+		assert_eq!(result[0].to_string(), "Berlin office");
+		// the stock exchange is closed on good friday, while it is a holiday in all german counties
+		assert_eq!(
+			team.get_availability(NaiveDate::from_ymd_opt(2024, 3, 29).unwrap()),
+			0.0
+		);
+		// the berlin office falls back to the county of its calendar on international women's day
+		let result = team.get_absent_locations(NaiveDate::from_ymd_opt(2024, 3, 8).unwrap());
+		assert_eq!(result.len(), 1);
+		assert_eq!(result[0].to_string(), "Berlin office");
+	}
+
+	#[test]
+	fn test_get_availability_range() {
+		let start = NaiveDate::from_ymd_opt(2024, 12, 23).unwrap();
+		let end = NaiveDate::from_ymd_opt(2024, 12, 27).unwrap();
+		let result = team().get_availability_range(start, end);
+		assert_eq!(result.len(), 5);
+		assert_eq!(result[0].1, 1.0);
+		assert_eq!(result[2].1, 0.0);
+		// everyone is back after the second day of christmas
+		assert_eq!(result[4].1, 1.0);
+	}
+}
@@ -0,0 +1,169 @@
+mod names;
+
+use std::{collections::HashMap, sync::RwLock};
+
+use lazy_static::lazy_static;
+
+use crate::types::holidayid::HolidayId;
+
+use self::names::{LOCALES, NAMES};
+
+lazy_static! {
+	static ref TRANSLATIONS: RwLock<HashMap<(HolidayId, String), String>> = RwLock::new(
+		NAMES
+			.iter()
+			.flat_map(|(id, names)| {
+				LOCALES
+					.iter()
+					.zip(names.iter())
+					.map(move |(locale, name)| ((id.clone(), locale.to_string()), name.to_string()))
+			})
+			.collect()
+	);
+}
+
+pub struct TranslationCatalog {}
+
+/// Catalog of the holiday names in different languages, keyed by the [`HolidayId`] and the locale
+///
+/// The catalog contains german, greek, english, spanish, french, polish, russian and ukrainian names for the holidays
+/// of the national providers. Translations can be added or overridden at runtime and are shared by the whole process.
+///
+/// # Example
+///
+/// ```
+/// use horus::{translations::TranslationCatalog, types::holidayid::HolidayId};
+///
+/// let id = HolidayId::EASTER_MONDAY;
+///
+/// assert_eq!(TranslationCatalog::get(&id, "fr"), Some("Lundi de Pâques".to_string()));
+/// assert_eq!(TranslationCatalog::get(&id, "de-AT"), Some("Ostermontag".to_string()));
+/// ```
+impl TranslationCatalog {
+	/// Gets the name of the holiday in the given locale. If no name exists for a regional locale like `de-AT`, the
+	/// name of the language is used. If no name is found at all, None will be returned.
+	pub fn get(id: &HolidayId, locale: &str) -> Option<String> {
+		let translations = TRANSLATIONS.read().unwrap();
+		let locale = Self::normalize(locale);
+
+		translations
+			.get(&(id.clone(), locale.clone()))
+			.or_else(|| {
+				let language = locale.split('-').next()?;
+				translations.get(&(id.clone(), language.to_string()))
+			})
+			.cloned()
+	}
+
+	/// Adds the name of the holiday in the given locale, an existing name will be overridden
+	///
+	/// # Example
+	///
+	/// ```
+	/// use horus::{translations::TranslationCatalog, types::holidayid::HolidayId};
+	///
+	/// let id = HolidayId::EPIPHANY;
+	/// TranslationCatalog::add(&id, "de-AT", "Dreikönigstag");
+	///
+	/// assert_eq!(TranslationCatalog::get(&id, "de-AT"), Some("Dreikönigstag".to_string()));
+	/// assert_eq!(TranslationCatalog::get(&id, "de"), Some("Heilige Drei Könige".to_string()));
+	/// ```
+	pub fn add(id: &HolidayId, locale: &str, name: &str) {
+		TRANSLATIONS
+			.write()
+			.unwrap()
+			.insert((id.clone(), Self::normalize(locale)), name.to_string());
+	}
+
+	/// Removes the name of the holiday in the given locale. Returns true if a name was removed.
+	pub fn remove(id: &HolidayId, locale: &str) -> bool {
+		TRANSLATIONS
+			.write()
+			.unwrap()
+			.remove(&(id.clone(), Self::normalize(locale)))
+			.is_some()
+	}
+
+	/// Locales are compared in lower case with a hyphen as separator, so `pt_BR` and `pt-br` are the same locale
+	fn normalize(locale: &str) -> String {
+		locale.trim().replace('_', "-").to_lowercase()
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use crate::{
+		holidayprovider::HolidayProvider,
+		types::{countrycode::CountryCode, holidayid::HolidayId},
+	};
+
+	use super::{names::LOCALES, TranslationCatalog};
+
+	#[test]
+	fn test_get() {
+		let id = HolidayId::CHRISTMAS_DAY;
+		assert_eq!(TranslationCatalog::get(&id, "uk"), Some("Різдво".to_string()));
+		assert_eq!(TranslationCatalog::get(&id, "PL"), Some("Boże Narodzenie".to_string()));
+		assert_eq!(TranslationCatalog::get(&id, "es_MX"), Some("Navidad".to_string()));
+		assert_eq!(TranslationCatalog::get(&id, "ja"), None);
+		assert_eq!(TranslationCatalog::get(&HolidayId::new("UNKNOWN"), "de"), None);
+		assert_eq!(
+			TranslationCatalog::get(&HolidayId::BOXING_DAY, "de"),
+			Some("Zweiter Weihnachtsfeiertag".to_string())
+		);
+		assert_eq!(
+			TranslationCatalog::get(&HolidayId::CLEAN_MONDAY, "de"),
+			Some("Reiner Montag".to_string())
+		);
+		assert_eq!(
+			TranslationCatalog::get(&HolidayId::CHILDRENS_DAY, "ru"),
+			Some("День детей".to_string())
+		);
+	}
+
+	#[test]
+	fn test_add_and_remove() {
+		let id = HolidayId::new("TEST_HOLIDAY");
+		TranslationCatalog::add(&id, "fr", "Jour de test");
+		assert_eq!(TranslationCatalog::get(&id, "fr-CA"), Some("Jour de test".to_string()));
+		TranslationCatalog::add(&id, "fr", "Journée de test");
+		assert_eq!(TranslationCatalog::get(&id, "fr"), Some("Journée de test".to_string()));
+		assert!(TranslationCatalog::remove(&id, "fr"));
+		assert!(!TranslationCatalog::remove(&id, "fr"));
+		assert_eq!(TranslationCatalog::get(&id, "fr"), None);
+	}
+
+	#[test]
+	fn test_holidays_of_providers_are_translated() {
+		let countries = [
+			CountryCode::CN,
+			CountryCode::DE,
+			CountryCode::GB,
+			CountryCode::GG,
+			CountryCode::GR,
+			CountryCode::IL,
+			CountryCode::IM,
+			CountryCode::JE,
+			CountryCode::JP,
+			CountryCode::KR,
+			CountryCode::PL,
+			CountryCode::RU,
+			CountryCode::SA,
+			CountryCode::UA,
+			CountryCode::US,
+		];
+		for country_code in countries {
+			for year in 2000..=2030 {
+				for holiday in HolidayProvider::get_holidays(year, country_code) {
+					for locale in LOCALES {
+						assert!(
+							TranslationCatalog::get(&holiday.id, locale).is_some(),
+							"{} has no name in {locale}",
+							holiday.id
+						);
+					}
+				}
+			}
+		}
+	}
+}
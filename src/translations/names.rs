@@ -0,0 +1,1715 @@
+use crate::types::holidayid::HolidayId;
+
+/// Locales of the built-in names, in the order of the names in [`NAMES`]
+pub(super) const LOCALES: [&str; 8] = ["de", "el", "en", "es", "fr", "pl", "ru", "uk"];
+
+/// Built-in names of the holidays of the national providers, keyed by the [`HolidayId`]
+pub(super) const NAMES: &[(HolidayId, [&str; 8])] = &[
+	(
+		HolidayId::ALL_SAINTS_DAY,
+		[
+			"Allerheiligen",
+			"Αγίων Πάντων",
+			"All Saints' Day",
+			"Día de Todos los Santos",
+			"Toussaint",
+			"Wszystkich Świętych",
+			"День всех святых",
+			"День усіх святих",
+		],
+	),
+	(
+		HolidayId::ANNUNCIATION,
+		[
+			"Mariä Verkündigung",
+			"Ευαγγελισμός της Θεοτόκου",
+			"Annunciation",
+			"Anunciación",
+			"Annonciation",
+			"Zwiastowanie Pańskie",
+			"Благовещение",
+			"Благовіщення",
+		],
+	),
+	(
+		HolidayId::ARAFAT_DAY,
+		[
+			"Arafat-Tag",
+			"Ημέρα του Αραφάτ",
+			"Arafat Day",
+			"Día de Arafat",
+			"Jour d'Arafat",
+			"Dzień Arafat",
+			"День Арафат",
+			"День Арафат",
+		],
+	),
+	(
+		HolidayId::ASCENSION_DAY,
+		[
+			"Christi Himmelfahrt",
+			"Ανάληψη",
+			"Ascension Day",
+			"Ascensión",
+			"Ascension",
+			"Wniebowstąpienie Pańskie",
+			"Вознесение",
+			"Вознесіння",
+		],
+	),
+	(
+		HolidayId::ASSUMPTION_DAY,
+		[
+			"Mariä Himmelfahrt",
+			"Κοίμηση της Θεοτόκου",
+			"Assumption Day",
+			"Asunción",
+			"Assomption",
+			"Wniebowzięcie Najświętszej Maryi Panny",
+			"Успение Богородицы",
+			"Успіння Богородиці",
+		],
+	),
+	(
+		HolidayId::BOXING_DAY,
+		[
+			"Zweiter Weihnachtsfeiertag",
+			"Δεύτερη μέρα των Χριστουγέννων",
+			"Boxing Day",
+			"Segundo día de Navidad",
+			"Lendemain de Noël",
+			"Drugi dzień świąt Bożego Narodzenia",
+			"День подарков",
+			"День подарунків",
+		],
+	),
+	(
+		HolidayId::BUDDHAS_BIRTHDAY,
+		[
+			"Buddhas Geburtstag",
+			"Γενέθλια του Βούδα",
+			"Buddha's Birthday",
+			"Nacimiento de Buda",
+			"Anniversaire de Bouddha",
+			"Urodziny Buddy",
+			"День рождения Будды",
+			"День народження Будди",
+		],
+	),
+	(
+		HolidayId::CHILDRENS_DAY,
+		[
+			"Kindertag",
+			"Ημέρα του Παιδιού",
+			"Children's Day",
+			"Día del Niño",
+			"Journée des enfants",
+			"Dzień Dziecka",
+			"День детей",
+			"День дітей",
+		],
+	),
+	(
+		HolidayId::CHRISTMAS_DAY,
+		[
+			"Erster Weihnachtstag",
+			"Χριστούγεννα",
+			"Christmas Day",
+			"Navidad",
+			"Noël",
+			"Boże Narodzenie",
+			"Рождество",
+			"Різдво",
+		],
+	),
+	(
+		HolidayId::CLEAN_MONDAY,
+		[
+			"Reiner Montag",
+			"Καθαρά Δευτέρα",
+			"Clean Monday",
+			"Lunes Limpio",
+			"Lundi pur",
+			"Czysty Poniedziałek",
+			"Чистый понедельник",
+			"Чистий понеділок",
+		],
+	),
+	(
+		HolidayId::CN_NATIONAL_DAY,
+		[
+			"Nationalfeiertag",
+			"Εθνική εορτή",
+			"National Day",
+			"Día Nacional",
+			"Fête nationale",
+			"Święto Narodowe",
+			"Национальный день",
+			"Національний день",
+		],
+	),
+	(
+		HolidayId::CORPUS_CHRISTI,
+		[
+			"Fronleichnam",
+			"Αγία Ευχαριστία",
+			"Corpus Christi",
+			"Corpus Christi",
+			"Fête-Dieu",
+			"Boże Ciało",
+			"Праздник Тела и Крови Христовых",
+			"Свято Тіла і Крові Христових",
+		],
+	),
+	(
+		HolidayId::DE_AUGSBURG_PEACE_FESTIVAL,
+		[
+			"Augsburger Hohes Friedensfest",
+			"Υψηλή Γιορτή Ειρήνης του Άουγκσμπουργκ",
+			"Augsburg Peace Festival",
+			"Fiesta de la Paz de Augsburgo",
+			"Fête de la paix d'Augsbourg",
+			"Augsburskie Święto Pokoju",
+			"Аугсбургский праздник мира",
+			"Аугсбурзьке свято миру",
+		],
+	),
+	(
+		HolidayId::DE_GERMAN_UNITY_DAY,
+		[
+			"Tag der Deutschen Einheit",
+			"Ημέρα της Γερμανικής Ενότητας",
+			"German Unity Day",
+			"Día de la Unidad Alemana",
+			"Jour de l'unité allemande",
+			"Dzień Jedności Niemiec",
+			"День германского единства",
+			"День єдності Німеччини",
+		],
+	),
+	(
+		HolidayId::DE_LIBERATION_DAY,
+		[
+			"Tag der Befreiung",
+			"Ημέρα της Απελευθέρωσης",
+			"Liberation Day",
+			"Día de la Liberación",
+			"Jour de la Libération",
+			"Dzień Wyzwolenia",
+			"День освобождения",
+			"День визволення",
+		],
+	),
+	(
+		HolidayId::DE_REFORMATION_DAY,
+		[
+			"Reformationstag",
+			"Ημέρα της Μεταρρύθμισης",
+			"Reformation Day",
+			"Día de la Reforma",
+			"Fête de la Réformation",
+			"Święto Reformacji",
+			"День Реформации",
+			"День Реформації",
+		],
+	),
+	(
+		HolidayId::DE_REPENTANCE_AND_PRAYER_DAY,
+		[
+			"Buß- und Bettag",
+			"Ημέρα μετάνοιας και προσευχής",
+			"Repentance and Prayer Day",
+			"Día de Arrepentimiento y Oración",
+			"Jour de repentance et de prière",
+			"Dzień Pokuty i Modlitwy",
+			"День покаяния и молитвы",
+			"День покаяння і молитви",
+		],
+	),
+	(
+		HolidayId::DE_WORLD_CHILDRENS_DAY,
+		[
+			"Weltkindertag",
+			"Παγκόσμια Ημέρα του Παιδιού",
+			"World Children's Day",
+			"Día Universal del Niño",
+			"Journée mondiale de l'enfance",
+			"Światowy Dzień Dziecka",
+			"Всемирный день ребёнка",
+			"Всесвітній день дитини",
+		],
+	),
+	(
+		HolidayId::DRAGON_BOAT_FESTIVAL,
+		[
+			"Drachenbootfest",
+			"Γιορτή των Δρακόβαρκων",
+			"Dragon Boat Festival",
+			"Festival del Bote del Dragón",
+			"Fête des bateaux-dragons",
+			"Święto Smoczych Łodzi",
+			"Праздник драконьих лодок",
+			"Свято човнів-драконів",
+		],
+	),
+	(
+		HolidayId::EASTER_MONDAY,
+		[
+			"Ostermontag",
+			"Δευτέρα του Πάσχα",
+			"Easter Monday",
+			"Lunes de Pascua",
+			"Lundi de Pâques",
+			"Poniedziałek Wielkanocny",
+			"Пасхальный понедельник",
+			"Великодній понеділок",
+		],
+	),
+	(
+		HolidayId::EASTER_SUNDAY,
+		[
+			"Ostersonntag",
+			"Κυριακή του Πάσχα",
+			"Easter Sunday",
+			"Domingo de Pascua",
+			"Pâques",
+			"Wielkanoc",
+			"Пасха",
+			"Великдень",
+		],
+	),
+	(
+		HolidayId::EID_AL_ADHA,
+		[
+			"Opferfest",
+			"Ιντ αλ-Άντχα",
+			"Eid al-Adha",
+			"Fiesta del Sacrificio",
+			"Aïd al-Adha",
+			"Id al-Adha",
+			"Курбан-байрам",
+			"Курбан-байрам",
+		],
+	),
+	(
+		HolidayId::EID_AL_FITR,
+		[
+			"Fest des Fastenbrechens",
+			"Ιντ αλ-Φιτρ",
+			"Eid al-Fitr",
+			"Fiesta del fin del Ramadán",
+			"Aïd al-Fitr",
+			"Id al-Fitr",
+			"Ураза-байрам",
+			"Ураза-байрам",
+		],
+	),
+	(
+		HolidayId::EPIPHANY,
+		[
+			"Heilige Drei Könige",
+			"Θεοφάνεια",
+			"Epiphany",
+			"Epifanía",
+			"Épiphanie",
+			"Święto Trzech Króli",
+			"Богоявление",
+			"Богоявлення",
+		],
+	),
+	(
+		HolidayId::GB_BATTLE_OF_THE_BOYNE,
+		[
+			"Schlacht am Boyne",
+			"Μάχη του Μπόιν",
+			"Battle of the Boyne",
+			"Batalla del Boyne",
+			"Bataille de la Boyne",
+			"Bitwa nad Boyne",
+			"Битва на реке Бойн",
+			"Битва на річці Бойн",
+		],
+	),
+	(
+		HolidayId::GB_CORONATION_BANK_HOLIDAY,
+		[
+			"Feiertag zur Krönung",
+			"Αργία της στέψης",
+			"Coronation Bank Holiday",
+			"Feriado de la coronación",
+			"Jour férié du couronnement",
+			"Święto koronacji",
+			"Выходной в честь коронации",
+			"Вихідний на честь коронації",
+		],
+	),
+	(
+		HolidayId::GB_EARLY_MAY_BANK_HOLIDAY,
+		[
+			"Feiertag Anfang Mai",
+			"Αργία αρχών Μαΐου",
+			"Early May Bank Holiday",
+			"Feriado de principios de mayo",
+			"Jour férié de début mai",
+			"Święto na początku maja",
+			"Банковский выходной в начале мая",
+			"Банківський вихідний на початку травня",
+		],
+	),
+	(
+		HolidayId::GB_EDINBURGH_AUTUMN_HOLIDAY,
+		[
+			"Edinburgher Herbstfeiertag",
+			"Φθινοπωρινή αργία του Εδιμβούργου",
+			"Edinburgh Autumn Holiday",
+			"Festivo de otoño de Edimburgo",
+			"Congé d'automne d'Édimbourg",
+			"Jesienne Święto Edynburga",
+			"Осенний праздник Эдинбурга",
+			"Осіннє свято Единбурга",
+		],
+	),
+	(
+		HolidayId::GB_EDINBURGH_SPRING_HOLIDAY,
+		[
+			"Edinburgher Frühlingsfeiertag",
+			"Ανοιξιάτικη αργία του Εδιμβούργου",
+			"Edinburgh Spring Holiday",
+			"Festivo de primavera de Edimburgo",
+			"Congé de printemps d'Édimbourg",
+			"Wiosenne Święto Edynburga",
+			"Весенний праздник Эдинбурга",
+			"Весняне свято Единбурга",
+		],
+	),
+	(
+		HolidayId::GB_GLASGOW_FAIR,
+		[
+			"Glasgow Fair",
+			"Πανηγύρι της Γλασκώβης",
+			"Glasgow Fair",
+			"Feria de Glasgow",
+			"Foire de Glasgow",
+			"Jarmark Glasgowski",
+			"Ярмарка Глазго",
+			"Ярмарок Глазго",
+		],
+	),
+	(
+		HolidayId::GB_GLASGOW_SEPTEMBER_WEEKEND,
+		[
+			"Glasgower Septemberwochenende",
+			"Σεπτεμβριανό Σαββατοκύριακο της Γλασκώβης",
+			"Glasgow September Weekend",
+			"Fin de semana de septiembre de Glasgow",
+			"Week-end de septembre de Glasgow",
+			"Wrześniowy Weekend w Glasgow",
+			"Сентябрьские выходные в Глазго",
+			"Вересневі вихідні в Глазго",
+		],
+	),
+	(
+		HolidayId::GB_PLATINUM_JUBILEE,
+		[
+			"Platinjubiläum der Königin",
+			"Πλατινένιο Ιωβηλαίο της Βασίλισσας",
+			"Queen’s Platinum Jubilee",
+			"Jubileo de Platino de la Reina",
+			"Jubilé de platine de la Reine",
+			"Platynowy Jubileusz Królowej",
+			"Платиновый юбилей королевы",
+			"Платиновий ювілей королеви",
+		],
+	),
+	(
+		HolidayId::GB_SPRING_BANK_HOLIDAY,
+		[
+			"Feiertag im Frühling",
+			"Ανοιξιάτικη αργία",
+			"Spring Bank Holiday",
+			"Feriado de primavera",
+			"Jour férié de printemps",
+			"Święto wiosenne",
+			"Весенний банковский выходной",
+			"Весняний банківський вихідний",
+		],
+	),
+	(
+		HolidayId::GB_STATE_FUNERAL,
+		[
+			"Staatsbegräbnis der Königin",
+			"Κρατική κηδεία της Βασίλισσας",
+			"Queen’s State Funeral",
+			"Funeral de Estado de la Reina",
+			"Funérailles nationales de la Reine",
+			"Pogrzeb państwowy Królowej",
+			"Государственные похороны королевы",
+			"Державний похорон королеви",
+		],
+	),
+	(
+		HolidayId::GB_SUMMER_BANK_HOLIDAY,
+		[
+			"Feiertag im Sommer",
+			"Καλοκαιρινή αργία",
+			"Summer Bank Holiday",
+			"Feriado de verano",
+			"Jour férié d'été",
+			"Święto letnie",
+			"Летний банковский выходной",
+			"Літній банківський вихідний",
+		],
+	),
+	(
+		HolidayId::GB_VICTORIA_DAY,
+		[
+			"Victoria Day",
+			"Ημέρα της Βικτώριας",
+			"Victoria Day",
+			"Día de Victoria",
+			"Fête de la Reine",
+			"Dzień Wiktorii",
+			"День Виктории",
+			"День Вікторії",
+		],
+	),
+	(
+		HolidayId::GG_LIBERATION_DAY,
+		[
+			"Tag der Befreiung",
+			"Ημέρα Απελευθέρωσης",
+			"Liberation Day",
+			"Día de la Liberación",
+			"Jour de la Libération",
+			"Dzień Wyzwolenia",
+			"День освобождения",
+			"День визволення",
+		],
+	),
+	(
+		HolidayId::GOOD_FRIDAY,
+		[
+			"Karfreitag",
+			"Μεγάλη Παρασκευή",
+			"Good Friday",
+			"Viernes Santo",
+			"Vendredi saint",
+			"Wielki Piątek",
+			"Страстная пятница",
+			"Страсна п'ятниця",
+		],
+	),
+	(
+		HolidayId::GR_INDEPENDENCE_DAY,
+		[
+			"Unabhängigkeitstag",
+			"Ημέρα της Ανεξαρτησίας",
+			"Independence Day",
+			"Día de la Independencia",
+			"Fête de l'indépendance",
+			"Święto Niepodległości",
+			"День независимости",
+			"День Незалежності",
+		],
+	),
+	(
+		HolidayId::GR_OCHI_DAY,
+		[
+			"Ochi-Tag",
+			"Το Όχι",
+			"Ochi Day",
+			"Día del No",
+			"Jour du Non",
+			"Dzień Ochi",
+			"День «Охи»",
+			"День «Охі»",
+		],
+	),
+	(
+		HolidayId::GR_SYNAXIS_OF_THE_THEOTOKOS,
+		[
+			"Synaxis der Gottesmutter",
+			"Σύναξις Υπεραγίας Θεοτόκου",
+			"Synaxis of the Theotokos",
+			"Sinaxis de la Theotokos",
+			"Synaxe de la Théotokos",
+			"Synaksa Bogurodzicy",
+			"Собор Пресвятой Богородицы",
+			"Собор Пресвятої Богородиці",
+		],
+	),
+	(
+		HolidayId::IL_INDEPENDENCE_DAY,
+		[
+			"Unabhängigkeitstag",
+			"Ημέρα της Ανεξαρτησίας",
+			"Independence Day",
+			"Día de la Independencia",
+			"Fête de l'indépendance",
+			"Święto Niepodległości",
+			"День независимости",
+			"День Незалежності",
+		],
+	),
+	(
+		HolidayId::IM_TT_SENIOR_RACE_DAY,
+		[
+			"TT-Senior-Renntag",
+			"Ημέρα του αγώνα TT Senior",
+			"TT Senior Race Day",
+			"Día de la carrera TT Senior",
+			"Jour de la course TT Senior",
+			"Dzień wyścigu TT Senior",
+			"День гонки TT Senior",
+			"День перегонів TT Senior",
+		],
+	),
+	(
+		HolidayId::IM_TYNWALD_DAY,
+		[
+			"Tynwald-Tag",
+			"Ημέρα του Τίνγουολντ",
+			"Tynwald Day",
+			"Día de Tynwald",
+			"Jour de Tynwald",
+			"Dzień Tynwaldu",
+			"День Тинвальда",
+			"День Тінвальда",
+		],
+	),
+	(
+		HolidayId::INTERNATIONAL_WOMENS_DAY,
+		[
+			"Internationaler Frauentag",
+			"Παγκόσμια Ημέρα της Γυναίκας",
+			"International Women's Day",
+			"Día Internacional de la Mujer",
+			"Journée internationale des femmes",
+			"Międzynarodowy Dzień Kobiet",
+			"Международный женский день",
+			"Міжнародний жіночий день",
+		],
+	),
+	(
+		HolidayId::JE_LIBERATION_DAY,
+		[
+			"Tag der Befreiung",
+			"Ημέρα Απελευθέρωσης",
+			"Liberation Day",
+			"Día de la Liberación",
+			"Jour de la Libération",
+			"Dzień Wyzwolenia",
+			"День освобождения",
+			"День визволення",
+		],
+	),
+	(
+		HolidayId::JP_AUTUMNAL_EQUINOX_DAY,
+		[
+			"Herbst-Tagundnachtgleiche",
+			"Ημέρα της φθινοπωρινής ισημερίας",
+			"Autumnal Equinox Day",
+			"Día del equinoccio de otoño",
+			"Jour de l'équinoxe d'automne",
+			"Dzień równonocy jesiennej",
+			"День осеннего равноденствия",
+			"День осіннього рівнодення",
+		],
+	),
+	(
+		HolidayId::JP_CITIZENS_HOLIDAY,
+		[
+			"Bürgerfeiertag",
+			"Αργία των πολιτών",
+			"Citizens' Holiday",
+			"Feriado ciudadano",
+			"Jour férié des citoyens",
+			"Święto obywateli",
+			"Народный выходной",
+			"Народний вихідний",
+		],
+	),
+	(
+		HolidayId::JP_COMING_OF_AGE_DAY,
+		[
+			"Tag der Volljährigkeit",
+			"Ημέρα της ενηλικίωσης",
+			"Coming of Age Day",
+			"Día de la Mayoría de Edad",
+			"Jour de la majorité",
+			"Dzień Dorosłości",
+			"День совершеннолетия",
+			"День повноліття",
+		],
+	),
+	(
+		HolidayId::JP_CONSTITUTION_MEMORIAL_DAY,
+		[
+			"Verfassungsgedenktag",
+			"Ημέρα μνήμης του Συντάγματος",
+			"Constitution Memorial Day",
+			"Día de la Constitución",
+			"Jour de la Constitution",
+			"Dzień Pamięci Konstytucji",
+			"День Конституции",
+			"День Конституції",
+		],
+	),
+	(
+		HolidayId::JP_CULTURE_DAY,
+		[
+			"Tag der Kultur",
+			"Ημέρα του Πολιτισμού",
+			"Culture Day",
+			"Día de la Cultura",
+			"Jour de la culture",
+			"Dzień Kultury",
+			"День культуры",
+			"День культури",
+		],
+	),
+	(
+		HolidayId::JP_EMPERORS_BIRTHDAY,
+		[
+			"Geburtstag des Kaisers",
+			"Γενέθλια του Αυτοκράτορα",
+			"Emperor's Birthday",
+			"Cumpleaños del Emperador",
+			"Anniversaire de l'Empereur",
+			"Urodziny Cesarza",
+			"День рождения императора",
+			"День народження імператора",
+		],
+	),
+	(
+		HolidayId::JP_ENTHRONEMENT_CEREMONY_DAY,
+		[
+			"Tag der Inthronisierungszeremonie",
+			"Ημέρα της τελετής ενθρόνισης",
+			"Enthronement Ceremony Day",
+			"Día de la ceremonia de entronización",
+			"Jour de la cérémonie d'intronisation",
+			"Dzień ceremonii intronizacji",
+			"День церемонии интронизации",
+			"День церемонії інтронізації",
+		],
+	),
+	(
+		HolidayId::JP_ENTHRONEMENT_DAY,
+		[
+			"Tag der Thronbesteigung",
+			"Ημέρα της ενθρόνισης",
+			"Enthronement Day",
+			"Día de la entronización",
+			"Jour de l'intronisation",
+			"Dzień intronizacji",
+			"День восшествия на престол",
+			"День сходження на престол",
+		],
+	),
+	(
+		HolidayId::JP_GREENERY_DAY,
+		[
+			"Tag des Grüns",
+			"Ημέρα του Πρασίνου",
+			"Greenery Day",
+			"Día de la Naturaleza",
+			"Jour de la verdure",
+			"Dzień Zieleni",
+			"День зелени",
+			"День зелені",
+		],
+	),
+	(
+		HolidayId::JP_LABOUR_THANKSGIVING_DAY,
+		[
+			"Tag des Dankes für die Arbeit",
+			"Ημέρα ευχαριστίας για την εργασία",
+			"Labour Thanksgiving Day",
+			"Día de Acción de Gracias por el Trabajo",
+			"Fête du travail et de l'action de grâce",
+			"Święto Dziękczynienia za Pracę",
+			"День благодарности за труд",
+			"День подяки за працю",
+		],
+	),
+	(
+		HolidayId::JP_MARINE_DAY,
+		[
+			"Tag des Meeres",
+			"Ημέρα της Θάλασσας",
+			"Marine Day",
+			"Día del Mar",
+			"Jour de la mer",
+			"Dzień Morza",
+			"День моря",
+			"День моря",
+		],
+	),
+	(
+		HolidayId::JP_MOUNTAIN_DAY,
+		[
+			"Tag der Berge",
+			"Ημέρα του Βουνού",
+			"Mountain Day",
+			"Día de la Montaña",
+			"Jour de la montagne",
+			"Dzień Gór",
+			"День гор",
+			"День гір",
+		],
+	),
+	(
+		HolidayId::JP_NATIONAL_FOUNDATION_DAY,
+		[
+			"Staatsgründungstag",
+			"Ημέρα της Εθνικής Ίδρυσης",
+			"National Foundation Day",
+			"Día de la Fundación Nacional",
+			"Jour de la fondation nationale",
+			"Dzień Założenia Państwa",
+			"День основания государства",
+			"День заснування держави",
+		],
+	),
+	(
+		HolidayId::JP_RESPECT_FOR_THE_AGED_DAY,
+		[
+			"Tag der Achtung vor dem Alter",
+			"Ημέρα σεβασμού προς τους ηλικιωμένους",
+			"Respect for the Aged Day",
+			"Día del Respeto a los Mayores",
+			"Jour du respect des personnes âgées",
+			"Dzień Szacunku dla Osób Starszych",
+			"День почитания старших",
+			"День шанування літніх людей",
+		],
+	),
+	(
+		HolidayId::JP_SHOWA_DAY,
+		[
+			"Shōwa-Tag",
+			"Ημέρα Σόουα",
+			"Shōwa Day",
+			"Día de Shōwa",
+			"Jour de Shōwa",
+			"Dzień Shōwa",
+			"День Сёва",
+			"День Сьова",
+		],
+	),
+	(
+		HolidayId::JP_SPORTS_DAY,
+		[
+			"Tag des Sports",
+			"Ημέρα του Αθλητισμού",
+			"Sports Day",
+			"Día del Deporte",
+			"Jour du sport",
+			"Dzień Sportu",
+			"День спорта",
+			"День спорту",
+		],
+	),
+	(
+		HolidayId::JP_VERNAL_EQUINOX_DAY,
+		[
+			"Frühlings-Tagundnachtgleiche",
+			"Ημέρα της εαρινής ισημερίας",
+			"Vernal Equinox Day",
+			"Día del equinoccio de primavera",
+			"Jour de l'équinoxe de printemps",
+			"Dzień równonocy wiosennej",
+			"День весеннего равноденствия",
+			"День весняного рівнодення",
+		],
+	),
+	(
+		HolidayId::KR_HANGUL_DAY,
+		[
+			"Hangeul-Tag",
+			"Ημέρα του Χανγκούλ",
+			"Hangul Day",
+			"Día del Hangul",
+			"Jour du hangeul",
+			"Dzień Hangula",
+			"День хангыля",
+			"День хангиля",
+		],
+	),
+	(
+		HolidayId::KR_INDEPENDENCE_MOVEMENT_DAY,
+		[
+			"Tag der Unabhängigkeitsbewegung",
+			"Ημέρα του Κινήματος Ανεξαρτησίας",
+			"Independence Movement Day",
+			"Día del Movimiento de Independencia",
+			"Jour du mouvement d'indépendance",
+			"Dzień Ruchu Niepodległościowego",
+			"День движения за независимость",
+			"День руху за незалежність",
+		],
+	),
+	(
+		HolidayId::KR_LIBERATION_DAY,
+		[
+			"Tag der Befreiung",
+			"Ημέρα της Απελευθέρωσης",
+			"Liberation Day",
+			"Día de la Liberación",
+			"Jour de la Libération",
+			"Dzień Wyzwolenia",
+			"День освобождения",
+			"День визволення",
+		],
+	),
+	(
+		HolidayId::KR_MEMORIAL_DAY,
+		[
+			"Gedenktag",
+			"Ημέρα Μνήμης",
+			"Memorial Day",
+			"Día de los Caídos",
+			"Jour du Souvenir",
+			"Dzień Pamięci",
+			"День памяти",
+			"День пам'яті",
+		],
+	),
+	(
+		HolidayId::KR_NATIONAL_FOUNDATION_DAY,
+		[
+			"Staatsgründungstag",
+			"Ημέρα της Εθνικής Ίδρυσης",
+			"National Foundation Day",
+			"Día de la Fundación Nacional",
+			"Jour de la fondation nationale",
+			"Dzień Założenia Państwa",
+			"День основания государства",
+			"День заснування держави",
+		],
+	),
+	(
+		HolidayId::LABOUR_DAY,
+		[
+			"Tag der Arbeit",
+			"Εργατική Πρωτομαγιά",
+			"Labour Day",
+			"Día del Trabajo",
+			"Fête du Travail",
+			"Święto Pracy",
+			"День труда",
+			"День праці",
+		],
+	),
+	(
+		HolidayId::LUNAR_NEW_YEAR,
+		[
+			"Mondneujahr",
+			"Σεληνιακό Νέο Έτος",
+			"Lunar New Year",
+			"Año Nuevo Lunar",
+			"Nouvel An lunaire",
+			"Księżycowy Nowy Rok",
+			"Новый год по лунному календарю",
+			"Новий рік за місячним календарем",
+		],
+	),
+	(
+		HolidayId::LUNAR_NEW_YEARS_EVE,
+		[
+			"Vorabend des Mondneujahrs",
+			"Παραμονή του Σεληνιακού Νέου Έτους",
+			"Lunar New Year's Eve",
+			"Víspera del Año Nuevo Lunar",
+			"Réveillon du Nouvel An lunaire",
+			"Wigilia Księżycowego Nowego Roku",
+			"Канун Нового года по лунному календарю",
+			"Переддень Нового року за місячним календарем",
+		],
+	),
+	(
+		HolidayId::MARDI_GRAS,
+		[
+			"Faschingsdienstag",
+			"Μαρντί Γκρα",
+			"Mardi Gras",
+			"Martes de Carnaval",
+			"Mardi gras",
+			"Ostatki",
+			"Марди Гра",
+			"Марді Гра",
+		],
+	),
+	(
+		HolidayId::MAUNDY_THURSDAY,
+		[
+			"Gründonnerstag",
+			"Μεγάλη Πέμπτη",
+			"Maundy Thursday",
+			"Jueves Santo",
+			"Jeudi saint",
+			"Wielki Czwartek",
+			"Великий четверг",
+			"Великий четвер",
+		],
+	),
+	(
+		HolidayId::MID_AUTUMN_FESTIVAL,
+		[
+			"Mondfest",
+			"Γιορτή του Μέσου του Φθινοπώρου",
+			"Mid-Autumn Festival",
+			"Festival del Medio Otoño",
+			"Fête de la mi-automne",
+			"Święto Środka Jesieni",
+			"Праздник середины осени",
+			"Свято середини осені",
+		],
+	),
+	(
+		HolidayId::NEW_YEARS_DAY,
+		[
+			"Neujahr",
+			"Πρωτοχρονιά",
+			"New Year's Day",
+			"Año Nuevo",
+			"Jour de l'an",
+			"Nowy Rok",
+			"Новый год",
+			"Новий рік",
+		],
+	),
+	(
+		HolidayId::ORTHODOX_CHRISTMAS_DAY,
+		[
+			"Orthodoxe Weihnachten",
+			"Χριστούγεννα (Ιουλιανό ημερολόγιο)",
+			"Orthodox Christmas Day",
+			"Navidad ortodoxa",
+			"Noël orthodoxe",
+			"Prawosławne Boże Narodzenie",
+			"Рождество Христово",
+			"Різдво Христове",
+		],
+	),
+	(
+		HolidayId::PASSOVER,
+		[
+			"Pessach",
+			"Πάσχα των Εβραίων",
+			"Passover",
+			"Pésaj",
+			"Pâque juive",
+			"Pesach",
+			"Песах",
+			"Песах",
+		],
+	),
+	(
+		HolidayId::PASSOVER_SEVENTH_DAY,
+		[
+			"Siebter Tag von Pessach",
+			"Έβδομη ημέρα του Πάσχα των Εβραίων",
+			"Seventh day of Passover",
+			"Séptimo día de Pésaj",
+			"Septième jour de la Pâque juive",
+			"Siódmy dzień Pesach",
+			"Седьмой день Песаха",
+			"Сьомий день Песаху",
+		],
+	),
+	(
+		HolidayId::PENTECOST,
+		[
+			"Pfingstsonntag",
+			"Πεντηκοστή",
+			"Pentecost",
+			"Pentecostés",
+			"Pentecôte",
+			"Zielone Świątki",
+			"Троица",
+			"Трійця",
+		],
+	),
+	(
+		HolidayId::PL_CONSTITUTION_DAY,
+		[
+			"Tag der Verfassung",
+			"Ημέρα του Συντάγματος",
+			"Constitution Day",
+			"Día de la Constitución",
+			"Jour de la Constitution",
+			"Święto Konstytucji 3 Maja",
+			"День Конституции",
+			"День Конституції",
+		],
+	),
+	(
+		HolidayId::PL_INDEPENDENCE_DAY,
+		[
+			"Unabhängigkeitstag",
+			"Ημέρα της Ανεξαρτησίας",
+			"Independence Day",
+			"Día de la Independencia",
+			"Fête de l'indépendance",
+			"Święto Niepodległości",
+			"День независимости",
+			"День Незалежності",
+		],
+	),
+	(
+		HolidayId::QINGMING_FESTIVAL,
+		[
+			"Qingming-Fest",
+			"Γιορτή Τσινγκμίνγκ",
+			"Qingming Festival",
+			"Festival de Qingming",
+			"Fête de Qingming",
+			"Święto Qingming",
+			"Праздник Цинмин",
+			"Свято Цінмін",
+		],
+	),
+	(
+		HolidayId::ROSH_HASHANAH,
+		[
+			"Rosch ha-Schana",
+			"Ρος Χασανά",
+			"Rosh Hashanah",
+			"Rosh Hashaná",
+			"Roch Hachana",
+			"Rosz ha-Szana",
+			"Рош ха-Шана",
+			"Рош га-Шана",
+		],
+	),
+	(
+		HolidayId::RU_DEFENDER_OF_THE_FATHERLAND_DAY,
+		[
+			"Tag des Verteidigers des Vaterlandes",
+			"Ημέρα του Υπερασπιστή της Πατρίδας",
+			"Defender of the Fatherland Day",
+			"Día del Defensor de la Patria",
+			"Jour du défenseur de la patrie",
+			"Dzień Obrońcy Ojczyzny",
+			"День защитника Отечества",
+			"День захисника Вітчизни",
+		],
+	),
+	(
+		HolidayId::RU_NEW_YEAR_HOLIDAY,
+		[
+			"Neujahrsferien",
+			"Διακοπές της Πρωτοχρονιάς",
+			"New Year holiday",
+			"Vacaciones de Año Nuevo",
+			"Vacances du Nouvel An",
+			"Ferie noworoczne",
+			"Новогодние каникулы",
+			"Новорічні канікули",
+		],
+	),
+	(
+		HolidayId::RU_RUSSIA_DAY,
+		[
+			"Tag Russlands",
+			"Ημέρα της Ρωσίας",
+			"Russia Day",
+			"Día de Rusia",
+			"Jour de la Russie",
+			"Dzień Rosji",
+			"День России",
+			"День Росії",
+		],
+	),
+	(
+		HolidayId::RU_UNITY_DAY,
+		[
+			"Tag der Einheit des Volkes",
+			"Ημέρα της Εθνικής Ενότητας",
+			"Unity Day",
+			"Día de la Unidad Popular",
+			"Jour de l'unité nationale",
+			"Dzień Jedności Narodowej",
+			"День народного единства",
+			"День народної єдності",
+		],
+	),
+	(
+		HolidayId::SA_FOUNDING_DAY,
+		[
+			"Gründungstag",
+			"Ημέρα της ίδρυσης",
+			"Founding Day",
+			"Día de la Fundación",
+			"Jour de la fondation",
+			"Dzień Założenia",
+			"День основания",
+			"День заснування",
+		],
+	),
+	(
+		HolidayId::SA_NATIONAL_DAY,
+		[
+			"Nationalfeiertag",
+			"Εθνική εορτή",
+			"National Day",
+			"Día Nacional",
+			"Fête nationale",
+			"Święto Narodowe",
+			"Национальный день",
+			"Національний день",
+		],
+	),
+	(
+		HolidayId::SHAVUOT,
+		[
+			"Schawuot",
+			"Σαβουότ",
+			"Shavuot",
+			"Shavuot",
+			"Chavouot",
+			"Szawuot",
+			"Шавуот",
+			"Шавуот",
+		],
+	),
+	(
+		HolidayId::SIMCHAT_TORAH,
+		[
+			"Simchat Tora",
+			"Σιμχάτ Τορά",
+			"Simchat Torah",
+			"Simjat Torá",
+			"Sim'hat Torah",
+			"Simchat Tora",
+			"Симхат Тора",
+			"Сімхат Тора",
+		],
+	),
+	(
+		HolidayId::ST_ANDREWS_DAY,
+		[
+			"Andreastag",
+			"Ημέρα του Αγίου Ανδρέα",
+			"Saint Andrew's Day",
+			"Día de San Andrés",
+			"Saint-André",
+			"Dzień Świętego Andrzeja",
+			"День святого Андрея",
+			"День святого Андрія",
+		],
+	),
+	(
+		HolidayId::ST_PATRICKS_DAY,
+		[
+			"St. Patrick's Day",
+			"Ημέρα του Αγίου Πατρικίου",
+			"Saint Patrick's Day",
+			"Día de San Patricio",
+			"Saint-Patrick",
+			"Dzień Świętego Patryka",
+			"День святого Патрика",
+			"День святого Патрика",
+		],
+	),
+	(
+		HolidayId::ST_STEPHENS_DAY,
+		[
+			"Zweiter Weihnachtstag",
+			"Δεύτερη ημέρα των Χριστουγέννων",
+			"St. Stephen's Day",
+			"San Esteban",
+			"Saint-Étienne",
+			"Drugi Dzień Bożego Narodzenia",
+			"День святого Стефана",
+			"День святого Стефана",
+		],
+	),
+	(
+		HolidayId::SUBSTITUTE_HOLIDAY,
+		[
+			"Ersatzfeiertag",
+			"Αναπληρωματική αργία",
+			"Substitute Holiday",
+			"Feriado sustitutorio",
+			"Jour férié de remplacement",
+			"Dzień wolny w zamian za święto",
+			"Перенесённый выходной",
+			"Перенесений вихідний",
+		],
+	),
+	(
+		HolidayId::SUKKOT,
+		["Sukkot", "Σουκότ", "Sukkot", "Sucot", "Souccot", "Sukkot", "Суккот", "Сукот"],
+	),
+	(
+		HolidayId::TRANSFERRED_DAY_OFF,
+		[
+			"Verlegter freier Tag",
+			"Μεταφερμένη ημέρα αργίας",
+			"Transferred day off",
+			"Día libre trasladado",
+			"Jour de repos reporté",
+			"Przeniesiony dzień wolny",
+			"Перенесённый выходной день",
+			"Перенесений вихідний день",
+		],
+	),
+	(
+		HolidayId::UA_CONSTITUTION_DAY,
+		[
+			"Tag der Verfassung",
+			"Ημέρα του Συντάγματος",
+			"Constitution Day",
+			"Día de la Constitución",
+			"Jour de la Constitution",
+			"Dzień Konstytucji Ukrainy",
+			"День Конституции",
+			"День Конституції",
+		],
+	),
+	(
+		HolidayId::UA_DEFENDERS_DAY,
+		[
+			"Tag der Verteidiger der Ukraine",
+			"Ημέρα των Υπερασπιστών της Ουκρανίας",
+			"Defender of Ukraine Day",
+			"Día de los Defensores de Ucrania",
+			"Jour des défenseurs de l'Ukraine",
+			"Dzień Obrońców Ukrainy",
+			"День защитников Украины",
+			"День захисника України",
+		],
+	),
+	(
+		HolidayId::UA_INDEPENDENCE_DAY,
+		[
+			"Unabhängigkeitstag",
+			"Ημέρα της Ανεξαρτησίας",
+			"Independence Day",
+			"Día de la Independencia",
+			"Fête de l'indépendance",
+			"Święto Niepodległości",
+			"День независимости",
+			"День Незалежності",
+		],
+	),
+	(
+		HolidayId::UA_VICTORY_OVER_NAZISM_DAY,
+		[
+			"Tag des Sieges über den Nationalsozialismus im Zweiten Weltkrieg",
+			"Ημέρα της Νίκης επί του Ναζισμού στον Β' Παγκόσμιο Πόλεμο",
+			"Victory day over Nazism in World War II",
+			"Día de la Victoria sobre el nazismo en la Segunda Guerra Mundial",
+			"Jour de la victoire sur le nazisme lors de la Seconde Guerre mondiale",
+			"Dzień Zwycięstwa nad nazizmem w II wojnie światowej",
+			"День победы над нацизмом во Второй мировой войне",
+			"День перемоги над нацизмом у Другій світовій війні",
+		],
+	),
+	(
+		HolidayId::US_ALASKA_DAY,
+		[
+			"Alaska-Tag",
+			"Ημέρα της Αλάσκας",
+			"Alaska Day",
+			"Día de Alaska",
+			"Jour de l'Alaska",
+			"Dzień Alaski",
+			"День Аляски",
+			"День Аляски",
+		],
+	),
+	(
+		HolidayId::US_AMERICAN_SAMOA_FLAG_DAY,
+		[
+			"Flaggentag von Amerikanisch-Samoa",
+			"Ημέρα της Σημαίας της Αμερικανικής Σαμόα",
+			"American Samoa Flag Day",
+			"Día de la Bandera de Samoa Americana",
+			"Jour du drapeau des Samoa américaines",
+			"Dzień Flagi Samoa Amerykańskiego",
+			"День флага Американского Самоа",
+			"День прапора Американського Самоа",
+		],
+	),
+	(
+		HolidayId::US_CASIMIR_PULASKI_DAY,
+		[
+			"Casimir-Pulaski-Tag",
+			"Ημέρα Κάζιμιρ Πουλάσκι",
+			"Casimir Pulaski Day",
+			"Día de Casimir Pulaski",
+			"Jour de Casimir Pulaski",
+			"Dzień Kazimierza Pułaskiego",
+			"День Казимира Пулаского",
+			"День Казимира Пуласького",
+		],
+	),
+	(
+		HolidayId::US_CESAR_CHAVEZ_DAY,
+		[
+			"César-Chávez-Tag",
+			"Ημέρα Σέζαρ Τσάβες",
+			"Cesar Chavez Day",
+			"Día de César Chávez",
+			"Journée César Chávez",
+			"Dzień Césara Cháveza",
+			"День Сесара Чавеса",
+			"День Сесара Чавеса",
+		],
+	),
+	(
+		HolidayId::US_COLUMBUS_DAY,
+		[
+			"Columbus Day",
+			"Ημέρα του Κολόμβου",
+			"Columbus Day",
+			"Día de Colón",
+			"Jour de Christophe Colomb",
+			"Dzień Kolumba",
+			"День Колумба",
+			"День Колумба",
+		],
+	),
+	(
+		HolidayId::US_COVENANT_DAY,
+		[
+			"Tag des Covenant",
+			"Ημέρα της Συνθήκης",
+			"Covenant Day",
+			"Día del Pacto",
+			"Jour du Pacte",
+			"Dzień Przymierza",
+			"День Соглашения",
+			"День Угоди",
+		],
+	),
+	(
+		HolidayId::US_ELECTION_DAY,
+		[
+			"Wahltag",
+			"Ημέρα εκλογών",
+			"Election Day",
+			"Día de las Elecciones",
+			"Jour des élections",
+			"Dzień Wyborów",
+			"День выборов",
+			"День виборів",
+		],
+	),
+	(
+		HolidayId::US_EMANCIPATION_DAY,
+		[
+			"Tag der Emanzipation",
+			"Ημέρα της Χειραφέτησης",
+			"Emancipation Day",
+			"Día de la Emancipación",
+			"Jour de l'émancipation",
+			"Dzień Emancypacji",
+			"День эмансипации",
+			"День емансипації",
+		],
+	),
+	(
+		HolidayId::US_GUAM_LIBERATION_DAY,
+		[
+			"Tag der Befreiung Guams",
+			"Ημέρα Απελευθέρωσης του Γκουάμ",
+			"Guam Liberation Day",
+			"Día de la Liberación de Guam",
+			"Jour de la libération de Guam",
+			"Dzień Wyzwolenia Guamu",
+			"День освобождения Гуама",
+			"День визволення Гуаму",
+		],
+	),
+	(
+		HolidayId::US_INAUGURATION_DAY,
+		[
+			"Tag der Amtseinführung",
+			"Ημέρα ορκωμοσίας",
+			"Inauguration Day",
+			"Día de la Investidura",
+			"Jour de l'investiture",
+			"Dzień Inauguracji",
+			"День инаугурации",
+			"День інавгурації",
+		],
+	),
+	(
+		HolidayId::US_INDEPENDENCE_DAY,
+		[
+			"Unabhängigkeitstag",
+			"Ημέρα της Ανεξαρτησίας",
+			"Independence Day",
+			"Día de la Independencia",
+			"Fête de l'indépendance",
+			"Święto Niepodległości",
+			"День независимости",
+			"День Незалежності",
+		],
+	),
+	(
+		HolidayId::US_JUNETEENTH,
+		[
+			"Juneteenth",
+			"Juneteenth",
+			"Juneteenth",
+			"Juneteenth",
+			"Juneteenth",
+			"Juneteenth",
+			"Джунтинс",
+			"Джунтінс",
+		],
+	),
+	(
+		HolidayId::US_KAMEHAMEHA_DAY,
+		[
+			"Kamehameha-Tag",
+			"Ημέρα Καμεχαμέχα",
+			"Kamehameha Day",
+			"Día del Rey Kamehameha",
+			"Jour de Kamehameha",
+			"Dzień Kamehamehy",
+			"День Камехамехи",
+			"День Камегамеги",
+		],
+	),
+	(
+		HolidayId::US_LABOR_DAY,
+		[
+			"Tag der Arbeit",
+			"Ημέρα της Εργασίας",
+			"Labor Day",
+			"Día del Trabajo",
+			"Fête du Travail",
+			"Święto Pracy",
+			"День труда",
+			"День праці",
+		],
+	),
+	(
+		HolidayId::US_LINCOLNS_BIRTHDAY,
+		[
+			"Lincolns Geburtstag",
+			"Γενέθλια του Λίνκολν",
+			"Lincoln's Birthday",
+			"Natalicio de Lincoln",
+			"Anniversaire de Lincoln",
+			"Urodziny Lincolna",
+			"День рождения Линкольна",
+			"День народження Лінкольна",
+		],
+	),
+	(
+		HolidayId::US_MARTIN_LUTHER_KING_DAY,
+		[
+			"Martin-Luther-King-Tag",
+			"Ημέρα του Μάρτιν Λούθερ Κινγκ",
+			"Martin Luther King, Jr. Day",
+			"Día de Martin Luther King",
+			"Jour de Martin Luther King",
+			"Dzień Martina Luthera Kinga",
+			"День Мартина Лютера Кинга",
+			"День Мартіна Лютера Кінга",
+		],
+	),
+	(
+		HolidayId::US_MEMORIAL_DAY,
+		[
+			"Gedenktag",
+			"Ημέρα Μνήμης",
+			"Memorial Day",
+			"Día de los Caídos",
+			"Jour du Souvenir",
+			"Dzień Pamięci",
+			"День памяти",
+			"День пам'яті",
+		],
+	),
+	(
+		HolidayId::US_NEVADA_DAY,
+		[
+			"Nevada-Tag",
+			"Ημέρα της Νεβάδα",
+			"Nevada Day",
+			"Día de Nevada",
+			"Jour du Nevada",
+			"Dzień Nevady",
+			"День Невады",
+			"День Невади",
+		],
+	),
+	(
+		HolidayId::US_PATRIOTS_DAY,
+		[
+			"Tag der Patrioten",
+			"Ημέρα των Πατριωτών",
+			"Patriots' Day",
+			"Día de los Patriotas",
+			"Journée des Patriotes",
+			"Dzień Patriotów",
+			"День патриотов",
+			"День патріотів",
+		],
+	),
+	(
+		HolidayId::US_PIONEER_DAY,
+		[
+			"Pioniertag",
+			"Ημέρα των Πιονέρων",
+			"Pioneer Day",
+			"Día de los Pioneros",
+			"Jour des Pionniers",
+			"Dzień Pionierów",
+			"День пионеров",
+			"День піонерів",
+		],
+	),
+	(
+		HolidayId::US_PUERTO_RICO_CONSTITUTION_DAY,
+		[
+			"Verfassungstag von Puerto Rico",
+			"Ημέρα του Συντάγματος του Πουέρτο Ρίκο",
+			"Puerto Rico Constitution Day",
+			"Día de la Constitución de Puerto Rico",
+			"Jour de la Constitution de Porto Rico",
+			"Dzień Konstytucji Portoryko",
+			"День Конституции Пуэрто-Рико",
+			"День Конституції Пуерто-Рико",
+		],
+	),
+	(
+		HolidayId::US_SEWARDS_DAY,
+		[
+			"Seward-Tag",
+			"Ημέρα του Σιούαρντ",
+			"Seward's Day",
+			"Día de Seward",
+			"Jour de Seward",
+			"Dzień Sewarda",
+			"День Сьюарда",
+			"День Сьюарда",
+		],
+	),
+	(
+		HolidayId::US_TEXAS_INDEPENDENCE_DAY,
+		[
+			"Unabhängigkeitstag von Texas",
+			"Ημέρα Ανεξαρτησίας του Τέξας",
+			"Texas Independence Day",
+			"Día de la Independencia de Texas",
+			"Jour de l'indépendance du Texas",
+			"Dzień Niepodległości Teksasu",
+			"День независимости Техаса",
+			"День незалежності Техасу",
+		],
+	),
+	(
+		HolidayId::US_THANKSGIVING,
+		[
+			"Erntedankfest",
+			"Ημέρα των Ευχαριστιών",
+			"Thanksgiving Day",
+			"Día de Acción de Gracias",
+			"Action de grâce",
+			"Święto Dziękczynienia",
+			"День благодарения",
+			"День подяки",
+		],
+	),
+	(
+		HolidayId::US_TRANSFER_DAY,
+		[
+			"Tag der Übergabe",
+			"Ημέρα της Μεταβίβασης",
+			"Transfer Day",
+			"Día de la Transferencia",
+			"Jour du transfert",
+			"Dzień Przekazania",
+			"День передачи",
+			"День передачі",
+		],
+	),
+	(
+		HolidayId::US_TRUMAN_DAY,
+		[
+			"Truman-Tag",
+			"Ημέρα Τρούμαν",
+			"Truman Day",
+			"Día de Truman",
+			"Jour de Truman",
+			"Dzień Trumana",
+			"День Трумэна",
+			"День Трумена",
+		],
+	),
+	(
+		HolidayId::US_VETERANS_DAY,
+		[
+			"Veteranentag",
+			"Ημέρα των Βετεράνων",
+			"Veterans Day",
+			"Día de los Veteranos",
+			"Jour des anciens combattants",
+			"Dzień Weteranów",
+			"День ветеранов",
+			"День ветеранів",
+		],
+	),
+	(
+		HolidayId::US_WASHINGTONS_BIRTHDAY,
+		[
+			"Washingtons Geburtstag",
+			"Γενέθλια του Ουάσιγκτον",
+			"Washington's Birthday",
+			"Día del Presidente",
+			"Jour des présidents",
+			"Dzień Prezydentów",
+			"День рождения Вашингтона",
+			"День народження Вашингтона",
+		],
+	),
+	(
+		HolidayId::VICTORY_DAY,
+		[
+			"Tag des Sieges",
+			"Ημέρα της Νίκης",
+			"Victory Day",
+			"Día de la Victoria",
+			"Jour de la Victoire",
+			"Dzień Zwycięstwa",
+			"День Победы",
+			"День Перемоги",
+		],
+	),
+	(
+		HolidayId::WHIT_MONDAY,
+		[
+			"Pfingstmontag",
+			"Δευτέρα του Αγίου Πνεύματος",
+			"Whit Monday",
+			"Lunes de Pentecostés",
+			"Lundi de Pentecôte",
+			"Poniedziałek Zielonoświątkowy",
+			"Духов день",
+			"Духів день",
+		],
+	),
+	(
+		HolidayId::YOM_KIPPUR,
+		[
+			"Jom Kippur",
+			"Γιομ Κιπούρ",
+			"Yom Kippur",
+			"Yom Kipur",
+			"Yom Kippour",
+			"Jom Kipur",
+			"Йом Кипур",
+			"Йом Кіпур",
+		],
+	),
+];
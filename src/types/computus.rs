@@ -0,0 +1,87 @@
+use chrono::NaiveDate;
+
+use crate::calendars::julian::JulianCalendar;
+
+/// Method to calculate the date of easter sunday
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Computus {
+	/// Easter of the western churches, based on the gregorian calendar
+	Gregorian,
+	/// Easter of the orthodox churches, based on the julian calendar
+	Julian,
+}
+
+impl Computus {
+	/// Gets the date of easter sunday for the given year
+	///
+	/// # Example
+	///
+	/// ```
+	/// use horus::types::computus::Computus;
+	/// use chrono::NaiveDate;
+	///
+	/// assert_eq!(Computus::Gregorian.easter_sunday(2022), NaiveDate::from_ymd_opt(2022, 4, 17).unwrap());
+	/// assert_eq!(Computus::Julian.easter_sunday(2022), NaiveDate::from_ymd_opt(2022, 4, 24).unwrap());
+	/// ```
+	pub fn easter_sunday(&self, year: i32) -> NaiveDate {
+		match self {
+			Computus::Gregorian => Self::gregorian_easter_sunday(year),
+			Computus::Julian => Self::julian_easter_sunday(year),
+		}
+	}
+
+	fn gregorian_easter_sunday(year: i32) -> NaiveDate {
+		//should be
+		//Easter Monday  28 Mar 2005  17 Apr 2006  9 Apr 2007  24 Mar 2008
+
+		//Oudin's Algorithm - http://www.smart.net/~mmontes/oudin.html
+		let g = year % 19;
+		let c = year / 100;
+		let h = (c - c / 4 - (8 * c + 13) / 25 + 19 * g + 15) % 30;
+		let i = h - (h / 28) * (1 - (h / 28) * (29 / (h + 1)) * ((21 - g) / 11));
+
+		let mut day: u32 = (i - ((year + (year / 4) + i + 2 - c + (c / 4)) % 7) + 28) as u32;
+		let mut month = 3;
+
+		if day > 31 {
+			month += 1;
+			day -= 31;
+		}
+
+		NaiveDate::from_ymd_opt(year, month, day).unwrap()
+	}
+
+	fn julian_easter_sunday(year: i32) -> NaiveDate {
+		let (year, month, day) = JulianCalendar::easter_sunday(year);
+		JulianCalendar::to_gregorian(year, month, day).unwrap()
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use chrono::Datelike;
+
+	use super::Computus;
+
+	#[test]
+	fn test_gregorian_easter_sunday() {
+		let result = Computus::Gregorian.easter_sunday(2024);
+		assert_eq!(result.day(), 31);
+		assert_eq!(result.month(), 3);
+	}
+
+	#[test]
+	fn test_julian_easter_sunday() {
+		let result = Computus::Julian.easter_sunday(2024);
+		assert_eq!(result.day(), 5);
+		assert_eq!(result.month(), 5);
+	}
+
+	#[test]
+	fn test_julian_easter_sunday_after_2100() {
+		// from 2100 onwards the julian calendar is 14 days behind the gregorian calendar
+		let result = Computus::Julian.easter_sunday(2101);
+		assert_eq!(result.day(), 24);
+		assert_eq!(result.month(), 4);
+	}
+}
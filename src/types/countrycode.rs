@@ -1,5 +1,7 @@
+use serde::{Deserialize, Serialize};
+
 /// Supported Country Codes (ISO 3166-1 ALPHA-2)
-#[derive(Debug, PartialEq, Eq)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
 pub enum CountryCode {
 	/// Andorra
 	AD,
@@ -208,6 +210,9 @@ pub enum CountryCode {
 	/// Ethiopia
 	ET,
 
+	/// European Union (exceptionally reserved)
+	EU,
+
 	/// Finland
 	FI,
 
@@ -0,0 +1,107 @@
+use chrono::{Duration, NaiveDate};
+
+use crate::calendars::reform::CalendarReform;
+
+use super::{
+	computus::Computus, countrycode::CountryCode, holidayid::HolidayId, publicholiday::PublicHoliday,
+	publicholidaytype::PublicHolidayType,
+};
+
+/// Rule for a holiday which takes place a fixed number of days before or after easter sunday
+///
+/// # Example
+///
+/// ```
+/// use horus::types::{computus::Computus, easterrelative::EasterRelative};
+/// use chrono::NaiveDate;
+///
+/// let rule = EasterRelative::new(Computus::Gregorian, -2);
+///
+/// assert_eq!(rule.date(2022), NaiveDate::from_ymd_opt(2022, 4, 15).unwrap());
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct EasterRelative {
+	pub computus: Computus,
+	pub offset_days: i64,
+}
+
+impl EasterRelative {
+	pub fn new(computus: Computus, offset_days: i64) -> Self {
+		EasterRelative { computus, offset_days }
+	}
+
+	/// Gets the date of the rule for the given year
+	pub fn date(&self, year: i32) -> NaiveDate {
+		self.computus.easter_sunday(year) + Duration::days(self.offset_days)
+	}
+
+	/// Gets the date of the rule for the given year in the country. For years before the country adopted the
+	/// gregorian calendar, easter is calculated with the julian computus.
+	///
+	/// # Example
+	///
+	/// ```
+	/// use horus::types::{computus::Computus, countrycode::CountryCode, easterrelative::EasterRelative};
+	/// use chrono::NaiveDate;
+	///
+	/// let rule = EasterRelative::new(Computus::Gregorian, 0);
+	///
+	/// assert_eq!(rule.date_in(1750, CountryCode::GB), NaiveDate::from_ymd_opt(1750, 4, 26).unwrap());
+	/// assert_eq!(rule.date_in(1750, CountryCode::PL), rule.date(1750));
+	/// ```
+	pub fn date_in(&self, year: i32, country_code: CountryCode) -> NaiveDate {
+		let computus = CalendarReform::computus_in_force(country_code, self.computus, year);
+		EasterRelative::new(computus, self.offset_days).date(year)
+	}
+
+	/// Creates a public holiday for the given year which takes place on the date of the rule. For years before the
+	/// country adopted the gregorian calendar, easter is calculated with the julian computus.
+	pub fn holiday(
+		&self,
+		id: HolidayId,
+		local_name: &str,
+		name: &str,
+		year: i32,
+		country_code: CountryCode,
+	) -> PublicHoliday {
+		PublicHoliday::new_non_fixed(
+			id,
+			self.date_in(year, country_code),
+			local_name,
+			name,
+			country_code,
+			None,
+			None,
+			PublicHolidayType::Public,
+		)
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use crate::types::{computus::Computus, countrycode::CountryCode, holidayid::HolidayId};
+
+	use super::EasterRelative;
+
+	#[test]
+	fn test_holiday() {
+		let result = EasterRelative::new(Computus::Julian, 7).holiday(
+			HolidayId::new("TEST"),
+			"test",
+			"test name",
+			2022,
+			CountryCode::GR,
+		);
+		assert_eq!(result.to_string(), "2022-05-01 test name");
+		assert!(!result.fixed);
+	}
+
+	#[test]
+	fn test_holiday_before_calendar_reform() {
+		let rule = EasterRelative::new(Computus::Gregorian, 0);
+		let result = rule.holiday(HolidayId::new("TEST"), "test", "test name", 1750, CountryCode::GB);
+		assert_eq!(result.to_string(), "1750-04-26 test name");
+		let result = rule.holiday(HolidayId::new("TEST"), "test", "test name", 1750, CountryCode::PL);
+		assert_eq!(result.to_string(), "1750-03-29 test name");
+	}
+}
@@ -0,0 +1,88 @@
+use chrono::NaiveDate;
+
+use super::{
+	countrycode::CountryCode, holidayid::HolidayId, publicholiday::PublicHoliday, publicholidaytype::PublicHolidayType,
+};
+use crate::calendars::CalendarSystem;
+
+/// Rule for a holiday which takes place every year on the same month and day of a calendar system
+///
+/// # Example
+///
+/// ```
+/// use horus::{calendars::CalendarSystem, types::fixeddate::FixedDate};
+/// use chrono::NaiveDate;
+///
+/// let rule = FixedDate::new(CalendarSystem::Julian, 12, 25);
+///
+/// assert_eq!(rule.date(2024), Some(NaiveDate::from_ymd_opt(2024, 1, 7).unwrap()));
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct FixedDate {
+	pub calendar: CalendarSystem,
+	pub month: u32,
+	pub day: u32,
+}
+
+impl FixedDate {
+	pub fn new(calendar: CalendarSystem, month: u32, day: u32) -> Self {
+		FixedDate { calendar, month, day }
+	}
+
+	/// Gets the gregorian date of the rule in the given gregorian year. If the date doesn't take place in the year,
+	/// None will be returned.
+	pub fn date(&self, year: i32) -> Option<NaiveDate> {
+		let date = self.calendar.fixed_date_in_gregorian_year(year, self.month, self.day)?;
+
+		Some(date)
+	}
+
+	/// Creates a public holiday for the given year which takes place on the date of the rule
+	pub fn holiday(
+		&self,
+		id: HolidayId,
+		local_name: &str,
+		name: &str,
+		year: i32,
+		country_code: CountryCode,
+	) -> Option<PublicHoliday> {
+		Some(PublicHoliday::new_non_fixed(
+			id,
+			self.date(year)?,
+			local_name,
+			name,
+			country_code,
+			None,
+			None,
+			PublicHolidayType::Public,
+		))
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use crate::{
+		calendars::CalendarSystem,
+		types::{countrycode::CountryCode, holidayid::HolidayId},
+	};
+
+	use super::FixedDate;
+
+	#[test]
+	fn test_holiday() {
+		let rule = FixedDate::new(CalendarSystem::RevisedJulian, 12, 25);
+		let result = rule.holiday(HolidayId::new("TEST"), "test", "test name", 2022, CountryCode::GR);
+		assert_eq!(result.unwrap().to_string(), "2022-12-25 test name");
+	}
+
+	#[test]
+	fn test_holiday_leap_day() {
+		let rule = FixedDate::new(CalendarSystem::Julian, 2, 29);
+		assert!(rule
+			.holiday(HolidayId::new("TEST"), "test", "test name", 2100, CountryCode::RU)
+			.is_some());
+		assert!(rule
+			.holiday(HolidayId::new("TEST"), "test", "test name", 2101, CountryCode::RU)
+			.is_none());
+	}
+}
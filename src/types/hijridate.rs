@@ -0,0 +1,96 @@
+use chrono::NaiveDate;
+
+use super::{
+	countrycode::CountryCode, holidayid::HolidayId, publicholiday::PublicHoliday, publicholidaytype::PublicHolidayType,
+};
+use crate::calendars::hijri::HijriCalendar;
+
+/// Rule for a holiday which takes place every year on the same month and day of the islamic (hijri) calendar
+///
+/// As the hijri year is shorter than the gregorian year, the rule can take place twice in a gregorian year. The
+/// holidays of the rule are marked as estimated, because the actual dates depend on the sighting of the moon.
+///
+/// # Example
+///
+/// ```
+/// use horus::{calendars::hijri::HijriCalendar, types::hijridate::HijriDate};
+/// use chrono::NaiveDate;
+///
+/// let rule = HijriDate::new(HijriCalendar::UmmAlQura, HijriCalendar::SHAWWAL, 1);
+///
+/// assert_eq!(rule.dates(2024), vec![NaiveDate::from_ymd_opt(2024, 4, 10).unwrap()]);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct HijriDate {
+	pub calendar: HijriCalendar,
+	pub month: u32,
+	pub day: u32,
+}
+
+impl HijriDate {
+	pub fn new(calendar: HijriCalendar, month: u32, day: u32) -> Self {
+		HijriDate { calendar, month, day }
+	}
+
+	/// Gets the gregorian dates of the rule in the given gregorian year
+	pub fn dates(&self, year: i32) -> Vec<NaiveDate> {
+		self.calendar.fixed_dates_in_gregorian_year(year, self.month, self.day)
+	}
+
+	/// Creates the estimated public holidays for the given year which take place on the dates of the rule
+	pub fn holidays(
+		&self,
+		id: HolidayId,
+		local_name: &str,
+		name: &str,
+		year: i32,
+		country_code: CountryCode,
+	) -> Vec<PublicHoliday> {
+		self.dates(year)
+			.into_iter()
+			.map(|date| {
+				let mut holiday = PublicHoliday::new_non_fixed(
+					id.clone(),
+					date,
+					local_name,
+					name,
+					country_code,
+					None,
+					None,
+					PublicHolidayType::Public,
+				);
+				holiday.set_estimated(true);
+				holiday
+			})
+			.collect()
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use crate::{
+		calendars::hijri::HijriCalendar,
+		types::{countrycode::CountryCode, holidayid::HolidayId},
+	};
+
+	use super::HijriDate;
+
+	#[test]
+	fn test_holidays() {
+		let rule = HijriDate::new(HijriCalendar::UmmAlQura, HijriCalendar::DHU_AL_HIJJAH, 10);
+		let result = rule.holidays(HolidayId::new("TEST"), "test", "test name", 2024, CountryCode::SA);
+		assert_eq!(result.len(), 1);
+		assert_eq!(result[0].to_string(), "2024-06-16 test name");
+		assert!(result[0].estimated);
+	}
+
+	#[test]
+	fn test_holidays_twice_in_year() {
+		let rule = HijriDate::new(HijriCalendar::Arithmetic, HijriCalendar::SHAWWAL, 1);
+		assert_eq!(
+			rule.holidays(HolidayId::new("TEST"), "test", "test name", 2000, CountryCode::AE)
+				.len(),
+			2
+		);
+	}
+}
@@ -0,0 +1,141 @@
+use super::{location::Location, publicholiday::PublicHoliday};
+
+/// Days off of one location in a [`HolidayComparison`]
+#[derive(Debug, Clone)]
+pub struct LocationComparison {
+	pub location: Location,
+	/// Holidays of the location whose date isn't a day off in every other location
+	pub not_shared: Vec<PublicHoliday>,
+	/// Holidays of the location whose date isn't a day off in any other location
+	pub only: Vec<PublicHoliday>,
+	/// Number of business days of the location in the year
+	pub working_days: u32,
+}
+
+/// Comparison of the days off of several locations in a year
+///
+/// # Example
+///
+/// ```
+/// use horus::{holidayprovider::HolidayProvider, types::{countrycode::CountryCode, location::Location}};
+///
+/// let baden_wuerttemberg = Location::new(CountryCode::DE, Some("DE-BW"));
+/// let berlin = Location::new(CountryCode::DE, Some("DE-BE"));
+/// let comparison = HolidayProvider::compare_holidays(2025, &[baden_wuerttemberg.clone(), berlin.clone()]);
+///
+/// let names: Vec<&str> = comparison.only_in(&berlin).unwrap().iter().map(|holiday| holiday.name.as_str()).collect();
+///
+/// assert_eq!(names, vec!["International Women's Day"]);
+/// assert_eq!(comparison.working_day_difference(&berlin, &baden_wuerttemberg), Some(2));
+/// ```
+#[derive(Debug, Clone)]
+pub struct HolidayComparison {
+	pub year: i32,
+	/// Holidays of the first location whose date is a day off in every location
+	pub shared: Vec<PublicHoliday>,
+	pub locations: Vec<LocationComparison>,
+}
+
+impl HolidayComparison {
+	/// Gets the holidays of the given location whose date isn't a day off in any other location. If the location
+	/// isn't part of the comparison, None will be returned.
+	pub fn only_in(&self, location: &Location) -> Option<&[PublicHoliday]> {
+		self.find(location).map(|comparison| comparison.only.as_slice())
+	}
+
+	/// Gets the holidays of the given location whose date isn't a day off in every other location. If the location
+	/// isn't part of the comparison, None will be returned.
+	pub fn not_shared_by_all(&self, location: &Location) -> Option<&[PublicHoliday]> {
+		self.find(location).map(|comparison| comparison.not_shared.as_slice())
+	}
+
+	/// Gets the number of business days which the first location has more than the second location
+	pub fn working_day_difference(&self, location: &Location, other: &Location) -> Option<i64> {
+		Some(self.find(location)?.working_days as i64 - self.find(other)?.working_days as i64)
+	}
+
+	fn find(&self, location: &Location) -> Option<&LocationComparison> {
+		self.locations
+			.iter()
+			.find(|comparison| comparison.location == *location)
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use crate::{
+		customcalendar::CustomCalendar,
+		holidayprovider::HolidayProvider,
+		types::{
+			countrycode::CountryCode, location::Location, publicholiday::PublicHoliday,
+			publicholidaytype::PublicHolidayType,
+		},
+	};
+
+	#[test]
+	fn test_compare_countries() {
+		let germany = Location::new(CountryCode::DE, Some("DE-BY"));
+		let poland = Location::new(CountryCode::PL, None);
+		let united_states = Location::new(CountryCode::US, Some("US-CA"));
+		let comparison = HolidayProvider::compare_holidays(2024, &[germany.clone(), poland.clone(), united_states]);
+		let shared: Vec<String> = comparison.shared.iter().map(|holiday| holiday.to_string()).collect();
+		// easter monday is shared, because cesar chavez day is observed on the same day in california
+		assert_eq!(
+			shared,
+			vec![
+				"2024-01-01 New Year's Day",
+				"2024-04-01 Easter Monday",
+				"2024-12-25 Christmas Day"
+			]
+		);
+		// assumption day is a holiday in poland, but neither in bavaria nor in the united states
+		assert!(comparison
+			.only_in(&poland)
+			.unwrap()
+			.iter()
+			.any(|holiday| holiday.name == "Assumption Day"));
+		assert!(comparison.working_day_difference(&germany, &poland).is_some());
+		// epiphany is a holiday in bavaria and poland, so it is not shared by all but not only in poland
+		let epiphany = |holidays: &[PublicHoliday]| holidays.iter().any(|holiday| holiday.name == "Epiphany");
+		assert!(epiphany(comparison.not_shared_by_all(&poland).unwrap()));
+		assert!(!epiphany(comparison.only_in(&poland).unwrap()));
+		assert!(!epiphany(comparison.only_in(&germany).unwrap()));
+	}
+
+	#[test]
+	fn test_compare_calendars() {
+		let mut company = CustomCalendar::new(CountryCode::DE);
+		company.set_county("DE-BE").add_fixed_holiday(
+			12,
+			24,
+			"Heiligabend",
+			"Christmas Eve",
+			PublicHolidayType::Public,
+		);
+		let with_christmas_eve = Location::from_calendar("Company", company, None);
+		let berlin = Location::new(CountryCode::DE, Some("DE-BE"));
+		let comparison = HolidayProvider::compare_holidays(2024, &[with_christmas_eve.clone(), berlin.clone()]);
+		let only: Vec<String> = comparison
+			.only_in(&with_christmas_eve)
+			.unwrap()
+			.iter()
+			.map(|holiday| holiday.to_string())
+			.collect();
+		assert_eq!(only, vec!["2024-12-24 Christmas Eve"]);
+		assert!(comparison.only_in(&berlin).unwrap().is_empty());
+		assert_eq!(
+			comparison.working_day_difference(&with_christmas_eve, &berlin),
+			Some(-1)
+		);
+	}
+
+	#[test]
+	fn test_unknown_location() {
+		let germany = Location::new(CountryCode::DE, None);
+		let comparison = HolidayProvider::compare_holidays(2024, std::slice::from_ref(&germany));
+		let poland = Location::new(CountryCode::PL, None);
+		assert!(comparison.only_in(&poland).is_none());
+		assert_eq!(comparison.working_day_difference(&germany, &poland), None);
+		assert_eq!(comparison.working_day_difference(&germany, &germany), Some(0));
+	}
+}
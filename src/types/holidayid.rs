@@ -0,0 +1,267 @@
+use std::borrow::Cow;
+
+use serde::{Deserialize, Serialize};
+
+/// Stable identifier of a holiday, which doesn't depend on the language of the names or on the year
+///
+/// Holidays which are celebrated in several countries share the same identifier, like `EASTER_MONDAY` or
+/// `CHRISTMAS_DAY`, and national holidays are prefixed with the country code, like `US_THANKSGIVING` or
+/// `DE_REFORMATION_DAY`. The identifier is used as key of the
+/// [`TranslationCatalog`](crate::translations::TranslationCatalog).
+///
+/// # Example
+///
+/// ```
+/// use horus::{holidayprovider::HolidayProvider, types::{countrycode::CountryCode, holidayid::HolidayId}};
+///
+/// let holidays = HolidayProvider::get_holidays(2024, CountryCode::US);
+///
+/// assert_eq!(holidays.last().unwrap().id, HolidayId::CHRISTMAS_DAY);
+/// assert_eq!(HolidayId::US_THANKSGIVING.as_str(), "US_THANKSGIVING");
+/// assert_eq!(HolidayId::from_name("Company Day").as_str(), "COMPANY_DAY");
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Hash, PartialOrd, Ord, Serialize, Deserialize)]
+pub struct HolidayId(Cow<'static, str>);
+
+macro_rules! holiday_ids {
+	($($id:ident),* $(,)?) => {
+		impl HolidayId {
+			$(pub const $id: HolidayId = HolidayId(Cow::Borrowed(stringify!($id)));)*
+		}
+	};
+}
+
+holiday_ids!(
+	ALL_SAINTS_DAY,
+	ANNUNCIATION,
+	ARAFAT_DAY,
+	ASCENSION_DAY,
+	ASH_WEDNESDAY,
+	ASSUMPTION_DAY,
+	BOXING_DAY,
+	BUDDHAS_BIRTHDAY,
+	CARNIVAL_MONDAY,
+	CARNIVAL_TUESDAY,
+	CHILDRENS_DAY,
+	CHRISTMAS_DAY,
+	CHRISTMAS_EVE,
+	CHRISTMAS_HOLIDAY,
+	CLEAN_MONDAY,
+	CN_NATIONAL_DAY,
+	CORPUS_CHRISTI,
+	DE_AUGSBURG_PEACE_FESTIVAL,
+	DE_GERMAN_UNITY_DAY,
+	DE_LIBERATION_DAY,
+	DE_REFORMATION_DAY,
+	DE_REPENTANCE_AND_PRAYER_DAY,
+	DE_WORLD_CHILDRENS_DAY,
+	DRAGON_BOAT_FESTIVAL,
+	EARLY_CLOSE,
+	EASTER_MONDAY,
+	EASTER_SUNDAY,
+	EID_AL_ADHA,
+	EID_AL_FITR,
+	EPIPHANY,
+	GB_BATTLE_OF_THE_BOYNE,
+	GB_CORONATION_BANK_HOLIDAY,
+	GB_EARLY_MAY_BANK_HOLIDAY,
+	GB_EDINBURGH_AUTUMN_HOLIDAY,
+	GB_EDINBURGH_SPRING_HOLIDAY,
+	GB_GLASGOW_FAIR,
+	GB_GLASGOW_SEPTEMBER_WEEKEND,
+	GB_PLATINUM_JUBILEE,
+	GB_SPRING_BANK_HOLIDAY,
+	GB_STATE_FUNERAL,
+	GB_SUMMER_BANK_HOLIDAY,
+	GB_VICTORIA_DAY,
+	GG_LIBERATION_DAY,
+	GOOD_FRIDAY,
+	GR_INDEPENDENCE_DAY,
+	GR_OCHI_DAY,
+	GR_SYNAXIS_OF_THE_THEOTOKOS,
+	HOLY_SATURDAY,
+	IL_INDEPENDENCE_DAY,
+	IM_TT_SENIOR_RACE_DAY,
+	IM_TYNWALD_DAY,
+	INTERNATIONAL_WOMENS_DAY,
+	ISLAMIC_NEW_YEAR,
+	JE_LIBERATION_DAY,
+	JP_AUTUMNAL_EQUINOX_DAY,
+	JP_CITIZENS_HOLIDAY,
+	JP_COMING_OF_AGE_DAY,
+	JP_CONSTITUTION_MEMORIAL_DAY,
+	JP_CULTURE_DAY,
+	JP_EMPERORS_BIRTHDAY,
+	JP_ENTHRONEMENT_CEREMONY_DAY,
+	JP_ENTHRONEMENT_DAY,
+	JP_GREENERY_DAY,
+	JP_LABOUR_THANKSGIVING_DAY,
+	JP_MARINE_DAY,
+	JP_MOUNTAIN_DAY,
+	JP_NATIONAL_FOUNDATION_DAY,
+	JP_RESPECT_FOR_THE_AGED_DAY,
+	JP_SHOWA_DAY,
+	JP_SPORTS_DAY,
+	JP_VERNAL_EQUINOX_DAY,
+	KR_HANGUL_DAY,
+	KR_INDEPENDENCE_MOVEMENT_DAY,
+	KR_LIBERATION_DAY,
+	KR_MEMORIAL_DAY,
+	KR_NATIONAL_FOUNDATION_DAY,
+	LABOUR_DAY,
+	LUNAR_NEW_YEAR,
+	LUNAR_NEW_YEARS_EVE,
+	MARDI_GRAS,
+	MAUNDY_THURSDAY,
+	MID_AUTUMN_FESTIVAL,
+	NEW_YEARS_DAY,
+	NEW_YEARS_EVE,
+	ORTHODOX_CHRISTMAS_DAY,
+	PALM_SUNDAY,
+	PASSOVER,
+	PASSOVER_SEVENTH_DAY,
+	PENTECOST,
+	PL_CONSTITUTION_DAY,
+	PL_INDEPENDENCE_DAY,
+	PROPHETS_BIRTHDAY,
+	QINGMING_FESTIVAL,
+	ROSH_HASHANAH,
+	RU_DEFENDER_OF_THE_FATHERLAND_DAY,
+	RU_NEW_YEAR_HOLIDAY,
+	RU_RUSSIA_DAY,
+	RU_UNITY_DAY,
+	SACRED_HEART,
+	SA_FOUNDING_DAY,
+	SA_NATIONAL_DAY,
+	SHAVUOT,
+	SIMCHAT_TORAH,
+	ST_ANDREWS_DAY,
+	ST_PATRICKS_DAY,
+	ST_STEPHENS_DAY,
+	SUBSTITUTE_HOLIDAY,
+	SUKKOT,
+	TRANSFERRED_DAY_OFF,
+	TRINITY_SUNDAY,
+	UA_CONSTITUTION_DAY,
+	UA_DEFENDERS_DAY,
+	UA_INDEPENDENCE_DAY,
+	UA_VICTORY_OVER_NAZISM_DAY,
+	US_ALASKA_DAY,
+	US_AMERICAN_SAMOA_FLAG_DAY,
+	US_CASIMIR_PULASKI_DAY,
+	US_CESAR_CHAVEZ_DAY,
+	US_COLUMBUS_DAY,
+	US_COVENANT_DAY,
+	US_ELECTION_DAY,
+	US_EMANCIPATION_DAY,
+	US_GUAM_LIBERATION_DAY,
+	US_HURRICANE_SANDY,
+	US_INAUGURATION_DAY,
+	US_INDEPENDENCE_DAY,
+	US_JUNETEENTH,
+	US_KAMEHAMEHA_DAY,
+	US_LABOR_DAY,
+	US_LINCOLNS_BIRTHDAY,
+	US_MARTIN_LUTHER_KING_DAY,
+	US_MEMORIAL_DAY,
+	US_NATIONAL_DAY_OF_MOURNING,
+	US_NEVADA_DAY,
+	US_PATRIOTS_DAY,
+	US_PIONEER_DAY,
+	US_PUERTO_RICO_CONSTITUTION_DAY,
+	US_SEPTEMBER_11_ATTACKS,
+	US_SEWARDS_DAY,
+	US_TEXAS_INDEPENDENCE_DAY,
+	US_THANKSGIVING,
+	US_TRANSFER_DAY,
+	US_TRUMAN_DAY,
+	US_VETERANS_DAY,
+	US_WASHINGTONS_BIRTHDAY,
+	VICTORY_DAY,
+	WHIT_MONDAY,
+	YOM_KIPPUR,
+);
+
+impl HolidayId {
+	/// Creates an identifier from the given text, which should already be written in upper snake case
+	pub fn new(id: &str) -> Self {
+		HolidayId(Cow::Owned(id.to_string()))
+	}
+
+	/// Derives the identifier from the english name of a holiday. Apostrophes are removed and all other characters
+	/// which are no letters or digits are replaced by an underscore.
+	pub fn from_name(name: &str) -> Self {
+		let mut id = String::with_capacity(name.len());
+		for c in name.chars().filter(|c| *c != '\'' && *c != '’') {
+			if c.is_alphanumeric() {
+				id.extend(c.to_uppercase());
+			} else if !id.is_empty() && !id.ends_with('_') {
+				id.push('_');
+			}
+		}
+
+		HolidayId(Cow::Owned(id.trim_end_matches('_').to_string()))
+	}
+
+	pub fn as_str(&self) -> &str {
+		&self.0
+	}
+}
+
+impl std::fmt::Display for HolidayId {
+	fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+		write!(f, "{}", self.0)
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use crate::{holidayprovider::HolidayProvider, types::countrycode::CountryCode};
+
+	use super::HolidayId;
+
+	#[test]
+	fn test_from_name() {
+		assert_eq!(HolidayId::from_name("Easter Monday").as_str(), "EASTER_MONDAY");
+		assert_eq!(HolidayId::from_name("St. Stephen's Day").as_str(), "ST_STEPHENS_DAY");
+		assert_eq!(
+			HolidayId::from_name("Martin Luther King, Jr. Day").as_str(),
+			"MARTIN_LUTHER_KING_JR_DAY"
+		);
+		assert_eq!(HolidayId::from_name("(Julian) Christmas").as_str(), "JULIAN_CHRISTMAS");
+		assert_eq!(
+			HolidayId::from_name("Queen’s State Funeral").as_str(),
+			"QUEENS_STATE_FUNERAL"
+		);
+	}
+
+	#[test]
+	fn test_provider_ids() {
+		let id = |country_code, name: &str| {
+			HolidayProvider::get_holidays(2024, country_code)
+				.into_iter()
+				.find(|holiday| holiday.name == name)
+				.map(|holiday| holiday.id)
+		};
+		assert_eq!(id(CountryCode::GB, "St. Stephen's Day"), Some(HolidayId::BOXING_DAY));
+		assert_eq!(
+			id(CountryCode::DE, "St. Stephen's Day"),
+			Some(HolidayId::ST_STEPHENS_DAY)
+		);
+		assert_eq!(
+			id(CountryCode::PL, "Independence Day"),
+			Some(HolidayId::PL_INDEPENDENCE_DAY)
+		);
+		assert_eq!(
+			id(CountryCode::US, "Independence Day"),
+			Some(HolidayId::US_INDEPENDENCE_DAY)
+		);
+		assert_eq!(id(CountryCode::KR, "Seollal"), Some(HolidayId::LUNAR_NEW_YEAR));
+	}
+
+	#[test]
+	fn test_display() {
+		assert_eq!(HolidayId::new("EID_AL_FITR").to_string(), "EID_AL_FITR");
+		assert_eq!(HolidayId::from_name("Eid al-Fitr"), HolidayId::new("EID_AL_FITR"));
+	}
+}
@@ -0,0 +1,113 @@
+use chrono::NaiveDate;
+
+use super::{countrycode::CountryCode, publicholidaytype::PublicHolidayType};
+
+/// Holiday which lasts several days, like the school holidays
+///
+/// # Example
+///
+/// ```
+/// use horus::types::{countrycode::CountryCode, holidayperiod::HolidayPeriod, publicholidaytype::PublicHolidayType};
+/// use chrono::NaiveDate;
+///
+/// let period = HolidayPeriod::new(
+///     NaiveDate::from_ymd_opt(2024, 7, 8).unwrap(),
+///     NaiveDate::from_ymd_opt(2024, 8, 20).unwrap(),
+///     "Sommerferien",
+///     "Summer holidays",
+///     CountryCode::DE,
+///     Some(vec!["DE-NW".to_string()]),
+///     PublicHolidayType::School,
+/// );
+///
+/// assert_eq!(period.days(), 44);
+/// assert!(period.contains(NaiveDate::from_ymd_opt(2024, 8, 20).unwrap()));
+/// ```
+#[derive(Debug, Clone)]
+pub struct HolidayPeriod {
+	pub start: NaiveDate,
+	pub end: NaiveDate,
+	pub local_name: String,
+	pub name: String,
+	pub country_code: CountryCode,
+	pub counties: Option<Vec<String>>,
+	pub holiday_type: PublicHolidayType,
+}
+
+impl HolidayPeriod {
+	/// Creates a period from the first until the last day of the holidays
+	pub fn new(
+		start: NaiveDate,
+		end: NaiveDate,
+		local_name: &str,
+		name: &str,
+		country_code: CountryCode,
+		counties: Option<Vec<String>>,
+		holiday_type: PublicHolidayType,
+	) -> Self {
+		HolidayPeriod {
+			start,
+			end,
+			local_name: local_name.to_string(),
+			name: name.to_string(),
+			country_code,
+			counties,
+			holiday_type,
+		}
+	}
+
+	pub fn global(&self) -> bool {
+		self.counties.is_none()
+	}
+
+	/// Checks if the period applies to the given county, global periods apply to every county
+	pub fn is_in_county(&self, county: &str) -> bool {
+		match &self.counties {
+			Some(counties) => counties.iter().any(|c| c == county),
+			None => true,
+		}
+	}
+
+	/// Checks if the given date is a day of the period, including the first and the last day
+	pub fn contains(&self, date: NaiveDate) -> bool {
+		self.start <= date && date <= self.end
+	}
+
+	/// Gets the number of days of the period, including the first and the last day
+	pub fn days(&self) -> i64 {
+		(self.end - self.start).num_days() + 1
+	}
+}
+
+impl std::fmt::Display for HolidayPeriod {
+	fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+		write!(f, "{:?}..{:?} {}", self.start, self.end, self.name)
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use chrono::NaiveDate;
+
+	use crate::types::{countrycode::CountryCode, publicholidaytype::PublicHolidayType};
+
+	use super::HolidayPeriod;
+
+	#[test]
+	fn test_contains() {
+		let period = HolidayPeriod::new(
+			NaiveDate::from_ymd_opt(2024, 12, 23).unwrap(),
+			NaiveDate::from_ymd_opt(2025, 1, 4).unwrap(),
+			"test",
+			"test name",
+			CountryCode::DE,
+			None,
+			PublicHolidayType::School,
+		);
+		assert!(period.contains(NaiveDate::from_ymd_opt(2024, 12, 23).unwrap()));
+		assert!(period.contains(NaiveDate::from_ymd_opt(2025, 1, 1).unwrap()));
+		assert!(!period.contains(NaiveDate::from_ymd_opt(2025, 1, 5).unwrap()));
+		assert_eq!(period.days(), 13);
+		assert!(period.is_in_county("DE-BY"));
+	}
+}
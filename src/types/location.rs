@@ -0,0 +1,178 @@
+use std::sync::Arc;
+
+use super::countrycode::CountryCode;
+use crate::holidaycalendar::HolidayCalendar;
+
+/// Place where a part of a team works, which is a calendar and optionally a county and a locality within the county,
+/// weighted by the headcount
+///
+/// The calendar is a country code for the holidays of the national provider, but can be any [`HolidayCalendar`], like
+/// a [`CustomCalendar`](crate::customcalendar::CustomCalendar) with the company holidays or a
+/// [`Market`](crate::types::market::Market). Locations are identified by their name, county, locality and headcount.
+///
+/// # Example
+///
+/// ```
+/// use horus::{customcalendar::CustomCalendar, types::{countrycode::CountryCode, location::Location}};
+///
+/// let mut location = Location::new(CountryCode::DE, Some("DE-BY"));
+/// location.set_headcount(12);
+///
+/// assert_eq!(location.to_string(), "DE-BY");
+/// assert_eq!(location.headcount, 12);
+///
+/// location.set_locality("09761000");
+///
+/// assert_eq!(location.to_string(), "DE-BY 09761000");
+///
+/// let office = Location::from_calendar("Head office", CustomCalendar::new(CountryCode::DE), Some("DE-BE"));
+///
+/// assert_eq!(office.to_string(), "Head office");
+/// ```
+#[derive(Clone)]
+pub struct Location {
+	pub name: String,
+	pub calendar: Arc<dyn HolidayCalendar + Send + Sync>,
+	pub county: Option<String>,
+	/// Municipality within the county, either as official municipality key (AGS) or as name
+	pub locality: Option<String>,
+	pub headcount: u32,
+}
+
+impl Location {
+	/// Creates a location of the national provider with a headcount of one, which is named after the county or, if no
+	/// county is given, after the country
+	pub fn new(country_code: CountryCode, county: Option<&str>) -> Self {
+		let name = match county {
+			Some(county) => county.to_string(),
+			None => format!("{country_code:?}"),
+		};
+		Self::from_calendar(&name, country_code, county)
+	}
+
+	/// Creates a location of the given calendar with a headcount of one
+	pub fn from_calendar<C: HolidayCalendar + Send + Sync + 'static>(
+		name: &str,
+		calendar: C,
+		county: Option<&str>,
+	) -> Self {
+		Location {
+			name: name.to_string(),
+			calendar: Arc::new(calendar),
+			county: county.map(|county| county.to_string()),
+			locality: None,
+			headcount: 1,
+		}
+	}
+
+	pub fn set_locality(&mut self, locality: &str) -> &mut Self {
+		self.locality = Some(locality.to_string());
+		self
+	}
+
+	pub fn set_headcount(&mut self, headcount: u32) -> &mut Self {
+		self.headcount = headcount;
+		self
+	}
+
+	/// Gets the county of the location, which falls back to the county of the calendar
+	pub(crate) fn get_county(&self) -> Option<&str> {
+		self.county.as_deref().or(self.calendar.county())
+	}
+
+	/// Gets the locality of the location, which falls back to the locality of the calendar
+	pub(crate) fn get_locality(&self) -> Option<&str> {
+		self.locality.as_deref().or(self.calendar.locality())
+	}
+
+	fn key(&self) -> (&str, Option<&str>, Option<&str>, u32) {
+		(
+			&self.name,
+			self.county.as_deref(),
+			self.locality.as_deref(),
+			self.headcount,
+		)
+	}
+}
+
+impl PartialEq for Location {
+	fn eq(&self, other: &Self) -> bool {
+		self.key() == other.key()
+	}
+}
+
+impl Eq for Location {}
+
+impl std::hash::Hash for Location {
+	fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
+		self.key().hash(state);
+	}
+}
+
+impl std::fmt::Debug for Location {
+	fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+		f.debug_struct("Location")
+			.field("name", &self.name)
+			.field("county", &self.county)
+			.field("locality", &self.locality)
+			.field("headcount", &self.headcount)
+			.finish()
+	}
+}
+
+impl std::fmt::Display for Location {
+	fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+		write!(f, "{}", self.name)?;
+		match &self.locality {
+			Some(locality) => write!(f, " {locality}"),
+			None => Ok(()),
+		}
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use crate::{
+		customcalendar::CustomCalendar,
+		types::{countrycode::CountryCode, market::Market},
+	};
+
+	use super::Location;
+
+	#[test]
+	fn test_display() {
+		assert_eq!(Location::new(CountryCode::PL, None).to_string(), "PL");
+		assert_eq!(Location::new(CountryCode::US, Some("US-CA")).to_string(), "US-CA");
+		assert_eq!(
+			Location::new(CountryCode::DE, Some("DE-SN"))
+				.set_locality("Crostwitz")
+				.to_string(),
+			"DE-SN Crostwitz"
+		);
+		assert_eq!(
+			Location::from_calendar("Trading desk", Market::NYSE, None).to_string(),
+			"Trading desk"
+		);
+	}
+
+	#[test]
+	fn test_eq() {
+		assert_eq!(
+			Location::new(CountryCode::DE, Some("DE-BY")),
+			Location::new(CountryCode::DE, Some("DE-BY"))
+		);
+		assert_ne!(
+			Location::new(CountryCode::DE, Some("DE-BY")),
+			Location::new(CountryCode::DE, Some("DE-BE"))
+		);
+	}
+	#[test]
+	fn test_county_of_calendar() {
+		let mut calendar = CustomCalendar::new(CountryCode::DE);
+		calendar.set_county("DE-BY").set_locality("Augsburg");
+		let location = Location::from_calendar("Augsburg office", calendar, None);
+		assert_eq!(location.get_county(), Some("DE-BY"));
+		assert_eq!(location.get_locality(), Some("Augsburg"));
+		assert_eq!(Location::new(CountryCode::PL, None).get_county(), None);
+	}
+}
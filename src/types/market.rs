@@ -0,0 +1,48 @@
+/// Supported exchanges and payment systems with their own trading calendar
+///
+/// A market is a [`HolidayCalendar`](crate::holidaycalendar::HolidayCalendar), so the business day functions of the
+/// [`HolidayProvider`](crate::holidayprovider::HolidayProvider) can be used to calculate settlement dates. Days on
+/// which the market closes early are holidays which start at the time of the early close.
+///
+/// # Example
+///
+/// ```
+/// use horus::{holidayprovider::HolidayProvider, types::market::Market};
+/// use chrono::{NaiveDate, NaiveTime};
+///
+/// // the trade on the wednesday before good friday settles on tuesday (T+2)
+/// let trade_date = NaiveDate::from_ymd_opt(2024, 3, 27).unwrap();
+/// let settlement_date = HolidayProvider::add_business_days(trade_date, 2, Market::TARGET2, None);
+///
+/// assert_eq!(settlement_date, NaiveDate::from_ymd_opt(2024, 4, 2).unwrap());
+///
+/// // the new york stock exchange closes early on the day after thanksgiving
+/// let opening = NaiveTime::from_hms_opt(9, 30, 0).unwrap();
+/// let closing = NaiveTime::from_hms_opt(16, 0, 0).unwrap();
+/// let black_friday = NaiveDate::from_ymd_opt(2024, 11, 29).unwrap();
+/// let result = HolidayProvider::get_working_hours(black_friday, Market::NYSE, None, opening, closing);
+///
+/// assert_eq!(result, vec![(opening, NaiveTime::from_hms_opt(13, 0, 0).unwrap())]);
+///
+/// // the federal reserve doesn't observe independence day on a saturday on the friday before
+/// let friday = NaiveDate::from_ymd_opt(2026, 7, 3).unwrap();
+///
+/// assert!(HolidayProvider::is_business_day(friday, Market::FederalReserve, None));
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Market {
+	/// Federal reserve banks of the united states, which settle the ACH and Fedwire payments
+	FederalReserve,
+
+	/// New York Stock Exchange
+	NYSE,
+
+	/// London Stock Exchange
+	LSE,
+
+	/// Xetra trading venue of the Frankfurt Stock Exchange
+	Xetra,
+
+	/// Trans-European Automated Real-time Gross Settlement Express Transfer System of the eurosystem
+	TARGET2,
+}
@@ -1,4 +1,19 @@
+pub mod computus;
 pub mod countrycode;
+pub mod easterrelative;
+pub mod fixeddate;
+pub mod hijridate;
+pub mod holidaycomparison;
+pub mod holidayid;
+pub mod holidayperiod;
+pub mod location;
+pub mod market;
+pub mod moveablefeast;
 pub mod occurrence;
+pub mod proclaimedholiday;
 pub mod publicholiday;
 pub mod publicholidaytype;
+pub mod recurrence;
+pub mod season;
+pub mod suspensionperiod;
+pub mod workingdayoverride;
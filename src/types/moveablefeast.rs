@@ -0,0 +1,166 @@
+use super::{
+	computus::Computus, countrycode::CountryCode, easterrelative::EasterRelative, holidayid::HolidayId,
+	publicholiday::PublicHoliday,
+};
+
+/// Catalogue of the named feasts which depend on the date of easter sunday
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum MoveableFeast {
+	// Rose Monday, Monday before Ash Wednesday
+	CarnivalMonday,
+	// Shrove Tuesday, Tuesday before Ash Wednesday
+	CarnivalTuesday,
+	// First day of Lent
+	AshWednesday,
+	// First day of the orthodox Great Lent
+	CleanMonday,
+	// Sunday before easter
+	PalmSunday,
+	MaundyThursday,
+	GoodFriday,
+	HolySaturday,
+	EasterSunday,
+	EasterMonday,
+	AscensionDay,
+	Pentecost,
+	WhitMonday,
+	// Sunday after Pentecost
+	TrinitySunday,
+	CorpusChristi,
+	// Friday after the second Sunday after Pentecost
+	SacredHeart,
+}
+
+impl MoveableFeast {
+	/// Gets the days between easter sunday and the feast
+	pub fn offset_days(&self) -> i64 {
+		match self {
+			MoveableFeast::CarnivalMonday => -48,
+			MoveableFeast::CarnivalTuesday => -47,
+			MoveableFeast::AshWednesday => -46,
+			MoveableFeast::CleanMonday => -48,
+			MoveableFeast::PalmSunday => -7,
+			MoveableFeast::MaundyThursday => -3,
+			MoveableFeast::GoodFriday => -2,
+			MoveableFeast::HolySaturday => -1,
+			MoveableFeast::EasterSunday => 0,
+			MoveableFeast::EasterMonday => 1,
+			MoveableFeast::AscensionDay => 39,
+			MoveableFeast::Pentecost => 49,
+			MoveableFeast::WhitMonday => 50,
+			MoveableFeast::TrinitySunday => 56,
+			MoveableFeast::CorpusChristi => 60,
+			MoveableFeast::SacredHeart => 68,
+		}
+	}
+
+	/// Gets the english name of the feast
+	pub fn name(&self) -> &'static str {
+		match self {
+			MoveableFeast::CarnivalMonday => "Carnival Monday",
+			MoveableFeast::CarnivalTuesday => "Carnival Tuesday",
+			MoveableFeast::AshWednesday => "Ash Wednesday",
+			MoveableFeast::CleanMonday => "Clean Monday",
+			MoveableFeast::PalmSunday => "Palm Sunday",
+			MoveableFeast::MaundyThursday => "Maundy Thursday",
+			MoveableFeast::GoodFriday => "Good Friday",
+			MoveableFeast::HolySaturday => "Holy Saturday",
+			MoveableFeast::EasterSunday => "Easter Sunday",
+			MoveableFeast::EasterMonday => "Easter Monday",
+			MoveableFeast::AscensionDay => "Ascension Day",
+			MoveableFeast::Pentecost => "Pentecost",
+			MoveableFeast::WhitMonday => "Whit Monday",
+			MoveableFeast::TrinitySunday => "Trinity Sunday",
+			MoveableFeast::CorpusChristi => "Corpus Christi",
+			MoveableFeast::SacredHeart => "Sacred Heart",
+		}
+	}
+
+	/// Gets the identifier of the feast
+	pub fn id(&self) -> HolidayId {
+		match self {
+			MoveableFeast::CarnivalMonday => HolidayId::CARNIVAL_MONDAY,
+			MoveableFeast::CarnivalTuesday => HolidayId::CARNIVAL_TUESDAY,
+			MoveableFeast::AshWednesday => HolidayId::ASH_WEDNESDAY,
+			MoveableFeast::CleanMonday => HolidayId::CLEAN_MONDAY,
+			MoveableFeast::PalmSunday => HolidayId::PALM_SUNDAY,
+			MoveableFeast::MaundyThursday => HolidayId::MAUNDY_THURSDAY,
+			MoveableFeast::GoodFriday => HolidayId::GOOD_FRIDAY,
+			MoveableFeast::HolySaturday => HolidayId::HOLY_SATURDAY,
+			MoveableFeast::EasterSunday => HolidayId::EASTER_SUNDAY,
+			MoveableFeast::EasterMonday => HolidayId::EASTER_MONDAY,
+			MoveableFeast::AscensionDay => HolidayId::ASCENSION_DAY,
+			MoveableFeast::Pentecost => HolidayId::PENTECOST,
+			MoveableFeast::WhitMonday => HolidayId::WHIT_MONDAY,
+			MoveableFeast::TrinitySunday => HolidayId::TRINITY_SUNDAY,
+			MoveableFeast::CorpusChristi => HolidayId::CORPUS_CHRISTI,
+			MoveableFeast::SacredHeart => HolidayId::SACRED_HEART,
+		}
+	}
+
+	/// Gets the easter relative rule of the feast for the given computus
+	pub fn rule(&self, computus: Computus) -> EasterRelative {
+		EasterRelative::new(computus, self.offset_days())
+	}
+
+	/// Creates a public holiday for the feast in the given year
+	///
+	/// # Example
+	///
+	/// ```
+	/// use horus::types::{computus::Computus, countrycode::CountryCode, moveablefeast::MoveableFeast};
+	///
+	/// let holiday = MoveableFeast::AshWednesday.holiday(Computus::Gregorian, "Aschermittwoch", 2022, CountryCode::DE);
+	///
+	/// assert_eq!(holiday.to_string(), "2022-03-02 Ash Wednesday");
+	/// ```
+	pub fn holiday(&self, computus: Computus, local_name: &str, year: i32, country_code: CountryCode) -> PublicHoliday {
+		self.rule(computus)
+			.holiday(self.id(), local_name, self.name(), year, country_code)
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use crate::types::{computus::Computus, countrycode::CountryCode};
+
+	use super::MoveableFeast;
+
+	#[test]
+	fn test_carnival() {
+		let monday = MoveableFeast::CarnivalMonday.holiday(Computus::Gregorian, "test", 2022, CountryCode::DE);
+		let tuesday = MoveableFeast::CarnivalTuesday.holiday(Computus::Gregorian, "test", 2022, CountryCode::DE);
+		assert_eq!(monday.to_string(), "2022-02-28 Carnival Monday");
+		assert_eq!(tuesday.to_string(), "2022-03-01 Carnival Tuesday");
+	}
+
+	#[test]
+	fn test_clean_monday() {
+		let result = MoveableFeast::CleanMonday.holiday(Computus::Julian, "test", 2022, CountryCode::GR);
+		assert_eq!(result.to_string(), "2022-03-07 Clean Monday");
+	}
+
+	#[test]
+	fn test_palm_sunday() {
+		let result = MoveableFeast::PalmSunday.holiday(Computus::Gregorian, "test", 2022, CountryCode::DE);
+		assert_eq!(result.to_string(), "2022-04-10 Palm Sunday");
+	}
+
+	#[test]
+	fn test_holy_saturday() {
+		let result = MoveableFeast::HolySaturday.holiday(Computus::Gregorian, "test", 2022, CountryCode::DE);
+		assert_eq!(result.to_string(), "2022-04-16 Holy Saturday");
+	}
+
+	#[test]
+	fn test_trinity_sunday() {
+		let result = MoveableFeast::TrinitySunday.holiday(Computus::Gregorian, "test", 2022, CountryCode::DE);
+		assert_eq!(result.to_string(), "2022-06-12 Trinity Sunday");
+	}
+
+	#[test]
+	fn test_sacred_heart() {
+		let result = MoveableFeast::SacredHeart.holiday(Computus::Gregorian, "test", 2022, CountryCode::DE);
+		assert_eq!(result.to_string(), "2022-06-24 Sacred Heart");
+	}
+}
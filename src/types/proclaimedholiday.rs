@@ -0,0 +1,102 @@
+use chrono::NaiveDate;
+use serde::{Deserialize, Serialize};
+
+use super::{
+	countrycode::CountryCode, holidayid::HolidayId, publicholiday::PublicHoliday, publicholidaytype::PublicHolidayType,
+};
+
+/// One-off holiday which was proclaimed by the government of a country, like a jubilee or a state funeral, or a
+/// regular holiday which was moved to another date in a single year
+///
+/// # Example
+///
+/// ```
+/// use horus::types::{countrycode::CountryCode, proclaimedholiday::ProclaimedHoliday};
+/// use chrono::NaiveDate;
+///
+/// let mut spring_bank_holiday = ProclaimedHoliday::new(
+///     NaiveDate::from_ymd_opt(2022, 6, 2).unwrap(),
+///     "Spring Bank Holiday",
+///     "Spring Bank Holiday",
+///     CountryCode::GB,
+///     "https://www.gov.uk/government/news/extra-bank-holiday-to-mark-the-queens-platinum-jubilee-in-2022",
+/// );
+/// spring_bank_holiday.set_replaces(NaiveDate::from_ymd_opt(2022, 5, 30).unwrap());
+///
+/// assert_eq!(spring_bank_holiday.to_holiday().to_string(), "2022-06-02 Spring Bank Holiday");
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ProclaimedHoliday {
+	/// Identifier of the holiday, which is derived from the english name if it isn't set
+	#[serde(default)]
+	pub id: Option<HolidayId>,
+	pub date: NaiveDate,
+	pub local_name: String,
+	pub name: String,
+	pub country_code: CountryCode,
+	#[serde(default)]
+	pub counties: Option<Vec<String>>,
+	#[serde(default = "ProclaimedHoliday::default_holiday_type")]
+	pub holiday_type: PublicHolidayType,
+	/// Regular date of the holiday with the same name, which doesn't take place if the holiday was moved
+	#[serde(default)]
+	pub replaces: Option<NaiveDate>,
+	/// Reference to the proclamation, like the url of the announcement or the gazette
+	pub source: String,
+}
+
+impl ProclaimedHoliday {
+	/// Creates a public holiday for the whole country
+	pub fn new(date: NaiveDate, local_name: &str, name: &str, country_code: CountryCode, source: &str) -> Self {
+		ProclaimedHoliday {
+			id: None,
+			date,
+			local_name: local_name.to_string(),
+			name: name.to_string(),
+			country_code,
+			counties: None,
+			holiday_type: Self::default_holiday_type(),
+			replaces: None,
+			source: source.to_string(),
+		}
+	}
+
+	pub fn set_counties(&mut self, counties: Vec<String>) -> &mut Self {
+		self.counties = Some(counties);
+		self
+	}
+
+	pub fn set_holiday_type(&mut self, holiday_type: PublicHolidayType) -> &mut Self {
+		self.holiday_type = holiday_type;
+		self
+	}
+
+	pub fn set_id(&mut self, id: HolidayId) -> &mut Self {
+		self.id = Some(id);
+		self
+	}
+
+	/// Sets the regular date of the holiday which was moved to the date of the proclamation
+	pub fn set_replaces(&mut self, replaces: NaiveDate) -> &mut Self {
+		self.replaces = Some(replaces);
+		self
+	}
+
+	/// Gets the holiday as it is returned by the providers
+	pub fn to_holiday(&self) -> PublicHoliday {
+		PublicHoliday::new_non_fixed(
+			self.id.clone().unwrap_or_else(|| HolidayId::from_name(&self.name)),
+			self.date,
+			&self.local_name,
+			&self.name,
+			self.country_code,
+			None,
+			self.counties.clone(),
+			self.holiday_type,
+		)
+	}
+
+	fn default_holiday_type() -> PublicHolidayType {
+		PublicHolidayType::Public
+	}
+}
@@ -1,21 +1,41 @@
-use super::{countrycode::CountryCode, publicholidaytype::PublicHolidayType};
-use chrono::{DateTime, TimeZone, Utc};
+use super::{countrycode::CountryCode, holidayid::HolidayId, publicholidaytype::PublicHolidayType};
+use crate::{calendars::reform::CalendarReform, translations::TranslationCatalog};
+use chrono::{NaiveDate, NaiveTime};
+use std::collections::HashMap;
 
-#[derive(Debug)]
+#[derive(Debug, Clone)]
 pub struct PublicHoliday {
-	pub date: DateTime<Utc>,
+	pub id: HolidayId,
+	pub date: NaiveDate,
 	pub local_name: String,
 	pub name: String,
 	pub country_code: CountryCode,
 	pub fixed: bool,
 	pub counties: Option<Vec<String>>,
+	/// Municipalities to which the holiday is restricted, keyed by the county they belong to, either as official
+	/// municipality keys (AGS) or as names. Counties without an entry keep the holiday for all of their municipalities.
+	pub localities: Option<HashMap<String, Vec<String>>>,
+	/// Municipalities in which the holiday doesn't apply, keyed by the county they belong to, in the same format as the
+	/// localities
+	pub excluded_localities: Option<HashMap<String, Vec<String>>>,
 	pub holiday_type: PublicHolidayType,
+	/// Whether the holiday gives a day off, which is false if the days off are suspended, e.g. under martial law
+	pub non_working: bool,
 	pub launch_year: Option<i32>,
+	pub starts_previous_evening: bool,
+	pub estimated: bool,
+	pub start_time: Option<NaiveTime>,
+	pub end_time: Option<NaiveTime>,
 }
 
 impl PublicHoliday {
+	/// Creates a holiday which takes place every year on the same month and day. For years before the country adopted
+	/// the gregorian calendar, the date is taken from the julian calendar and converted into the gregorian calendar,
+	/// see [`CalendarReform::fixed_date`]. Panics if the date doesn't exist in the year, e.g. because it was skipped by
+	/// the reform.
 	#[allow(clippy::too_many_arguments)]
 	pub fn new_fixed(
+		id: HolidayId,
 		year: i32,
 		month: u32,
 		day: u32,
@@ -26,21 +46,31 @@ impl PublicHoliday {
 		counties: Option<Vec<String>>,
 		holiday_type: PublicHolidayType,
 	) -> Self {
-		let date = Utc.with_ymd_and_hms(year, month, day, 0, 0, 0).unwrap();
+		let date = CalendarReform::fixed_date(country_code, year, month, day).unwrap();
 		PublicHoliday {
+			id,
 			date,
 			local_name: local_name.to_string(),
 			name: name.to_string(),
 			country_code,
 			fixed: true,
 			counties,
+			localities: None,
+			excluded_localities: None,
 			holiday_type,
+			non_working: true,
 			launch_year,
+			starts_previous_evening: false,
+			estimated: false,
+			start_time: None,
+			end_time: None,
 		}
 	}
 
+	#[allow(clippy::too_many_arguments)]
 	pub fn new_non_fixed(
-		date: DateTime<Utc>,
+		id: HolidayId,
+		date: NaiveDate,
 		local_name: &str,
 		name: &str,
 		country_code: CountryCode,
@@ -49,35 +79,207 @@ impl PublicHoliday {
 		holiday_type: PublicHolidayType,
 	) -> Self {
 		PublicHoliday {
+			id,
 			date,
 			local_name: local_name.to_string(),
 			name: name.to_string(),
 			country_code,
 			fixed: false,
 			counties,
+			localities: None,
+			excluded_localities: None,
 			holiday_type,
+			non_working: true,
 			launch_year,
+			starts_previous_evening: false,
+			estimated: false,
+			start_time: None,
+			end_time: None,
 		}
 	}
 
+	/// Gets the name of the holiday in the given locale from the [`TranslationCatalog`]. If no translation exists,
+	/// the english name will be returned.
+	///
+	/// # Example
+	///
+	/// ```
+	/// use horus::{holidayprovider::HolidayProvider, types::countrycode::CountryCode};
+	///
+	/// let holidays = HolidayProvider::get_holidays(2024, CountryCode::DE);
+	/// let new_years_day = holidays.iter().find(|holiday| holiday.local_name == "Neujahr").unwrap();
+	///
+	/// assert_eq!(new_years_day.name_in("uk"), "Новий рік");
+	/// assert_eq!(new_years_day.name_in("ja"), "New Year's Day");
+	/// ```
+	pub fn name_in(&self, locale: &str) -> String {
+		TranslationCatalog::get(&self.id, locale).unwrap_or_else(|| self.name.clone())
+	}
+
 	pub fn global(&self) -> bool {
 		self.counties.is_none()
 	}
 
+	/// Checks if the holiday only lasts a part of the day, because it has a local start or end time
+	pub fn is_partial_day(&self) -> bool {
+		self.start_time.is_some() || self.end_time.is_some()
+	}
+
+	/// Checks if the holiday applies to the whole given county, global holidays apply to every county. Holidays which
+	/// are restricted to localities of the county only apply to some of its municipalities, see
+	/// [`PublicHoliday::is_in_location`].
+	pub fn is_in_county(&self, county: &str) -> bool {
+		self.is_in_counties(county)
+			&& self.get_localities(county).is_none()
+			&& self.get_excluded_localities(county).is_none()
+	}
+
+	/// Checks if the holiday applies to the given locality of the county. Global holidays apply to every county and
+	/// holidays without localities in the county to all of its municipalities, while holidays which are restricted to
+	/// localities of the county never apply if no locality is given. The same holds for holidays which exclude some
+	/// localities of the county, which apply to all other localities. A locality of the holiday which is an official
+	/// municipality key matches every key starting with it, so that the key `16061` of the district Eichsfeld matches
+	/// all of its municipalities. Names are compared case insensitive.
+	///
+	/// # Example
+	///
+	/// ```
+	/// use horus::{holidayprovider::HolidayProvider, types::countrycode::CountryCode};
+	///
+	/// let holidays = HolidayProvider::get_holidays(2024, CountryCode::DE);
+	/// let peace_festival = holidays.iter().find(|holiday| holiday.name == "Augsburg Peace Festival").unwrap();
+	///
+	/// assert!(peace_festival.is_in_location("DE-BY", Some("09761000")));
+	/// assert!(peace_festival.is_in_location("DE-BY", Some("augsburg")));
+	/// assert!(!peace_festival.is_in_location("DE-BY", Some("09162000")));
+	/// assert!(!peace_festival.is_in_location("DE-BY", None));
+	/// assert!(!peace_festival.is_in_county("DE-BY"));
+	/// ```
+	pub fn is_in_location(&self, county: &str, locality: Option<&str>) -> bool {
+		if !self.is_in_counties(county) {
+			return false;
+		}
+		if self.get_localities(county).is_none() && self.get_excluded_localities(county).is_none() {
+			return true;
+		}
+		let Some(locality) = locality.map(str::trim) else {
+			return false;
+		};
+
+		self.get_localities(county)
+			.map_or(true, |localities| Self::matches_locality(localities, locality))
+			&& !self
+				.get_excluded_localities(county)
+				.map_or(false, |localities| Self::matches_locality(localities, locality))
+	}
+
+	/// Gets the localities to which the holiday is restricted within the given county. If the holiday applies to the
+	/// whole county, None will be returned.
+	pub fn get_localities(&self, county: &str) -> Option<&[String]> {
+		self.localities
+			.as_ref()
+			.and_then(|localities| localities.get(county))
+			.map(Vec::as_slice)
+	}
+
+	/// Gets the localities in which the holiday doesn't apply within the given county. If no locality of the county is
+	/// excluded, None will be returned.
+	pub fn get_excluded_localities(&self, county: &str) -> Option<&[String]> {
+		self.excluded_localities
+			.as_ref()
+			.and_then(|localities| localities.get(county))
+			.map(Vec::as_slice)
+	}
+
+	fn matches_locality(localities: &[String], locality: &str) -> bool {
+		localities.iter().any(|l| {
+			if l.chars().all(|c| c.is_ascii_digit()) {
+				locality.starts_with(l.as_str())
+			} else {
+				l.to_lowercase() == locality.to_lowercase()
+			}
+		})
+	}
+
+	fn is_in_counties(&self, county: &str) -> bool {
+		match &self.counties {
+			Some(counties) => counties.iter().any(|c| c == county),
+			None => true,
+		}
+	}
+
 	pub fn set_counties(&mut self, counties: Vec<String>) -> &mut Self {
 		self.counties = Some(counties);
 		self
 	}
 
+	/// Restricts the holiday to the given municipalities within one of its counties, the other counties keep the
+	/// holiday for all of their municipalities
+	pub fn set_localities(&mut self, county: &str, localities: Vec<String>) -> &mut Self {
+		self.localities
+			.get_or_insert_with(HashMap::new)
+			.insert(county.to_string(), localities);
+		self
+	}
+
+	/// Excludes the given municipalities within one of its counties from the holiday, the other municipalities of the
+	/// county keep it
+	pub fn set_excluded_localities(&mut self, county: &str, localities: Vec<String>) -> &mut Self {
+		self.excluded_localities
+			.get_or_insert_with(HashMap::new)
+			.insert(county.to_string(), localities);
+		self
+	}
+
 	pub fn set_launch_year(&mut self, launch_year: i32) -> &mut Self {
 		self.launch_year = Some(launch_year);
 		self
 	}
 
+	pub fn set_id(&mut self, id: HolidayId) -> &mut Self {
+		self.id = id;
+		self
+	}
+
 	pub fn set_holiday_type(&mut self, holiday_type: PublicHolidayType) -> &mut Self {
 		self.holiday_type = holiday_type;
 		self
 	}
+
+	/// Marks the holiday as a day off or as a regular working day, if the days off are suspended
+	pub fn set_non_working(&mut self, non_working: bool) -> &mut Self {
+		self.non_working = non_working;
+		self
+	}
+
+	/// Marks the holiday as starting at the evening before its date, like the holidays of the hebrew calendar
+	pub fn set_starts_previous_evening(&mut self, starts_previous_evening: bool) -> &mut Self {
+		self.starts_previous_evening = starts_previous_evening;
+		self
+	}
+
+	/// Sets the local time at which the holiday starts, the time before is a regular working time
+	pub fn set_start_time(&mut self, start_time: NaiveTime) -> &mut Self {
+		self.start_time = Some(start_time);
+		self
+	}
+
+	/// Sets the local time at which the holiday ends, the time after is a regular working time
+	pub fn set_end_time(&mut self, end_time: NaiveTime) -> &mut Self {
+		self.end_time = Some(end_time);
+		self
+	}
+
+	/// Marks the holiday as a half day which starts at noon, like christmas eve in several countries
+	pub fn set_from_noon(&mut self) -> &mut Self {
+		self.set_start_time(NaiveTime::from_hms_opt(12, 0, 0).unwrap())
+	}
+
+	/// Marks the date of the holiday as an estimation, e.g. if it depends on the sighting of the moon
+	pub fn set_estimated(&mut self, estimated: bool) -> &mut Self {
+		self.estimated = estimated;
+		self
+	}
 }
 
 impl std::fmt::Display for PublicHoliday {
@@ -90,14 +292,15 @@ impl std::fmt::Display for PublicHoliday {
 mod tests {
 	use chrono::Utc;
 
-	use crate::types::{countrycode::CountryCode, publicholidaytype::PublicHolidayType};
+	use crate::types::{countrycode::CountryCode, holidayid::HolidayId, publicholidaytype::PublicHolidayType};
 
 	use super::PublicHoliday;
 
 	#[test]
 	fn test_set_counties() {
 		let mut holiday = PublicHoliday::new_non_fixed(
-			Utc::now(),
+			HolidayId::new("TEST_NAME"),
+			Utc::now().date_naive(),
 			"test",
 			"test name",
 			CountryCode::DE,
@@ -111,10 +314,75 @@ mod tests {
 		assert_eq!(holiday.counties.unwrap()[0], "DE-NW".to_string());
 	}
 
+	#[test]
+	fn test_is_in_county() {
+		let mut holiday = PublicHoliday::new_non_fixed(
+			HolidayId::new("TEST_NAME"),
+			Utc::now().date_naive(),
+			"test",
+			"test name",
+			CountryCode::DE,
+			None,
+			None,
+			PublicHolidayType::Public,
+		);
+		assert!(holiday.is_in_county("DE-BY"));
+		holiday.set_counties(vec!["DE-NW".to_string()]);
+		assert!(holiday.is_in_county("DE-NW"));
+		assert!(!holiday.is_in_county("DE-BY"));
+		holiday.set_localities("DE-NW", vec!["Köln".to_string()]);
+		assert!(!holiday.is_in_county("DE-NW"));
+		assert!(holiday.is_in_location("DE-NW", Some("Köln")));
+		assert!(!holiday.is_in_location("DE-NW", None));
+		assert!(!holiday.is_in_location("DE-BY", Some("Köln")));
+		holiday
+			.set_counties(vec!["DE-NW".to_string(), "DE-HE".to_string()])
+			.set_excluded_localities("DE-HE", vec!["06411000".to_string()]);
+		assert!(!holiday.is_in_county("DE-HE"));
+		assert!(holiday.is_in_location("DE-HE", Some("06412000")));
+		assert!(!holiday.is_in_location("DE-HE", Some("06411000")));
+		assert!(!holiday.is_in_location("DE-HE", None));
+	}
+
+	#[test]
+	fn test_new_fixed_before_calendar_reform() {
+		let holiday = PublicHoliday::new_fixed(
+			HolidayId::new("TEST_NAME"),
+			1900,
+			1,
+			1,
+			"test",
+			"test name",
+			CountryCode::RU,
+			None,
+			None,
+			PublicHolidayType::Public,
+		);
+		assert_eq!(holiday.to_string(), "1900-01-13 test name");
+	}
+
+	#[test]
+	fn test_set_id() {
+		let mut holiday = PublicHoliday::new_non_fixed(
+			HolidayId::new("TEST_NAME"),
+			Utc::now().date_naive(),
+			"test",
+			"test name",
+			CountryCode::DE,
+			None,
+			None,
+			PublicHolidayType::Public,
+		);
+		assert_eq!(holiday.id.as_str(), "TEST_NAME");
+		holiday.set_id(HolidayId::new("COMPANY_ANNIVERSARY"));
+		assert_eq!(holiday.id.as_str(), "COMPANY_ANNIVERSARY");
+	}
+
 	#[test]
 	fn test_set_holiday_type() {
 		let mut holiday = PublicHoliday::new_non_fixed(
-			Utc::now(),
+			HolidayId::new("TEST_NAME"),
+			Utc::now().date_naive(),
 			"test",
 			"test name",
 			CountryCode::DE,
@@ -130,7 +398,8 @@ mod tests {
 	#[test]
 	fn test_set_launch_year() {
 		let mut holiday = PublicHoliday::new_non_fixed(
-			Utc::now(),
+			HolidayId::new("TEST_NAME"),
+			Utc::now().date_naive(),
 			"test",
 			"test name",
 			CountryCode::DE,
@@ -143,4 +412,59 @@ mod tests {
 		assert_eq!(holiday.launch_year.is_some(), true);
 		assert_eq!(holiday.launch_year.unwrap(), 1991);
 	}
+
+	#[test]
+	fn test_set_starts_previous_evening() {
+		let mut holiday = PublicHoliday::new_non_fixed(
+			HolidayId::new("TEST_NAME"),
+			Utc::now().date_naive(),
+			"test",
+			"test name",
+			CountryCode::IL,
+			None,
+			None,
+			PublicHolidayType::Public,
+		);
+		assert!(!holiday.starts_previous_evening);
+		holiday.set_starts_previous_evening(true);
+		assert!(holiday.starts_previous_evening);
+	}
+
+	#[test]
+	fn test_set_estimated() {
+		let mut holiday = PublicHoliday::new_non_fixed(
+			HolidayId::new("TEST_NAME"),
+			Utc::now().date_naive(),
+			"test",
+			"test name",
+			CountryCode::SA,
+			None,
+			None,
+			PublicHolidayType::Public,
+		);
+		assert!(!holiday.estimated);
+		holiday.set_estimated(true);
+		assert!(holiday.estimated);
+	}
+
+	#[test]
+	fn test_set_from_noon() {
+		let mut holiday = PublicHoliday::new_fixed(
+			HolidayId::new("TEST_NAME"),
+			2024,
+			12,
+			24,
+			"Heiligabend",
+			"Christmas Eve",
+			CountryCode::DE,
+			None,
+			None,
+			PublicHolidayType::Bank,
+		);
+		assert!(!holiday.is_partial_day());
+		holiday.set_from_noon();
+		assert!(holiday.is_partial_day());
+		assert_eq!(holiday.start_time.unwrap().to_string(), "12:00:00");
+		assert!(holiday.end_time.is_none());
+	}
 }
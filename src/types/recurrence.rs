@@ -0,0 +1,57 @@
+/// Rule for holidays which don't take place every year, but every few years in a fixed cycle
+///
+/// # Example
+///
+/// ```
+/// use horus::types::recurrence::Recurrence;
+///
+/// let inauguration = Recurrence::new(1937, 4);
+///
+/// assert!(inauguration.occurs_in(2025));
+/// assert!(!inauguration.occurs_in(2026));
+/// assert!(!inauguration.occurs_in(1933));
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Recurrence {
+	pub start_year: i32,
+	pub cycle: i32,
+	pub end_year: Option<i32>,
+}
+
+impl Recurrence {
+	/// Creates a rule which occurs in the start year and then every cycle years, a cycle of one occurs every year
+	pub fn new(start_year: i32, cycle: i32) -> Self {
+		Recurrence { start_year, cycle, end_year: None }
+	}
+
+	/// Sets the last year in which the rule can occur
+	pub fn set_end_year(&mut self, end_year: i32) -> &mut Self {
+		self.end_year = Some(end_year);
+		self
+	}
+
+	/// Checks if the rule occurs in the given year
+	pub fn occurs_in(&self, year: i32) -> bool {
+		self.cycle > 0
+			&& year >= self.start_year
+			&& self.end_year.map_or(true, |end_year| year <= end_year)
+			&& (year - self.start_year) % self.cycle == 0
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::Recurrence;
+
+	#[test]
+	fn test_occurs_in() {
+		let mut recurrence = Recurrence::new(1793, 4);
+		recurrence.set_end_year(1933);
+		assert!(recurrence.occurs_in(1793));
+		assert!(recurrence.occurs_in(1933));
+		assert!(!recurrence.occurs_in(1937));
+		assert!(!recurrence.occurs_in(1792));
+		assert!(Recurrence::new(2020, 1).occurs_in(2031));
+		assert!(!Recurrence::new(2020, 0).occurs_in(2020));
+	}
+}
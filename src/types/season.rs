@@ -0,0 +1,12 @@
+/// Equinoxes and solstices, which start the astronomical seasons
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Season {
+	// Start of the spring on the northern hemisphere
+	MarchEquinox,
+	// Start of the summer on the northern hemisphere
+	JuneSolstice,
+	// Start of the autumn on the northern hemisphere
+	SeptemberEquinox,
+	// Start of the winter on the northern hemisphere
+	DecemberSolstice,
+}
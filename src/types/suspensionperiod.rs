@@ -0,0 +1,66 @@
+use chrono::NaiveDate;
+
+use super::countrycode::CountryCode;
+
+/// Period in which the holidays of a country don't give a day off, e.g. under martial law. The holidays are still
+/// listed, but marked as working days.
+///
+/// # Example
+///
+/// ```
+/// use horus::types::{countrycode::CountryCode, suspensionperiod::SuspensionPeriod};
+/// use chrono::NaiveDate;
+///
+/// let martial_law = SuspensionPeriod::new(
+///     NaiveDate::from_ymd_opt(2022, 3, 24).unwrap(),
+///     None,
+///     "Воєнний стан",
+///     "Martial law",
+///     CountryCode::UA,
+/// );
+///
+/// assert!(martial_law.contains(NaiveDate::from_ymd_opt(2024, 1, 1).unwrap()));
+/// assert!(!martial_law.contains(NaiveDate::from_ymd_opt(2022, 1, 7).unwrap()));
+/// assert_eq!(martial_law.to_string(), "2022-03-24 - Martial law");
+/// ```
+#[derive(Debug, Clone)]
+pub struct SuspensionPeriod {
+	pub start: NaiveDate,
+	/// Last day of the period, None if the period hasn't ended yet
+	pub end: Option<NaiveDate>,
+	pub local_name: String,
+	pub name: String,
+	pub country_code: CountryCode,
+}
+
+impl SuspensionPeriod {
+	pub fn new(
+		start: NaiveDate,
+		end: Option<NaiveDate>,
+		local_name: &str,
+		name: &str,
+		country_code: CountryCode,
+	) -> Self {
+		SuspensionPeriod {
+			start,
+			end,
+			local_name: local_name.to_string(),
+			name: name.to_string(),
+			country_code,
+		}
+	}
+
+	/// Checks if the given date is part of the period, including the start and the end
+	pub fn contains(&self, date: NaiveDate) -> bool {
+		date >= self.start && self.end.map_or(true, |end| date <= end)
+	}
+}
+
+impl std::fmt::Display for SuspensionPeriod {
+	fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+		match self.end {
+			Some(end) => write!(f, "{:?} - {:?} {}", self.start, end, self.name),
+			None => write!(f, "{:?} - {}", self.start, self.name),
+		}
+	}
+}
@@ -0,0 +1,55 @@
+use chrono::NaiveDate;
+
+use super::countrycode::CountryCode;
+
+/// Weekend day which is declared as an official working day, usually to move a day off next to a holiday
+///
+/// # Example
+///
+/// ```
+/// use horus::types::{countrycode::CountryCode, workingdayoverride::WorkingDayOverride};
+/// use chrono::NaiveDate;
+///
+/// let working_day = WorkingDayOverride::new(
+///     NaiveDate::from_ymd_opt(2024, 4, 27).unwrap(),
+///     "Рабочий день",
+///     "Working day",
+///     CountryCode::RU,
+///     Some(NaiveDate::from_ymd_opt(2024, 4, 29).unwrap()),
+/// );
+///
+/// assert_eq!(working_day.to_string(), "2024-04-27 Working day");
+/// ```
+#[derive(Debug, Clone)]
+pub struct WorkingDayOverride {
+	pub date: NaiveDate,
+	pub local_name: String,
+	pub name: String,
+	pub country_code: CountryCode,
+	pub transferred_to: Option<NaiveDate>,
+}
+
+impl WorkingDayOverride {
+	/// Creates a working day on the given date, whose day off is transferred to the given date
+	pub fn new(
+		date: NaiveDate,
+		local_name: &str,
+		name: &str,
+		country_code: CountryCode,
+		transferred_to: Option<NaiveDate>,
+	) -> Self {
+		WorkingDayOverride {
+			date,
+			local_name: local_name.to_string(),
+			name: name.to_string(),
+			country_code,
+			transferred_to,
+		}
+	}
+}
+
+impl std::fmt::Display for WorkingDayOverride {
+	fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+		write!(f, "{:?} {}", self.date, self.name)
+	}
+}